@@ -0,0 +1,95 @@
+use std::{hint::black_box, ops::ControlFlow, time::Duration};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use komadori::{
+    iter::{Fold, TryFold},
+    prelude::*,
+};
+use rand::{RngExt, SeedableRng, rngs::StdRng};
+
+fn fold(criterion: &mut Criterion) {
+    let seed = 0;
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    macro_rules! bench_fn {
+        ($group:ident.$fn_name:ident($nums:expr)) => {
+            $group.bench_function(stringify!($fn_name), |bencher| {
+                bencher.iter(|| $fn_name(black_box($nums)));
+            });
+        };
+    }
+
+    let nums: Box<_> = std::iter::repeat_with(|| rng.random_range(-10_000..=10_000))
+        .take(500_000)
+        .collect();
+    println!("Seed: {seed}");
+    println!("First 10 elements: {:?}", &nums[..10]);
+
+    let mut group = criterion.benchmark_group("fold");
+    bench_fn!(group.iter_fold(&nums));
+    bench_fn!(group.bc_fold(&nums));
+    group.finish();
+
+    let mut group = criterion.benchmark_group("try_fold");
+    bench_fn!(group.iter_try_fold(&nums));
+    bench_fn!(group.bc_try_fold(&nums));
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .warm_up_time(Duration::from_secs(5))
+        .measurement_time(Duration::from_secs(30))
+        .sample_size(300);
+    targets = fold
+}
+criterion_main!(benches);
+
+// Kadane's algorithm, to have a state that is not trivially vectorized away.
+fn kadane((sum, max_sum): (i32, i32), num: i32) -> (i32, i32) {
+    let sum = num.max(sum + num);
+    (sum, max_sum.max(sum))
+}
+
+fn iter_fold(nums: &[i32]) -> i32 {
+    nums.iter().copied().fold((0, i32::MIN), kadane).1
+}
+
+fn bc_fold(nums: &[i32]) -> i32 {
+    nums.iter()
+        .copied()
+        .feed_into(Fold::new((0, i32::MIN), |state, num| {
+            *state = kadane(*state, num);
+        }))
+        .1
+}
+
+// Stops once the sum of squares exceeds a threshold, which happens about halfway.
+const THRESHOLD: i64 = 8_000_000_000_000;
+
+fn iter_try_fold(nums: &[i32]) -> i64 {
+    let (ControlFlow::Continue(sum) | ControlFlow::Break(sum)) =
+        nums.iter().try_fold(0_i64, |sum, &num| {
+            let new_sum = sum + i64::from(num) * i64::from(num);
+            if new_sum > THRESHOLD {
+                ControlFlow::Break(sum)
+            } else {
+                ControlFlow::Continue(new_sum)
+            }
+        });
+
+    sum
+}
+
+fn bc_try_fold(nums: &[i32]) -> i64 {
+    nums.iter().feed_into(TryFold::new(0_i64, |sum, &num| {
+        let new_sum = *sum + i64::from(num) * i64::from(num);
+        if new_sum > THRESHOLD {
+            ControlFlow::Break(())
+        } else {
+            *sum = new_sum;
+            ControlFlow::Continue(())
+        }
+    }))
+}
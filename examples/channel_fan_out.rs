@@ -0,0 +1,98 @@
+//! Fans jobs out to a pool of worker threads through channels,
+//! then gathers their results back into one summary.
+//!
+//! Jobs are spread over the workers in turn, and copied to an audit channel on the way.
+//! Bounded channels apply backpressure, so a slow worker holds the producer back
+//! instead of letting jobs pile up in memory.
+
+use std::{
+    collections::BTreeMap,
+    sync::mpsc::{self, Sender, SyncSender},
+    thread,
+};
+
+use komadori::{
+    collector::Spread,
+    iter::{Count, Fold},
+    prelude::*,
+};
+
+const WORKERS: usize = 3;
+
+const DOCUMENTS: [&str; 7] = [
+    "the quick brown fox",
+    "jumps over the lazy dog",
+    "the dog sleeps",
+    "a fox is quick",
+    "brown is a color",
+    "lazy days",
+    "the end",
+];
+
+type WordCounts = BTreeMap<&'static str, usize>;
+
+// Counts the words of each document it receives.
+fn worker(jobs: mpsc::Receiver<(usize, &'static str)>, results: Sender<(usize, WordCounts)>) {
+    jobs.into_iter()
+        .map(|(id, doc)| {
+            let counts = doc.split_whitespace().feed_into(Fold::new(
+                BTreeMap::new(),
+                |counts: &mut BTreeMap<_, usize>, word| *counts.entry(word).or_default() += 1,
+            ));
+            (id, counts)
+        })
+        .feed_into(results);
+}
+
+fn main() {
+    let (audit_tx, audit_rx) = mpsc::channel();
+    let (results_tx, results_rx) = mpsc::channel();
+
+    let (job_txs, job_rxs): (Vec<SyncSender<_>>, Vec<_>) =
+        (0..WORKERS).map(|_| mpsc::sync_channel(1)).unzip();
+
+    let (total, doc_count) = thread::scope(|s| {
+        for jobs in job_rxs {
+            let results = results_tx.clone();
+            s.spawn(move || worker(jobs, results));
+        }
+        // Otherwise, the gathering below would wait forever.
+        drop(results_tx);
+
+        s.spawn(move || {
+            // The senders are dropped after feeding, which lets the workers finish.
+            DOCUMENTS.into_iter().enumerate().feed_into(
+                Spread::round_robin(
+                    job_txs
+                        .into_iter()
+                        .map(IntoCollectorBase::into_collector)
+                        .collect(),
+                )
+                .tee(audit_tx),
+            );
+        });
+
+        results_rx.into_iter().feed_into(
+            Fold::new(
+                BTreeMap::new(),
+                |total: &mut WordCounts, (_, counts): (usize, WordCounts)| {
+                    for (word, count) in counts {
+                        *total.entry(word).or_default() += count;
+                    }
+                },
+            )
+            .tee_clone(Count::new()),
+        )
+    });
+
+    let audited = audit_rx.into_iter().feed_into(Count::new());
+
+    println!("processed {doc_count} documents, audited {audited}");
+    println!("word counts: {total:?}");
+
+    assert_eq!(doc_count, DOCUMENTS.len());
+    assert_eq!(audited, DOCUMENTS.len());
+    assert_eq!(total["the"], 4);
+    assert_eq!(total["fox"], 2);
+    assert_eq!(total["end"], 1);
+}
@@ -0,0 +1,127 @@
+//! Reads a CSV file of sales and computes statistics per column and per region,
+//! in a single pass over the rows.
+
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, Cursor},
+};
+
+use komadori::{
+    cmp::{Max, Min},
+    iter::{Count, Fold},
+    prelude::*,
+};
+
+// Stands for a file. Anything implementing `BufRead` works the same way.
+const CSV: &str = "\
+region,product,units,unit_price
+north,apple,12,150
+south,apple,7,150
+north,pear,3,210
+east,plum,,90
+south,pear,20,200
+west,apple,5,160
+";
+
+#[derive(Debug, Clone, Copy)]
+struct Sale<'a> {
+    region: &'a str,
+    units: u32,
+    unit_price: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct ColumnStats {
+    count: usize,
+    sum: u32,
+    min: Option<u32>,
+    max: Option<u32>,
+}
+
+impl ColumnStats {
+    fn mean(&self) -> Option<f64> {
+        (self.count != 0).then(|| f64::from(self.sum) / self.count as f64)
+    }
+}
+
+fn parse(line: &str) -> Result<Sale<'_>, String> {
+    let malformed = || format!("malformed row: {line:?}");
+
+    let mut cells = line.split(',');
+    let mut next = || cells.next().ok_or_else(malformed);
+
+    let region = next()?;
+    let _product = next()?;
+    let units = next()?.parse().map_err(|_| malformed())?;
+    let unit_price = next()?.parse().map_err(|_| malformed())?;
+
+    Ok(Sale {
+        region,
+        units,
+        unit_price,
+    })
+}
+
+// Statistics of a numeric column.
+fn column_stats() -> impl Collector<u32, Output = ColumnStats> {
+    Count::new()
+        .tee(u32::adding())
+        .tee(Min::new())
+        .tee(Max::new())
+        .map_output(|(((count, sum), min), max)| ColumnStats {
+            count,
+            sum,
+            min,
+            max,
+        })
+}
+
+fn main() -> io::Result<()> {
+    let lines: Vec<String> = Cursor::new(CSV)
+        .lines()
+        .skip(1)
+        .collect::<io::Result<_>>()?;
+
+    let ((((units, revenue), by_region), skipped), total_rows) =
+        lines.iter().map(String::as_str).map(parse).feed_into(
+            column_stats()
+                .map(|sale: Sale<'_>| sale.units)
+                .tee(u32::adding().map(|sale: Sale<'_>| sale.units * sale.unit_price))
+                .tee(Fold::new(
+                    BTreeMap::new(),
+                    |by_region: &mut BTreeMap<_, u32>, sale: Sale<'_>| {
+                        *by_region.entry(sale.region).or_default() += sale.units;
+                    },
+                ))
+                .partition_result(vec![])
+                .tee_clone(Count::new()),
+        );
+
+    println!("units: {units:?}, mean: {:?}", units.mean());
+    println!("revenue: {revenue}");
+    println!("units by region: {by_region:?}");
+    println!(
+        "skipped {} of {total_rows} rows: {skipped:?}",
+        skipped.len()
+    );
+
+    assert_eq!(
+        units,
+        ColumnStats {
+            count: 5,
+            sum: 47,
+            min: Some(3),
+            max: Some(20),
+        },
+    );
+    assert_eq!(units.mean(), Some(9.4));
+    assert_eq!(revenue, 1800 + 1050 + 630 + 4000 + 800);
+    assert_eq!(
+        by_region,
+        BTreeMap::from([("north", 15), ("south", 27), ("west", 5)]),
+    );
+    assert_eq!(skipped, ["malformed row: \"east,plum,,90\""]);
+    assert_eq!(total_rows, 6);
+
+    Ok(())
+}
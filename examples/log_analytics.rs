@@ -0,0 +1,122 @@
+//! Sessionizes an access log and computes some statistics over it, in a single pass.
+//!
+//! Each line is parsed into an event. Malformed lines are set aside,
+//! while well-formed ones are fed to several collectors at once.
+
+use std::collections::HashMap;
+
+use komadori::{
+    cmp::Max,
+    iter::{Count, Fold},
+    prelude::*,
+};
+
+// Format: `<timestamp> <user> <method> <path> <status> <latency in ms>`.
+const LOG: &str = "\
+1000 alice GET /index 200 12
+1010 bob GET /index 200 9
+1030 alice GET /cart 200 31
+1042 bob POST /login 401 5
+garbage
+1100 alice POST /checkout 500 250
+3500 alice GET /index 200 10
+3600 bob GET /index 200 11
+3610 bob GET /search?q 200 87
+3620 carol GET /index 503 1200
+";
+
+// A session of a user ends after this many seconds of inactivity.
+const SESSION_GAP: u64 = 30 * 60;
+
+#[derive(Debug, Clone, Copy)]
+struct Event<'a> {
+    time: u64,
+    user: &'a str,
+    path: &'a str,
+    status: u16,
+    latency_ms: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Session {
+    start: u64,
+    end: u64,
+    hits: usize,
+}
+
+fn parse(line: &str) -> Result<Event<'_>, String> {
+    let malformed = || format!("malformed line: {line:?}");
+
+    let mut fields = line.split_whitespace();
+    let mut next = || fields.next().ok_or_else(malformed);
+
+    let time = next()?.parse().map_err(|_| malformed())?;
+    let user = next()?;
+    let _method = next()?;
+    let path = next()?;
+    let status = next()?.parse().map_err(|_| malformed())?;
+    let latency_ms = next()?.parse().map_err(|_| malformed())?;
+
+    Ok(Event {
+        time,
+        user,
+        path,
+        status,
+        latency_ms,
+    })
+}
+
+fn sessionize<'a>(sessions: &mut HashMap<&'a str, Vec<Session>>, event: Event<'a>) {
+    let user_sessions = sessions.entry(event.user).or_default();
+
+    match user_sessions.last_mut() {
+        Some(session) if event.time - session.end <= SESSION_GAP => {
+            session.end = event.time;
+            session.hits += 1;
+        }
+        _ => user_sessions.push(Session {
+            start: event.time,
+            end: event.time,
+            hits: 1,
+        }),
+    }
+}
+
+fn main() {
+    let (((sessions, server_errors), slowest), malformed) = LOG.lines().map(parse).feed_into(
+        Fold::new(HashMap::new(), sessionize)
+            .tee(Count::new().filter(|event: &Event<'_>| event.status >= 500))
+            .tee(Max::by_key(|event: &Event<'_>| event.latency_ms))
+            .partition_result(vec![]),
+    );
+
+    let mut users: Vec<_> = sessions.iter().collect();
+    users.sort_by_key(|&(user, _)| *user);
+    for (user, sessions) in users {
+        println!("{user}: {sessions:?}");
+    }
+    println!("server errors: {server_errors}");
+    println!("slowest request: {slowest:?}");
+    println!("skipped: {malformed:?}");
+
+    assert_eq!(
+        sessions["alice"],
+        [
+            Session {
+                start: 1000,
+                end: 1100,
+                hits: 3,
+            },
+            Session {
+                start: 3500,
+                end: 3500,
+                hits: 1,
+            },
+        ],
+    );
+    assert_eq!(sessions["bob"].len(), 2);
+    assert_eq!(sessions["carol"].len(), 1);
+    assert_eq!(server_errors, 2);
+    assert_eq!(slowest.map(|event| event.path), Some("/index"));
+    assert_eq!(malformed, ["malformed line: \"garbage\""]);
+}
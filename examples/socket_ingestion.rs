@@ -0,0 +1,95 @@
+//! Ingests metrics sent over a TCP socket, one per line,
+//! and replies with a summary once the client says it is done.
+//!
+//! Both ends are in this example: a client thread writes the metrics with a
+//! [`Writer`] collector, and the server folds them as they arrive.
+
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+};
+
+use komadori::{
+    cmp::Max,
+    io::Writer,
+    iter::{Count, Fold},
+    prelude::*,
+};
+
+const METRICS: [&str; 6] = [
+    "cpu 42\n",
+    "mem 1024\n",
+    "cpu 97\n",
+    "cpu oops\n",
+    "mem 2048\n",
+    "END\n",
+];
+
+// Per metric: the number of samples and the peak value.
+type Summary = BTreeMap<String, (usize, u64)>;
+
+fn parse(line: &str) -> Option<(String, u64)> {
+    let (name, value) = line.split_once(' ')?;
+    Some((name.to_owned(), value.parse().ok()?))
+}
+
+fn serve(stream: TcpStream) -> io::Result<usize> {
+    let ((summary, peak), rejected) = BufReader::new(&stream)
+        .lines()
+        .map_while(Result::ok)
+        .feed_into(
+            Fold::new(Summary::new(), |summary, (name, value): (String, u64)| {
+                let (count, peak) = summary.entry(name).or_default();
+                *count += 1;
+                *peak = (*peak).max(value);
+            })
+            .tee_clone(Max::new().map(|(_, value): (String, u64)| value))
+            .partition_result(Count::new())
+            .map(|line: String| parse(&line).ok_or(line))
+            // The client keeps the connection open until it gets the summary.
+            .take_while(|line: &String| line != "END"),
+        );
+
+    let mut writer = BufWriter::new(&stream);
+    for (name, (count, peak)) in &summary {
+        writeln!(writer, "{name}: {count} samples, peak {peak}")?;
+    }
+    writeln!(writer, "overall peak: {peak:?}, rejected: {rejected}")?;
+    writer.flush()?;
+
+    Ok(summary.len())
+}
+
+fn main() -> io::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+
+    let client = thread::spawn(move || -> io::Result<Vec<String>> {
+        let stream = TcpStream::connect(addr)?;
+        METRICS.into_iter().feed_into(Writer::new(&stream))?;
+
+        BufReader::new(&stream).lines().collect()
+    });
+
+    let (stream, _) = listener.accept()?;
+    let metric_count = serve(stream)?;
+
+    let reply = client.join().expect("the client panicked")?;
+    for line in &reply {
+        println!("{line}");
+    }
+
+    assert_eq!(metric_count, 2);
+    assert_eq!(
+        reply,
+        [
+            "cpu: 2 samples, peak 97",
+            "mem: 2 samples, peak 2048",
+            "overall peak: Some(2048), rejected: 1",
+        ],
+    );
+
+    Ok(())
+}
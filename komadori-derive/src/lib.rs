@@ -0,0 +1,230 @@
+//! Derive macros for [`komadori`](https://docs.rs/komadori).
+//!
+//! Do not depend on this crate directly.
+//! Enable the `derive` feature of `komadori` and use the re-exports there instead.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Path, PathArguments, Type, TypePath, parse_macro_input};
+
+// Same as the largest tuple `komadori` implements `IntoCollectorBase` for.
+const MAX_TUPLE_LEN: usize = 12;
+
+/// Turns a struct whose fields are all collectors into a collector
+/// that feeds every item to each field.
+///
+/// The struct must be annotated with `#[collector(output = Type)]`,
+/// where `Type` is a struct with the same field names (or positions, for tuple structs)
+/// holding the outputs of the corresponding fields.
+///
+/// This derive implements `IntoCollectorBase` for the struct, whose `Output` is `Type`.
+/// The collector behaves like a tuple of the fields:
+/// every item is fed to each field that has not stopped accumulating, in order.
+/// Each field but the last receives a clone of the item, and the last one takes the ownership of it.
+/// The collector only stops when **all** of the fields have stopped.
+///
+/// Each field only needs to implement `IntoCollectorBase`,
+/// so fields of types like `Vec<T>` are accepted.
+///
+/// The struct itself does not implement `CollectorBase` or `Collector`.
+/// Methods taking `IntoCollector` types, such as `feed_into()`, accept it directly.
+/// Otherwise, call `into_collector()` on it first to use the methods of the collector,
+/// such as its adaptors.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::{Max, Min}, iter::Count, num};
+///
+/// #[derive(Collector)]
+/// #[collector(output = Stats)]
+/// struct StatsCollector {
+///     count: Count,
+///     sum: num::Adding<i32>,
+///     min: Min<i32>,
+///     max: Max<i32>,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Stats {
+///     count: usize,
+///     sum: i32,
+///     min: Option<i32>,
+///     max: Option<i32>,
+/// }
+///
+/// let stats = [4, -2, 7, 1].into_iter().feed_into(StatsCollector {
+///     count: Count::new(),
+///     sum: i32::adding(),
+///     min: Min::new(),
+///     max: Max::new(),
+/// });
+///
+/// assert_eq!(
+///     stats,
+///     Stats {
+///         count: 4,
+///         sum: 10,
+///         min: Some(-2),
+///         max: Some(7),
+///     },
+/// );
+/// ```
+///
+/// Tuple structs and generic structs are supported too.
+///
+/// ```
+/// use komadori::prelude::*;
+///
+/// #[derive(Collector)]
+/// #[collector(output = Pair<C1::Output, C2::Output>)]
+/// struct Pair<C1, C2>(C1, C2);
+///
+/// let Pair(nums, count) = [1, 2, 3]
+///     .into_iter()
+///     .feed_into(Pair(vec![], vec![].into_collector().take(2)));
+///
+/// assert_eq!(nums, [1, 2, 3]);
+/// assert_eq!(count, [1, 2]);
+/// ```
+#[proc_macro_derive(Collector, attributes(collector))]
+pub fn derive_collector(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive_collector_impl(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn derive_collector_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let output = parse_output_attr(&input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`Collector` can only be derived for structs",
+        ));
+    };
+
+    let fields: Vec<_> = match &data.fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => vec![],
+    };
+
+    if fields.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`Collector` cannot be derived for structs without fields",
+        ));
+    }
+
+    let members: Vec<_> = data.fields.members().collect();
+    let field_tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let field_vars: Vec<_> = (0..fields.len())
+        .map(|idx| format_ident!("__field{idx}"))
+        .collect();
+    let output_vars: Vec<_> = (0..fields.len())
+        .map(|idx| format_ident!("__output{idx}"))
+        .collect();
+
+    let nested_ty = nest(field_tys.iter().map(|ty| quote!(#ty)).collect());
+    let nested_fields = nest(field_vars.iter().map(|var| quote!(#var)).collect());
+    let nested_outputs = nest(output_vars.iter().map(|var| quote!(#var)).collect());
+
+    let output_expr = to_expr_path(&output)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let where_preds = where_clause
+        .into_iter()
+        .flat_map(|where_clause| &where_clause.predicates);
+
+    Ok(quote! {
+        impl #impl_generics ::komadori::collector::IntoCollectorBase for #name #ty_generics
+        where
+            #(#where_preds,)*
+            #(#field_tys: ::komadori::collector::IntoCollectorBase,)*
+        {
+            type Output = #output;
+
+            type IntoCollector = ::komadori::collector::MapOutput<
+                <#nested_ty as ::komadori::collector::IntoCollectorBase>::IntoCollector,
+                fn(<#nested_ty as ::komadori::collector::IntoCollectorBase>::Output) -> #output,
+            >;
+
+            #[inline]
+            fn into_collector(self) -> Self::IntoCollector {
+                let Self { #(#members: #field_vars),* } = self;
+
+                ::komadori::collector::CollectorBase::map_output(
+                    ::komadori::collector::IntoCollectorBase::into_collector(#nested_fields),
+                    (|#nested_outputs| #output_expr { #(#members: #output_vars),* }) as fn(_) -> _,
+                )
+            }
+        }
+    })
+}
+
+fn parse_output_attr(input: &DeriveInput) -> syn::Result<Type> {
+    let mut output = None;
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("collector") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("output") {
+                output = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `collector` attribute"))
+            }
+        })?;
+    }
+
+    output.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "missing `#[collector(output = Type)]` attribute",
+        )
+    })
+}
+
+// Turns the output type into a path usable in a struct expression,
+// e.g., `Stats<T>` into `Stats::<T>`.
+fn to_expr_path(output: &Type) -> syn::Result<Path> {
+    let Type::Path(TypePath { qself: None, path }) = output else {
+        return Err(syn::Error::new_spanned(
+            output,
+            "the output type must be a path to a struct",
+        ));
+    };
+
+    let mut path = path.clone();
+    for segment in &mut path.segments {
+        if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+            args.colon2_token.get_or_insert_with(Default::default);
+        }
+    }
+
+    Ok(path)
+}
+
+// Groups the elements into nested tuples so that no tuple exceeds `MAX_TUPLE_LEN`.
+// `komadori` implements nothing for 1-tuples, so a lone element is left unwrapped.
+fn nest(mut elems: Vec<TokenStream>) -> TokenStream {
+    while elems.len() > MAX_TUPLE_LEN {
+        elems = elems.chunks(MAX_TUPLE_LEN).map(tuple).collect();
+    }
+
+    tuple(&elems)
+}
+
+fn tuple(elems: &[TokenStream]) -> TokenStream {
+    match elems {
+        [elem] => elem.clone(),
+        elems => quote!((#(#elems,)*)),
+    }
+}
@@ -0,0 +1,65 @@
+//! Pins down that the derive compiles for any number of fields,
+//! including those nested into more than one tuple,
+//! and that every field receives its own output.
+
+use komadori::{collector::Take, prelude::*, vec};
+
+// Field `$field` collects the first `$n` items.
+macro_rules! field_count_test {
+    ($test:ident: $($field:ident = $n:literal),+) => {
+        #[test]
+        fn $test() {
+            #[derive(Collector)]
+            #[collector(output = Outputs)]
+            struct Collectors {
+                $($field: Take<vec::IntoCollector<i32>>,)+
+            }
+
+            #[derive(Debug, PartialEq)]
+            struct Outputs {
+                $($field: Vec<i32>,)+
+            }
+
+            let outputs = (1..=30).feed_into(Collectors {
+                $($field: vec![].into_collector().take($n),)+
+            });
+
+            assert_eq!(
+                outputs,
+                Outputs {
+                    $($field: (1..=$n).collect(),)+
+                },
+            );
+        }
+    };
+}
+
+field_count_test!(one: a = 1);
+
+field_count_test!(two: a = 1, b = 2);
+
+field_count_test!(
+    twelve: a = 1, b = 2, c = 3, d = 4, e = 5, f = 6, g = 7, h = 8, i = 9, j = 10, k = 11, l = 12
+);
+
+field_count_test!(
+    thirteen: a = 1, b = 2, c = 3, d = 4, e = 5, f = 6, g = 7, h = 8, i = 9, j = 10, k = 11,
+    l = 12, m = 13
+);
+
+field_count_test!(
+    twenty_five: a = 1, b = 2, c = 3, d = 4, e = 5, f = 6, g = 7, h = 8, i = 9, j = 10, k = 11,
+    l = 12, m = 13, n = 14, o = 15, p = 16, q = 17, r = 18, s = 19, t = 20, u = 21, v = 22,
+    w = 23, x = 24, y = 25
+);
+
+#[test]
+fn one_unnamed() {
+    #[derive(Collector)]
+    #[collector(output = Wrapper<C::Output>)]
+    struct Wrapper<C>(C);
+
+    let Wrapper(nums) = [1, 2, 3].into_iter().feed_into(Wrapper(vec![]));
+
+    assert_eq!(nums, [1, 2, 3]);
+}
@@ -0,0 +1,105 @@
+//! Pins down that the derive keeps the bounds of the struct,
+//! whether they are written inline or in a `where` clause.
+
+use komadori::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Outputs<O1, O2> {
+    first: O1,
+    second: O2,
+}
+
+#[derive(Debug, PartialEq)]
+struct PairOutput<O1, O2>(O1, O2);
+
+#[test]
+fn where_clause() {
+    #[derive(Collector)]
+    #[collector(output = Outputs<C1::Output, C2::Output>)]
+    struct Collectors<C1, C2>
+    where
+        C1: Clone,
+    {
+        first: C1,
+        second: C2,
+    }
+
+    let outputs = [1, 2, 3].into_iter().feed_into(Collectors {
+        first: vec![],
+        second: vec![].into_collector().take(1),
+    });
+
+    assert_eq!(
+        outputs,
+        Outputs {
+            first: vec![1, 2, 3],
+            second: vec![1],
+        },
+    );
+}
+
+#[test]
+fn where_clause_with_trailing_comma() {
+    #[derive(Collector)]
+    #[collector(output = Outputs<C1::Output, C2::Output>)]
+    struct Collectors<C1, C2>
+    where
+        C1: Clone,
+        C2: Clone,
+    {
+        first: C1,
+        second: C2,
+    }
+
+    let outputs = [1, 2, 3].into_iter().feed_into(Collectors {
+        first: vec![],
+        second: vec![].into_collector().skip(1),
+    });
+
+    assert_eq!(
+        outputs,
+        Outputs {
+            first: vec![1, 2, 3],
+            second: vec![2, 3],
+        },
+    );
+}
+
+#[test]
+fn where_clause_on_tuple_struct() {
+    #[derive(Collector)]
+    #[collector(output = PairOutput<C1::Output, C2::Output>)]
+    struct Pair<C1, C2>(C1, C2)
+    where
+        C1: Clone;
+
+    let PairOutput(nums, firsts) = [1, 2, 3]
+        .into_iter()
+        .feed_into(Pair(vec![], vec![].into_collector().take(1)));
+
+    assert_eq!(nums, [1, 2, 3]);
+    assert_eq!(firsts, [1]);
+}
+
+#[test]
+fn inline_bounds() {
+    #[derive(Collector)]
+    #[collector(output = Outputs<C1::Output, C2::Output>)]
+    struct Collectors<C1: Clone, C2: Default> {
+        first: C1,
+        second: C2,
+    }
+
+    let outputs = [1, 2, 3].into_iter().feed_into(Collectors {
+        first: vec![],
+        second: Vec::default(),
+    });
+
+    assert_eq!(
+        outputs,
+        Outputs {
+            first: vec![1, 2, 3],
+            second: vec![1, 2, 3],
+        },
+    );
+}
@@ -0,0 +1,331 @@
+//! [`Collector`]s for arrays.
+//!
+//! An array of collectors (or types implementing [`IntoCollectorBase`])
+//! is also a collector. Every item is fed to each element that has not stopped
+//! accumulating, in order. Each element but the last active one receives a clone of the item,
+//! and the last active one takes the ownership of it.
+//! The collector only stops when **all** of its elements have stopped.
+//! The [`Output`] is an array of the elements' outputs.
+//! To feed the elements in turn instead, use [`chain_all()`].
+//!
+//! This module corresponds to [`std::array`].
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::{prelude::*, iter::Count};
+//!
+//! let buckets = [0, 1, 2].map(|rem| Count::new().filter(move |&num: &i32| num % 3 == rem));
+//! let counts = [3, 4, 7, 9, 11, 12, 6].into_iter().feed_into(buckets);
+//!
+//! assert_eq!(counts, [4, 2, 1]);
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+//! [`Output`]: CollectorBase::Output
+
+use std::ops::ControlFlow;
+
+use crate::collector::{CollectorBase, IntoCollectorBase};
+
+/// A collector that feeds every item to each collector in an array.
+/// Its [`Output`](CollectorBase::Output) is an array of outputs of the underlying collectors.
+///
+/// This struct is created by `[C; N].into_collector()`.
+/// See the [module-level documentation](self) for more.
+///
+/// [`Collector`]: crate::collector::Collector
+#[derive(Debug, Clone)]
+pub struct Collector<C, const N: usize> {
+    collectors: [C; N],
+    stopped: [bool; N],
+}
+
+impl<C, const N: usize> IntoCollectorBase for [C; N]
+where
+    C: IntoCollectorBase,
+{
+    type Output = [C::Output; N];
+
+    type IntoCollector = Collector<C::IntoCollector, N>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        let collectors = self.map(C::into_collector);
+        let stopped = std::array::from_fn(|i| collectors[i].break_hint().is_break());
+
+        Collector {
+            collectors,
+            stopped,
+        }
+    }
+}
+
+impl<C, const N: usize> CollectorBase for Collector<C, N>
+where
+    C: CollectorBase,
+{
+    type Output = [C::Output; N];
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collectors.map(C::finish)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.stopped.iter().all(|&stopped| stopped) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        for (collector, _) in self
+            .collectors
+            .iter_mut()
+            .zip(&self.stopped)
+            .filter(|&(_, &stopped)| !stopped)
+        {
+            collector.reserve(additional_min, additional_max);
+        }
+    }
+}
+
+impl<C, T, const N: usize> crate::collector::Collector<T> for Collector<C, N>
+where
+    C: crate::collector::Collector<T>,
+    T: Clone,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let Some(last) = self.stopped.iter().rposition(|&stopped| !stopped) else {
+            return ControlFlow::Break(());
+        };
+
+        for (collector, stopped) in self.collectors[..last]
+            .iter_mut()
+            .zip(&mut self.stopped[..last])
+        {
+            if !*stopped {
+                *stopped = collector.collect(item.clone()).is_break();
+            }
+        }
+
+        self.stopped[last] = self.collectors[last].collect(item).is_break();
+
+        self.break_hint()
+    }
+}
+
+/// Creates a collector that feeds items into each collector of an array in turn,
+/// moving on to the next one once the current one stops accumulating.
+/// Its [`Output`](CollectorBase::Output) is an array of outputs of the underlying collectors.
+///
+/// This is the `N`-ary version of [`chain()`](CollectorBase::chain) for collectors of the same type,
+/// which suits fixed multi-phase protocols.
+/// Every collector but the last should be finite, otherwise the later ones never receive any item.
+/// Collectors that have stopped accumulating from the start are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, array::chain_all};
+///
+/// // Three header blocks of 2 bytes each, then the body.
+/// let [version, flags, len, body] = b"\x01\x00\x00\x03\x00\x05hello"
+///     .iter()
+///     .copied()
+///     .feed_into(chain_all([2, 2, 2, usize::MAX].map(|n| vec![].into_collector().take(n))));
+///
+/// assert_eq!(version, [1, 0]);
+/// assert_eq!(flags, [0, 3]);
+/// assert_eq!(len, [0, 5]);
+/// assert_eq!(body, b"hello");
+/// ```
+#[inline]
+pub fn chain_all<C, const N: usize>(collectors: [C; N]) -> ChainAll<C::IntoCollector, N>
+where
+    C: IntoCollectorBase,
+{
+    let mut chain_all = ChainAll {
+        collectors: collectors.map(C::into_collector),
+        current: 0,
+    };
+    chain_all.skip_stopped();
+    chain_all
+}
+
+/// A collector that feeds items into each collector of an array in turn.
+/// Its [`Output`](CollectorBase::Output) is an array of outputs of the underlying collectors.
+///
+/// This `struct` is created by [`chain_all()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct ChainAll<C, const N: usize> {
+    collectors: [C; N],
+    // The collectors before it have stopped.
+    current: usize,
+}
+
+impl<C, const N: usize> ChainAll<C, N>
+where
+    C: CollectorBase,
+{
+    // Each collector's `break_hint()` is called at most once, before it receives any item.
+    fn skip_stopped(&mut self) {
+        while self
+            .collectors
+            .get(self.current)
+            .is_some_and(|collector| collector.break_hint().is_break())
+        {
+            self.current += 1;
+        }
+    }
+}
+
+impl<C, const N: usize> CollectorBase for ChainAll<C, N>
+where
+    C: CollectorBase,
+{
+    type Output = [C::Output; N];
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collectors.map(C::finish)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.current == N {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any active collector may stop at any point.
+        for collector in self.collectors.iter_mut().skip(self.current) {
+            collector.reserve(0, additional_max);
+        }
+    }
+}
+
+impl<C, T, const N: usize> crate::collector::Collector<T> for ChainAll<C, N>
+where
+    C: crate::collector::Collector<T>,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let Some(collector) = self.collectors.get_mut(self.current) else {
+            return ControlFlow::Break(());
+        };
+
+        if collector.collect(item).is_break() {
+            self.current += 1;
+            self.skip_stopped();
+        }
+
+        self.break_hint()
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let mut items = items.into_iter();
+
+        while let Some(collector) = self.collectors.get_mut(self.current) {
+            if collector.collect_many(&mut items).is_continue() {
+                // The items have run out.
+                return ControlFlow::Continue(());
+            }
+
+            self.current += 1;
+            self.skip_stopped();
+        }
+
+        ControlFlow::Break(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::chain_all;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            counts in proptest::array::uniform3(..=5_usize),
+        ) {
+            all_collect_methods_impl(nums, counts)?;
+        }
+
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn chain_all_all_collect_methods(
+            nums in propvec(any::<i32>(), ..=10),
+            counts in proptest::array::uniform3(..=4_usize),
+        ) {
+            chain_all_all_collect_methods_impl(nums, counts)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, counts: [usize; 3]) -> TestCaseResult {
+        let max_count = counts.into_iter().max().unwrap_or(0);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                counts
+                    .map(|count| vec![].into_collector().take(count))
+                    .into_collector()
+            },
+            should_break_pred: |iter| iter.count() >= max_count,
+            pred: |iter, output, remaining| {
+                if output != counts.map(|count| iter.clone().take(count).collect::<Vec<_>>()) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(max_count).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn chain_all_all_collect_methods_impl(nums: Vec<i32>, counts: [usize; 3]) -> TestCaseResult {
+        let total: usize = counts.iter().sum();
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                chain_all(counts.map(|count| vec![].into_collector().take(count)))
+            },
+            should_break_pred: |iter| iter.count() >= total,
+            pred: |mut iter, output, remaining| {
+                let expected = counts.map(|count| iter.by_ref().take(count).collect::<Vec<_>>());
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
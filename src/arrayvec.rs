@@ -0,0 +1,228 @@
+//! [`Collector`]s for [`arrayvec::ArrayVec`].
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::prelude::*;
+//! use arrayvec::ArrayVec;
+//!
+//! let mut nums = 1..;
+//! let vec = nums
+//!     .by_ref()
+//!     .feed_into(ArrayVec::<i32, 3>::new());
+//!
+//! assert_eq!(vec.as_slice(), [1, 2, 3]);
+//! // Nothing more is consumed once the vector is full.
+//! assert_eq!(nums.next(), Some(4));
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::ops::ControlFlow;
+
+use arrayvec::ArrayVec;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pushes collected items into an [`ArrayVec`].
+/// Its [`Output`] is [`ArrayVec`].
+///
+/// It stops accumulating once the vector is full.
+///
+/// This struct is created by `ArrayVec::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct IntoCollector<T, const CAP: usize>(ArrayVec<T, CAP>);
+
+/// A collector that pushes collected items into a [`&mut ArrayVec`](ArrayVec).
+/// Its [`Output`] is [`&mut ArrayVec`](ArrayVec).
+///
+/// It stops accumulating once the vector is full.
+///
+/// This struct is created by `ArrayVec::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorMut<'a, T, const CAP: usize>(&'a mut ArrayVec<T, CAP>);
+
+impl<T, const CAP: usize> crate::collector::IntoCollectorBase for ArrayVec<T, CAP> {
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<T, CAP>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, T, const CAP: usize> crate::collector::IntoCollectorBase for &'a mut ArrayVec<T, CAP> {
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, T, CAP>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+#[inline]
+fn push<T, const CAP: usize>(vec: &mut ArrayVec<T, CAP>, item: T) -> ControlFlow<()> {
+    // The item is dropped if it doesn't fit, which only happens
+    // if the caller ignores the previous `Break`.
+    if vec.try_push(item).is_err() || vec.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+#[inline]
+fn break_hint<T, const CAP: usize>(vec: &ArrayVec<T, CAP>) -> ControlFlow<()> {
+    if vec.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T, const CAP: usize> CollectorBase for IntoCollector<T, CAP> {
+    type Output = ArrayVec<T, CAP>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(&self.0)
+    }
+}
+
+impl<T, const CAP: usize> Collector<T> for IntoCollector<T, CAP> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        push(&mut self.0, item)
+    }
+}
+
+impl<'i, T, const CAP: usize> Collector<&'i T> for IntoCollector<T, CAP>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &item: &'i T) -> ControlFlow<()> {
+        push(&mut self.0, item)
+    }
+}
+
+impl<'i, T, const CAP: usize> Collector<&'i mut T> for IntoCollector<T, CAP>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &mut item: &'i mut T) -> ControlFlow<()> {
+        push(&mut self.0, item)
+    }
+}
+
+impl<'a, T, const CAP: usize> CollectorBase for CollectorMut<'a, T, CAP> {
+    type Output = &'a mut ArrayVec<T, CAP>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(self.0)
+    }
+}
+
+impl<T, const CAP: usize> Collector<T> for CollectorMut<'_, T, CAP> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        push(self.0, item)
+    }
+}
+
+impl<'i, T, const CAP: usize> Collector<&'i T> for CollectorMut<'_, T, CAP>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &item: &'i T) -> ControlFlow<()> {
+        push(self.0, item)
+    }
+}
+
+impl<'i, T, const CAP: usize> Collector<&'i mut T> for CollectorMut<'_, T, CAP>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &mut item: &'i mut T) -> ControlFlow<()> {
+        push(self.0, item)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    const CAP: usize = 4;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            starting_nums in propvec(any::<i32>(), ..=CAP),
+            nums in propvec(any::<i32>(), ..=6),
+        ) {
+            all_collect_methods_impl(starting_nums, nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(starting_nums: Vec<i32>, nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                starting_nums
+                    .iter()
+                    .copied()
+                    .collect::<ArrayVec<i32, CAP>>()
+                    .into_collector()
+            },
+            should_break_pred: |iter| starting_nums.len() + iter.count() >= CAP,
+            pred: |mut iter, output, remaining| {
+                let free = CAP - starting_nums.len();
+                let expected: Vec<_> = starting_nums
+                    .iter()
+                    .copied()
+                    .chain(iter.by_ref().take(free))
+                    .collect();
+
+                if output[..] != expected[..] {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,264 @@
+//! [`Collector`]s for [`Cell`] and [`RefCell`].
+//!
+//! They allow one accumulation target to be shared across
+//! multiple chains within one thread, such as two separate
+//! [`feed_into()`](crate::iter::IteratorExt::feed_into) calls adding to the same total.
+//!
+//! This module corresponds to [`std::cell`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    ops::{Add, ControlFlow},
+};
+
+use crate::collector::{CollectorBase, IntoCollector, IntoCollectorBase};
+
+/// A collector that adds every collected item to the value in a [`&Cell`](Cell).
+/// Its [`Output`](CollectorBase::Output) is [`&Cell`](Cell).
+///
+/// An item of type `U` can be collected if the value type `T` implements
+/// [`Copy`] and [`Add<U, Output = T>`](Add).
+///
+/// This struct is created by `(&Cell).into_collector()` and `Cell::collector()`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use komadori::prelude::*;
+///
+/// let total = Cell::new(0);
+///
+/// [1, 2, 3].into_iter().feed_into(&total);
+/// [4, 5].iter().feed_into(total.collector());
+///
+/// assert_eq!(total.get(), 15);
+/// ```
+///
+/// [`Collector`]: crate::collector::Collector
+pub struct Collector<'a, T>(&'a Cell<T>);
+
+/// A collector that collects items into the collector of the value in a [`&RefCell`](RefCell).
+/// Its [`Output`](CollectorBase::Output) is [`&RefCell`](RefCell).
+///
+/// An item of type `T` can be collected if `&mut C` is a collector of `T`,
+/// like [`&mut Vec`](Vec) or [`&mut String`](String).
+/// The value is mutably borrowed only while each item is being collected.
+///
+/// This struct is created by `(&RefCell).into_collector()` and `RefCell::collector()`.
+///
+/// # Panics
+///
+/// Collecting panics if the value is currently borrowed.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use komadori::prelude::*;
+///
+/// let seen = RefCell::new(vec![]);
+///
+/// [1, 2].into_iter().feed_into(&seen);
+/// [3].into_iter().feed_into(seen.collector().take(1));
+///
+/// assert_eq!(seen.into_inner(), [1, 2, 3]);
+/// ```
+///
+/// [`Collector`]: crate::collector::Collector
+pub struct RefCellCollector<'a, C>(&'a RefCell<C>);
+
+impl<'a, T> IntoCollectorBase for &'a Cell<T> {
+    type Output = Self;
+
+    type IntoCollector = Collector<'a, T>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        Collector(self)
+    }
+}
+
+impl<'a, T> CollectorBase for Collector<'a, T> {
+    type Output = &'a Cell<T>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<T, U> crate::collector::Collector<U> for Collector<'_, T>
+where
+    T: Copy + Add<U, Output = T>,
+{
+    #[inline]
+    fn collect(&mut self, item: U) -> ControlFlow<()> {
+        self.0.set(self.0.get() + item);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = U>) -> Self::Output {
+        self.0.set(items.into_iter().fold(self.0.get(), Add::add));
+        self.0
+    }
+}
+
+impl<'a, C> IntoCollectorBase for &'a RefCell<C>
+where
+    for<'b> &'b mut C: IntoCollectorBase,
+{
+    type Output = Self;
+
+    type IntoCollector = RefCellCollector<'a, C>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        RefCellCollector(self)
+    }
+}
+
+impl<'a, C> CollectorBase for RefCellCollector<'a, C>
+where
+    for<'b> &'b mut C: IntoCollectorBase,
+{
+    type Output = &'a RefCell<C>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        (&mut *self.0.borrow_mut()).into_collector().break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        (&mut *self.0.borrow_mut())
+            .into_collector()
+            .reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, T> crate::collector::Collector<T> for RefCellCollector<'_, C>
+where
+    for<'b> &'b mut C: IntoCollector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        (&mut *self.0.borrow_mut()).into_collector().collect(item)
+    }
+}
+
+impl<T> Clone for Collector<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Collector<'_, T> {}
+
+impl<C> Clone for RefCellCollector<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for RefCellCollector<'_, C> {}
+
+impl<T: Copy + Debug> Debug for Collector<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Collector").field(&self.0).finish()
+    }
+}
+
+impl<C: Debug> Debug for RefCellCollector<'_, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RefCellCollector").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cell::{Cell, RefCell};
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        #[test]
+        fn all_collect_methods_cell(
+            nums in propvec(any::<i32>(), ..=5),
+        ) {
+            all_collect_methods_cell_impl(nums)?;
+        }
+
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::CollectorMut`]
+        #[test]
+        fn all_collect_methods_ref_cell(
+            nums in propvec(any::<i32>(), ..=5),
+            take_count in ..=5_usize,
+        ) {
+            all_collect_methods_ref_cell_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_cell_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied().map(std::num::Wrapping),
+            collector_factory: || {
+                // A fresh cell is needed for each collector, and the tester requires
+                // the collector to not borrow from anything local.
+                Box::leak(Box::new(Cell::new(std::num::Wrapping(0))))
+                    .into_collector()
+                    .map_output(Cell::get)
+            },
+            should_break_pred: |_| false,
+            pred: |mut iter, output, remaining| {
+                if output != iter.by_ref().sum() {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn all_collect_methods_ref_cell_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                Box::leak(Box::new(RefCell::new(vec![])))
+                    .into_collector()
+                    .map_output(RefCell::take)
+                    .take(take_count)
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                if iter.by_ref().take(take_count).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
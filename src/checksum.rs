@@ -0,0 +1,301 @@
+//! [`Collector`]s that compute checksums of bytes.
+//!
+//! Combined with [`tee()`], data can be verified while being processed
+//! (e.g., written to a file) in the same pass.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::{prelude::*, checksum::Crc32, io::Writer};
+//!
+//! let chunks: [&[u8]; 3] = [b"the ", b"noble ", b"and"];
+//!
+//! let (written, crc) = chunks
+//!     .into_iter()
+//!     .feed_into(Writer::new(vec![]).tee(Crc32::new()));
+//!
+//! assert_eq!(written.unwrap(), b"the noble and");
+//! assert_eq!(crc, crc32fast::hash(b"the noble and"));
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+//! [`tee()`]: crate::collector::CollectorBase::tee
+
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that computes the CRC-32 (IEEE) checksum of the collected bytes
+/// with [`crc32fast`].
+/// Its [`Output`](CollectorBase::Output) is the checksum.
+///
+/// It accepts `u8`, `&u8`, `&[u8]` and `&[u8; N]`.
+///
+/// This collector never stops accumulating.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, checksum::Crc32};
+///
+/// let crc = b"123456789".iter().feed_into(Crc32::new());
+///
+/// assert_eq!(crc, 0xcbf43926);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Crc32 {
+    hasher: crc32fast::Hasher,
+}
+
+impl Crc32 {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new instance of this collector continuing from the given checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, checksum::Crc32};
+    ///
+    /// let crc = b"1234".iter().feed_into(Crc32::new());
+    /// let crc = b"56789".iter().feed_into(Crc32::with_initial(crc));
+    ///
+    /// assert_eq!(crc, 0xcbf43926);
+    /// ```
+    #[inline]
+    pub fn with_initial(crc: u32) -> Self {
+        Self {
+            hasher: crc32fast::Hasher::new_with_initial(crc),
+        }
+    }
+}
+
+impl CollectorBase for Crc32 {
+    type Output = u32;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.hasher.finalize()
+    }
+}
+
+impl Collector<u8> for Crc32 {
+    #[inline]
+    fn collect(&mut self, byte: u8) -> ControlFlow<()> {
+        self.hasher.update(&[byte]);
+        ControlFlow::Continue(())
+    }
+}
+
+impl Collector<&u8> for Crc32 {
+    #[inline]
+    fn collect(&mut self, &byte: &u8) -> ControlFlow<()> {
+        self.collect(byte)
+    }
+}
+
+impl Collector<&[u8]> for Crc32 {
+    #[inline]
+    fn collect(&mut self, bytes: &[u8]) -> ControlFlow<()> {
+        self.hasher.update(bytes);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<const N: usize> Collector<&[u8; N]> for Crc32 {
+    #[inline]
+    fn collect(&mut self, bytes: &[u8; N]) -> ControlFlow<()> {
+        self.collect(bytes.as_slice())
+    }
+}
+
+/// A collector that computes the Adler-32 checksum of the collected bytes.
+/// Its [`Output`](CollectorBase::Output) is the checksum.
+///
+/// It accepts `u8`, `&u8`, `&[u8]` and `&[u8; N]`.
+///
+/// This collector never stops accumulating.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, checksum::Adler32};
+///
+/// let adler = b"Wikipedia".iter().feed_into(Adler32::new());
+///
+/// assert_eq!(adler, 0x11e60398);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+// The largest prime below 2^16.
+const MOD_ADLER: u32 = 65521;
+// The most bytes that can be summed before `b` may overflow.
+const NMAX: usize = 5552;
+
+impl Adler32 {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_initial(1)
+    }
+
+    /// Creates a new instance of this collector continuing from the given checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, checksum::Adler32};
+    ///
+    /// let adler = b"Wiki".iter().feed_into(Adler32::new());
+    /// let adler = b"pedia".iter().feed_into(Adler32::with_initial(adler));
+    ///
+    /// assert_eq!(adler, 0x11e60398);
+    /// ```
+    #[inline]
+    pub const fn with_initial(adler: u32) -> Self {
+        Self {
+            a: adler & 0xffff,
+            b: adler >> 16,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(NMAX) {
+            for &byte in chunk {
+                self.a += u32::from(byte);
+                self.b += self.a;
+            }
+
+            self.a %= MOD_ADLER;
+            self.b %= MOD_ADLER;
+        }
+    }
+}
+
+impl Default for Adler32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CollectorBase for Adler32 {
+    type Output = u32;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        (self.b << 16) | self.a
+    }
+}
+
+impl Collector<u8> for Adler32 {
+    #[inline]
+    fn collect(&mut self, byte: u8) -> ControlFlow<()> {
+        self.update(&[byte]);
+        ControlFlow::Continue(())
+    }
+}
+
+impl Collector<&u8> for Adler32 {
+    #[inline]
+    fn collect(&mut self, &byte: &u8) -> ControlFlow<()> {
+        self.collect(byte)
+    }
+}
+
+impl Collector<&[u8]> for Adler32 {
+    #[inline]
+    fn collect(&mut self, bytes: &[u8]) -> ControlFlow<()> {
+        self.update(bytes);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<const N: usize> Collector<&[u8; N]> for Adler32 {
+    #[inline]
+    fn collect(&mut self, bytes: &[u8; N]) -> ControlFlow<()> {
+        self.collect(bytes.as_slice())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn crc32_all_collect_methods(
+            chunks in propvec(propvec(any::<u8>(), ..8), ..5),
+        ) {
+            crc32_all_collect_methods_impl(chunks)?;
+        }
+
+        #[test]
+        fn adler32_all_collect_methods(
+            chunks in propvec(propvec(any::<u8>(), ..8), ..5),
+        ) {
+            adler32_all_collect_methods_impl(chunks)?;
+        }
+    }
+
+    fn crc32_all_collect_methods_impl(chunks: Vec<Vec<u8>>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chunks.iter().map(Vec::as_slice),
+            collector_factory: Crc32::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if output != crc32fast::hash(&chunks.concat()) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn adler32_all_collect_methods_impl(chunks: Vec<Vec<u8>>) -> TestCaseResult {
+        // The definition, without deferring the modulo.
+        let expected = || {
+            let (a, b) = chunks.concat().into_iter().fold((1, 0), |(a, b), byte| {
+                let a = (a + u32::from(byte)) % MOD_ADLER;
+                (a, (b + a) % MOD_ADLER)
+            });
+            (b << 16) | a
+        };
+
+        BasicCollectorTester {
+            iter_factory: || chunks.iter().map(Vec::as_slice),
+            collector_factory: Adler32::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if output != expected() {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -4,13 +4,26 @@
 //! values among the items they collect, using different comparison strategies.
 //! They correspond to [`Iterator`]’s comparison-related methods, such as
 //! [`Iterator::max()`], [`Iterator::min_by()`], and [`Iterator::max_by_key()`].
+//! `TopK` and `BottomK` generalize them to the `k` greatest or least items,
+//! and [`ArgMax`] and [`ArgMin`] also give the index of the item.
 //!
 //! This module corresponds to [`std::cmp`].
 //!
 //! [`Collector`]: crate::collector::Collector
 
-#[cfg(feature = "itertools")]
 mod all_equal;
+mod arg_max;
+mod arg_min;
+#[cfg(feature = "alloc")]
+mod bottom_k;
+#[cfg(feature = "alloc")]
+mod bottom_k_by;
+#[cfg(feature = "alloc")]
+mod bottom_k_by_key;
+mod is_sorted;
+mod is_strictly_sorted;
+#[cfg(feature = "alloc")]
+mod k_selection;
 mod max;
 mod max_by;
 mod max_by_key;
@@ -19,13 +32,29 @@ mod min_by;
 mod min_by_key;
 #[cfg(feature = "itertools")]
 mod min_max;
+#[cfg(feature = "alloc")]
+mod sliding_window_max;
+#[cfg(feature = "alloc")]
+mod top_k;
+#[cfg(feature = "alloc")]
+mod top_k_by;
+#[cfg(feature = "alloc")]
+mod top_k_by_key;
 mod value_key;
-// mod is_sorted;
 // mod is_sorted_by;
 // mod is_sorted_by_key;
 
-#[cfg(feature = "itertools")]
 pub use all_equal::*;
+pub use arg_max::*;
+pub use arg_min::*;
+#[cfg(feature = "alloc")]
+pub use bottom_k::*;
+#[cfg(feature = "alloc")]
+pub use bottom_k_by::*;
+#[cfg(feature = "alloc")]
+pub use bottom_k_by_key::*;
+pub use is_sorted::*;
+pub use is_strictly_sorted::*;
 pub use max::*;
 pub use max_by::*;
 pub use max_by_key::*;
@@ -34,6 +63,14 @@ pub use min_by::*;
 pub use min_by_key::*;
 #[cfg(feature = "itertools")]
 pub use min_max::*;
+#[cfg(feature = "alloc")]
+pub use sliding_window_max::*;
+#[cfg(feature = "alloc")]
+pub use top_k::*;
+#[cfg(feature = "alloc")]
+pub use top_k_by::*;
+#[cfg(feature = "alloc")]
+pub use top_k_by_key::*;
 
 #[inline]
 fn max_assign<T: Ord>(max: &mut T, value: T) {
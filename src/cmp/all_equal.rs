@@ -1,14 +1,41 @@
 use std::ops::ControlFlow;
 
-use itertools::Itertools;
-
 use crate::collector::{Collector, CollectorBase};
 
 /// A collector that determines whether all collected items are equal to each other.
 ///
 /// The [`Output`](CollectorBase::Output) is `true` if no items were collected.
 ///
+/// It stops accumulating at the first item not equal to the previous ones.
+///
 /// This corresponds to [`Itertools::all_equal()`].
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::AllEqual};
+///
+/// assert!([3, 3, 3].into_iter().feed_into(AllEqual::new()));
+/// assert!(![3, 3, 4].into_iter().feed_into(AllEqual::new()));
+/// ```
+///
+/// Checking that every row of an import has the same number of columns,
+/// while collecting the rows:
+///
+/// ```
+/// use komadori::{prelude::*, cmp::AllEqual};
+///
+/// let rows: [&[&str]; 3] = [&["a", "1"], &["b", "2"], &["c"]];
+///
+/// let (same_width, rows) = rows
+///     .into_iter()
+///     .feed_into(AllEqual::new().map(|row: &[_]| row.len()).tee(vec![]));
+///
+/// assert!(!same_width);
+/// assert_eq!(rows.len(), 3);
+/// ```
+///
+/// [`Itertools::all_equal()`]: https://docs.rs/itertools/latest/itertools/trait.Itertools.html#method.all_equal
 #[derive(Debug, Clone)]
 pub struct AllEqual<T> {
     state: State<T>,
@@ -106,7 +133,12 @@ where
     fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
         match self.state {
             State::NotEqual => false,
-            State::StillEqual { prev: None } => items.into_iter().all_equal(),
+            State::StillEqual { prev: None } => {
+                let mut items = items.into_iter();
+                items
+                    .next()
+                    .is_none_or(|first_item| items.all(move |item| first_item == item))
+            }
             State::StillEqual { prev: Some(prev) } => {
                 items.into_iter().all(move |item| prev == item)
             }
@@ -130,9 +162,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use itertools::Itertools;
-
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
@@ -157,9 +187,9 @@ mod proptests {
                 }
                 collector
             },
-            should_break_pred: |iter| !iter.chain(first_num).all_equal(),
+            should_break_pred: |iter| !all_equal(iter.chain(first_num)),
             pred: |mut iter, output, remaining| {
-                if first_num.into_iter().chain(&mut iter).all_equal() != output {
+                if all_equal(first_num.into_iter().chain(&mut iter)) != output {
                     Err(PredError::IncorrectOutput)
                 } else if remaining.ne(iter) {
                     Err(PredError::IncorrectIterConsumption)
@@ -170,4 +200,8 @@ mod proptests {
         }
         .test_collector()
     }
+
+    fn all_equal(mut iter: impl Iterator<Item = i32>) -> bool {
+        iter.next().is_none_or(|first| iter.all(|num| num == first))
+    }
 }
@@ -0,0 +1,124 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that computes the maximum item among the items it collects,
+/// along with its index.
+///
+/// Its [`Output`](CollectorBase::Output) is `None` if it has not collected any items,
+/// or `Some` containing the index of the maximum item, counting from 0, and the item itself.
+/// If several items are equally maximum, the last one is chosen, like [`Max`](super::Max).
+///
+/// This collector corresponds to `iter.enumerate().max_by_key(|&(_, item)| item)`.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::ArgMax};
+///
+/// let latencies = [12, 15, 96, 14, 96, 13];
+///
+/// // Which sample was the outlier?
+/// assert_eq!(latencies.into_iter().feed_into(ArgMax::new()), Some((4, 96)));
+/// ```
+///
+/// The output is `None` if no items were collected.
+///
+/// ```
+/// use komadori::{prelude::*, cmp::ArgMax};
+///
+/// assert_eq!(ArgMax::<i32>::new().finish(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArgMax<T> {
+    max: Option<(usize, T)>,
+    // The index of the next item.
+    index: usize,
+}
+
+impl<T> ArgMax<T> {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self
+    where
+        T: Ord,
+    {
+        assert_collector(Self {
+            max: None,
+            index: 0,
+        })
+    }
+}
+
+impl<T> Default for ArgMax<T>
+where
+    T: Ord,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for ArgMax<T> {
+    type Output = Option<(usize, T)>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.max
+    }
+}
+
+impl<T: Ord> Collector<T> for ArgMax<T> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let index = self.index;
+        self.index += 1;
+
+        match self.max {
+            // Don't use `>=`. See `max_assign()`.
+            Some((_, ref max)) if item < *max => {}
+            _ => self.max = Some((index, item)),
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4, ..6),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: ArgMax::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if iter.enumerate().max_by_key(|&(_, num)| num) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,123 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that computes the minimum item among the items it collects,
+/// along with its index.
+///
+/// Its [`Output`](CollectorBase::Output) is `None` if it has not collected any items,
+/// or `Some` containing the index of the minimum item, counting from 0, and the item itself.
+/// If several items are equally minimum, the first one is chosen, like [`Min`](super::Min).
+///
+/// This collector corresponds to `iter.enumerate().min_by_key(|&(_, item)| item)`.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::ArgMin};
+///
+/// let battery_levels = [87, 42, 65, 42, 90];
+///
+/// // Which device needs charging first?
+/// assert_eq!(battery_levels.into_iter().feed_into(ArgMin::new()), Some((1, 42)));
+/// ```
+///
+/// The output is `None` if no items were collected.
+///
+/// ```
+/// use komadori::{prelude::*, cmp::ArgMin};
+///
+/// assert_eq!(ArgMin::<i32>::new().finish(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArgMin<T> {
+    min: Option<(usize, T)>,
+    // The index of the next item.
+    index: usize,
+}
+
+impl<T> ArgMin<T> {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self
+    where
+        T: Ord,
+    {
+        assert_collector(Self {
+            min: None,
+            index: 0,
+        })
+    }
+}
+
+impl<T> Default for ArgMin<T>
+where
+    T: Ord,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for ArgMin<T> {
+    type Output = Option<(usize, T)>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.min
+    }
+}
+
+impl<T: Ord> Collector<T> for ArgMin<T> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let index = self.index;
+        self.index += 1;
+
+        match self.min {
+            Some((_, ref min)) if item >= *min => {}
+            _ => self.min = Some((index, item)),
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4, ..6),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: ArgMin::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if iter.enumerate().min_by_key(|&(_, num)| num) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
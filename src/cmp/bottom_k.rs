@@ -0,0 +1,128 @@
+use std::{cmp::Ordering, ops::ControlFlow};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::{BottomKBy, BottomKByKey, k_selection::KSelection};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that computes the `k` least items among the items it collects.
+///
+/// Its [`Output`](CollectorBase::Output) is a [`Vec`] of at most `k` items,
+/// from the least to the greatest.
+/// The selection is stable: among equal items, those collected first are kept
+/// and come first in the output.
+///
+/// It keeps a bounded binary heap of `k` items, so each item is compared
+/// in `O(log k)` time. If `k` is 0, it stops accumulating from construction.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::BottomK};
+///
+/// let bottom = [3, 1, 4, 1, 5, 9, 2, 6].into_iter().feed_into(BottomK::new(3));
+///
+/// assert_eq!(bottom, [1, 1, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BottomK<T> {
+    selection: KSelection<T>,
+}
+
+impl<T> BottomK<T> {
+    /// Creates a new instance of this collector, keeping `k` items.
+    #[inline]
+    pub const fn new(k: usize) -> Self
+    where
+        T: Ord,
+    {
+        assert_collector(Self {
+            selection: KSelection::new(k),
+        })
+    }
+
+    /// Creates a new instance of [`BottomKBy`] with a given comparison function.
+    #[inline]
+    pub const fn by<F>(k: usize, f: F) -> BottomKBy<T, F>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        assert_collector(BottomKBy::new(k, f))
+    }
+
+    /// Creates a new instance of [`BottomKByKey`] with a given key-extraction function.
+    #[inline]
+    pub const fn by_key<K, F>(k: usize, f: F) -> BottomKByKey<T, K, F>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        assert_collector(BottomKByKey::new(k, f))
+    }
+}
+
+impl<T: Ord> CollectorBase for BottomK<T> {
+    type Output = Vec<T>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.selection.into_sorted(|item1, item2| item2.cmp(item1))
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.selection.break_hint()
+    }
+}
+
+impl<T: Ord> Collector<T> for BottomK<T> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.selection.push(item, |item1, item2| item2.cmp(item1));
+        self.selection.break_hint()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::cmp::BottomK;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=8),
+            k in ..=8_usize,
+        ) {
+            all_collect_methods_impl(nums, k)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, k: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || BottomK::new(k),
+            should_break_pred: |_| k == 0,
+            pred: |iter, output, remaining| {
+                let mut expected: Vec<_> = iter.clone().collect();
+                expected.sort();
+                expected.truncate(k);
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != if k == 0 { iter.count() } else { 0 } {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,135 @@
+use std::{cmp::Ordering, fmt::Debug, ops::ControlFlow};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::k_selection::KSelection;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that computes the `k` least items among the items it collects,
+/// with respect to a comparison function.
+///
+/// Its [`Output`](CollectorBase::Output) is a [`Vec`] of at most `k` items,
+/// from the least to the greatest.
+/// The selection is stable: among equal items, those collected first are kept
+/// and come first in the output.
+///
+/// This collector is constructed by [`BottomK::by()`](super::BottomK::by).
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::BottomK};
+///
+/// let shortest = ["the", "a", "is", "an", "not"]
+///     .into_iter()
+///     .feed_into(BottomK::by(2, |s1: &&str, s2: &&str| s1.len().cmp(&s2.len())));
+///
+/// // "is" and "an" are as short, but "is" comes first.
+/// assert_eq!(shortest, ["a", "is"]);
+/// ```
+#[derive(Clone)]
+pub struct BottomKBy<T, F> {
+    selection: KSelection<T>,
+    f: F,
+}
+
+impl<T, F> BottomKBy<T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    #[inline]
+    pub(super) const fn new(k: usize, f: F) -> Self {
+        Self {
+            selection: KSelection::new(k),
+            f,
+        }
+    }
+}
+
+impl<T, F> CollectorBase for BottomKBy<T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    type Output = Vec<T>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        let mut f = self.f;
+        self.selection.into_sorted(|item1, item2| f(item2, item1))
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.selection.break_hint()
+    }
+}
+
+impl<T, F> Collector<T> for BottomKBy<T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.selection
+            .push(item, |item1, item2| (self.f)(item2, item1));
+        self.selection.break_hint()
+    }
+}
+
+impl<T: Debug, F> Debug for BottomKBy<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BottomKBy")
+            .field("selection", &self.selection)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::cmp::BottomK;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            keys in propvec(0..3, ..=8),
+            k in ..=8_usize,
+        ) {
+            all_collect_methods_impl(keys, k)?;
+        }
+    }
+
+    // Items are paired with their indices to check that ties keep the first-seen ones.
+    fn all_collect_methods_impl(keys: Vec<i32>, k: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || keys.iter().copied().enumerate(),
+            collector_factory: || {
+                BottomK::by(k, |(_, key1): &(usize, i32), (_, key2): &(usize, i32)| {
+                    key1.cmp(key2)
+                })
+            },
+            should_break_pred: |_| k == 0,
+            pred: |iter, output, remaining| {
+                let mut expected: Vec<_> = iter.clone().collect();
+                // A stable sort.
+                expected.sort_by_key(|&(_, key)| key);
+                expected.truncate(k);
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != if k == 0 { iter.count() } else { 0 } {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
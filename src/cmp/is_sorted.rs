@@ -0,0 +1,177 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that determines whether the collected items are sorted
+/// in non-decreasing order.
+/// Its [`Output`](CollectorBase::Output) is `true` if no items were collected.
+///
+/// It stops accumulating at the first item smaller than the previous one.
+/// Its index, counting from 0, can then be queried with [`first_violation()`].
+///
+/// This corresponds to [`Iterator::is_sorted()`].
+/// See [`IsStrictlySorted`](super::IsStrictlySorted) to also reject equal items.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::IsSorted};
+///
+/// assert!([1, 2, 2, 9].into_iter().feed_into(IsSorted::new()));
+/// assert!(![1, 3, 2, 4].into_iter().feed_into(IsSorted::new()));
+/// ```
+///
+/// Verifying the order in the same pass that consumes the data:
+///
+/// ```
+/// use komadori::{prelude::*, cmp::IsSorted};
+///
+/// let timestamps = [10, 12, 15, 14, 20];
+/// let mut is_sorted = IsSorted::new();
+///
+/// let (total, ()) = timestamps
+///     .into_iter()
+///     .feed_into(i32::adding().tee(&mut is_sorted));
+///
+/// assert_eq!(total, 71);
+/// assert_eq!(is_sorted.first_violation(), Some(3));
+/// assert!(!is_sorted.finish());
+/// ```
+///
+/// [`first_violation()`]: IsSorted::first_violation
+#[derive(Debug, Clone)]
+pub struct IsSorted<T> {
+    prev: Option<T>,
+    // The index of `prev`, or that of the first violation.
+    idx: usize,
+    sorted: bool,
+}
+
+impl<T> IsSorted<T>
+where
+    T: PartialOrd,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            prev: None,
+            idx: 0,
+            sorted: true,
+        }
+    }
+}
+
+impl<T> IsSorted<T> {
+    /// Returns the index of the first item out of order, counting from 0,
+    /// or `None` if the items collected so far are sorted.
+    #[inline]
+    pub const fn first_violation(&self) -> Option<usize> {
+        if self.sorted { None } else { Some(self.idx) }
+    }
+}
+
+impl<T> Default for IsSorted<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for IsSorted<T> {
+    type Output = bool;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.sorted
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.sorted {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+}
+
+impl<T> Collector<T> for IsSorted<T>
+where
+    T: PartialOrd,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if !self.sorted {
+            return ControlFlow::Break(());
+        }
+
+        match self.prev {
+            None => self.prev = Some(item),
+            Some(ref mut prev) => {
+                self.idx += 1;
+
+                if *prev <= item {
+                    *prev = item;
+                } else {
+                    self.sorted = false;
+                    return ControlFlow::Break(());
+                }
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4, ..6),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        // The index of the first item smaller than the previous one.
+        let violation = |nums: &[i32]| nums.windows(2).position(|w| w[0] > w[1]).map(|i| i + 1);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: IsSorted::new,
+            should_break_pred: |iter| violation(&iter.collect::<Vec<_>>()).is_some(),
+            pred: |iter, output, remaining| {
+                let nums: Vec<_> = iter.collect();
+                let consumed = violation(&nums).map_or(nums.len(), |idx| idx + 1);
+
+                if output != nums.is_sorted() {
+                    Err(PredError::IncorrectOutput)
+                } else if nums[consumed..].iter().copied().ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()?;
+
+        let mut collector = IsSorted::new();
+        let _ = collector.collect_many(nums.iter().copied());
+        prop_assert_eq!(collector.first_violation(), violation(&nums));
+
+        Ok(())
+    }
+}
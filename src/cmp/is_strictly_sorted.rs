@@ -0,0 +1,177 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that determines whether the collected items are sorted
+/// in strictly increasing order.
+/// Its [`Output`](CollectorBase::Output) is `true` if no items were collected.
+///
+/// It stops accumulating at the first item not greater than the previous one.
+/// Its index, counting from 0, can then be queried with [`first_violation()`].
+///
+/// This corresponds to `is_sorted_by(|a, b| a < b)`.
+/// See [`IsSorted`](super::IsSorted) to accept equal items.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::IsStrictlySorted};
+///
+/// assert!([1, 2, 5, 9].into_iter().feed_into(IsStrictlySorted::new()));
+/// assert!(![1, 2, 2, 9].into_iter().feed_into(IsStrictlySorted::new()));
+/// ```
+///
+/// Verifying that IDs are unique and increasing in the same pass that consumes them:
+///
+/// ```
+/// use komadori::{prelude::*, cmp::IsStrictlySorted};
+///
+/// let ids = [3, 5, 8, 8, 13];
+/// let mut is_sorted = IsStrictlySorted::new();
+///
+/// let (ids, ()) = ids
+///     .into_iter()
+///     .feed_into(vec![].into_collector().tee(&mut is_sorted));
+///
+/// assert_eq!(ids, [3, 5, 8, 8, 13]);
+/// assert_eq!(is_sorted.first_violation(), Some(3));
+/// assert!(!is_sorted.finish());
+/// ```
+///
+/// [`first_violation()`]: IsStrictlySorted::first_violation
+#[derive(Debug, Clone)]
+pub struct IsStrictlySorted<T> {
+    prev: Option<T>,
+    // The index of `prev`, or that of the first violation.
+    idx: usize,
+    sorted: bool,
+}
+
+impl<T> IsStrictlySorted<T>
+where
+    T: PartialOrd,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            prev: None,
+            idx: 0,
+            sorted: true,
+        }
+    }
+}
+
+impl<T> IsStrictlySorted<T> {
+    /// Returns the index of the first item out of order, counting from 0,
+    /// or `None` if the items collected so far are sorted.
+    #[inline]
+    pub const fn first_violation(&self) -> Option<usize> {
+        if self.sorted { None } else { Some(self.idx) }
+    }
+}
+
+impl<T> Default for IsStrictlySorted<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for IsStrictlySorted<T> {
+    type Output = bool;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.sorted
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.sorted {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+}
+
+impl<T> Collector<T> for IsStrictlySorted<T>
+where
+    T: PartialOrd,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if !self.sorted {
+            return ControlFlow::Break(());
+        }
+
+        match self.prev {
+            None => self.prev = Some(item),
+            Some(ref mut prev) => {
+                self.idx += 1;
+
+                if *prev < item {
+                    *prev = item;
+                } else {
+                    self.sorted = false;
+                    return ControlFlow::Break(());
+                }
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4, ..6),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        // The index of the first item not greater than the previous one.
+        let violation = |nums: &[i32]| nums.windows(2).position(|w| w[0] >= w[1]).map(|i| i + 1);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: IsStrictlySorted::new,
+            should_break_pred: |iter| violation(&iter.collect::<Vec<_>>()).is_some(),
+            pred: |iter, output, remaining| {
+                let nums: Vec<_> = iter.collect();
+                let consumed = violation(&nums).map_or(nums.len(), |idx| idx + 1);
+
+                if output != nums.is_sorted_by(|a, b| a < b) {
+                    Err(PredError::IncorrectOutput)
+                } else if nums[consumed..].iter().copied().ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()?;
+
+        let mut collector = IsStrictlySorted::new();
+        let _ = collector.collect_many(nums.iter().copied());
+        prop_assert_eq!(collector.first_violation(), violation(&nums));
+
+        Ok(())
+    }
+}
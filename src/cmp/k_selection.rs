@@ -0,0 +1,101 @@
+use std::{cmp::Ordering, ops::ControlFlow};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// The `k` greatest items seen so far, with ties kept in the order they were seen.
+///
+/// It is a binary heap whose root is the least item, paired with the index
+/// of each item so that an item seen later counts as less than an equal one seen earlier.
+/// Shared by [`TopK`](super::TopK), [`BottomK`](super::BottomK) and their variants.
+#[derive(Debug, Clone)]
+pub(super) struct KSelection<T> {
+    k: usize,
+    heap: Vec<(usize, T)>,
+    idx: usize,
+}
+
+impl<T> KSelection<T> {
+    #[inline]
+    pub(super) const fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: Vec::new(),
+            idx: 0,
+        }
+    }
+
+    /// Nothing is kept if `k` is 0.
+    #[inline]
+    pub(super) const fn break_hint(&self) -> ControlFlow<()> {
+        if self.k == 0 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    pub(super) fn push(&mut self, item: T, mut cmp: impl FnMut(&T, &T) -> Ordering) {
+        let idx = self.idx;
+        self.idx += 1;
+
+        if self.heap.len() < self.k {
+            self.heap.push((idx, item));
+            self.sift_up(self.heap.len() - 1, &mut cmp);
+        } else if let Some(least) = self.heap.first_mut()
+            // Ties are lost, since the item is seen later.
+            && cmp(&item, &least.1) == Ordering::Greater
+        {
+            *least = (idx, item);
+            self.sift_down(0, &mut cmp);
+        }
+    }
+
+    /// Returns the items from the greatest to the least.
+    pub(super) fn into_sorted(self, mut cmp: impl FnMut(&T, &T) -> Ordering) -> Vec<T> {
+        let mut heap = self.heap;
+        heap.sort_unstable_by(|(idx1, item1), (idx2, item2)| {
+            cmp(item2, item1).then(idx1.cmp(idx2))
+        });
+        heap.into_iter().map(|(_, item)| item).collect()
+    }
+
+    #[inline]
+    fn is_less(
+        (idx1, item1): &(usize, T),
+        (idx2, item2): &(usize, T),
+        cmp: &mut impl FnMut(&T, &T) -> Ordering,
+    ) -> bool {
+        cmp(item1, item2).then(idx2.cmp(idx1)) == Ordering::Less
+    }
+
+    fn sift_up(&mut self, mut pos: usize, cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if !Self::is_less(&self.heap[pos], &self.heap[parent], cmp) {
+                break;
+            }
+            self.heap.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize, cmp: &mut impl FnMut(&T, &T) -> Ordering) {
+        loop {
+            let mut least = pos;
+            for child in [2 * pos + 1, 2 * pos + 2] {
+                if child < self.heap.len()
+                    && Self::is_less(&self.heap[child], &self.heap[least], cmp)
+                {
+                    least = child;
+                }
+            }
+
+            if least == pos {
+                break;
+            }
+            self.heap.swap(pos, least);
+            pos = least;
+        }
+    }
+}
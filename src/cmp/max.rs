@@ -3,7 +3,7 @@ use std::{cmp::Ordering, ops::ControlFlow};
 use super::{MaxBy, MaxByKey, max_assign};
 
 use crate::{
-    collector::{Collector, CollectorBase, assert_collector},
+    collector::{Collector, CollectorBase, SnapshotCollector, assert_collector},
     iter::Fold,
 };
 
@@ -38,6 +38,7 @@ use crate::{
 /// assert_eq!(Max::<i32>::new().finish(), None);
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Max<T> {
     // For `Debug` impl used by `MaxByKey`.
     pub(super) max: Option<T>,
@@ -91,6 +92,16 @@ impl<T> CollectorBase for Max<T> {
     }
 }
 
+impl<T> SnapshotCollector for Max<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.max.clone()
+    }
+}
+
 impl<T: Ord> Collector<T> for Max<T> {
     #[inline]
     fn collect(&mut self, item: T) -> ControlFlow<()> {
@@ -141,7 +152,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::cmp::Max;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::super::test_utils::Id;
 
@@ -37,6 +37,32 @@ use super::{Max, value_key::ValueKey};
 ///
 /// assert_eq!(Max::by_key(|s: &&str| s.len()).finish(), None);
 /// ```
+///
+/// Collecting references finds the record with the highest score
+/// without taking ownership, while the records are also streamed elsewhere.
+///
+/// ```
+/// use komadori::{prelude::*, cmp::Max};
+///
+/// struct Record {
+///     name: &'static str,
+///     score: u32,
+/// }
+///
+/// let records = [
+///     Record { name: "Alice", score: 72 },
+///     Record { name: "Bob", score: 91 },
+///     Record { name: "Carol", score: 85 },
+/// ];
+///
+/// let (best, names) = records.iter().feed_into(
+///     Max::by_key(|record: &&Record| record.score)
+///         .tee(vec![].into_collector().map(|record: &Record| record.name)),
+/// );
+///
+/// assert_eq!(best.map(|record| record.name), Some("Bob"));
+/// assert_eq!(names, ["Alice", "Bob", "Carol"]);
+/// ```
 #[derive(Clone)]
 pub struct MaxByKey<T, K, F> {
     value_key_collector: Max<ValueKey<T, K>>,
@@ -3,7 +3,7 @@ use std::{cmp::Ordering, ops::ControlFlow};
 use super::{MinBy, MinByKey, min_assign};
 
 use crate::{
-    collector::{Collector, CollectorBase, assert_collector},
+    collector::{Collector, CollectorBase, SnapshotCollector, assert_collector},
     iter::Fold,
 };
 
@@ -38,6 +38,7 @@ use crate::{
 /// assert_eq!(Min::<i32>::new().finish(), None);
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Min<T> {
     // For `Debug` impl for `MinByKey`.
     pub(super) min: Option<T>,
@@ -91,6 +92,16 @@ impl<T> CollectorBase for Min<T> {
     }
 }
 
+impl<T> SnapshotCollector for Min<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.min.clone()
+    }
+}
+
 impl<T: Ord> Collector<T> for Min<T> {
     #[inline]
     fn collect(&mut self, item: T) -> ControlFlow<()> {
@@ -138,7 +149,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::cmp::Min;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::super::test_utils::Id;
 
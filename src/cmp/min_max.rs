@@ -40,11 +40,13 @@ use super::{max_assign, min_assign};
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MinMax<T> {
     state: State<T>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum State<T> {
     NoElements,
     OneElement(T),
@@ -313,7 +315,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::super::test_utils::Id;
     use super::*;
@@ -0,0 +1,207 @@
+use std::ops::ControlFlow;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use crate::collector::{Collector, CollectorBase, IntoCollector};
+
+/// A collector that feeds the maximum of each window of the last `k` items
+/// into another collector.
+/// Its [`Output`](CollectorBase::Output) is that of the underlying collector.
+///
+/// Once `k` items have been collected, the maximum of the last `k` items is fed
+/// after each item, so `n` items produce `n - k + 1` maxima.
+/// If several items are maximum, the last one is fed.
+///
+/// It keeps a monotonic deque of at most `k` items, so each item
+/// is compared and cloned in amortized constant time.
+///
+/// This collector stops accumulating once the underlying collector has.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::SlidingWindowMax};
+///
+/// let maxima = [1, 3, -1, -3, 5, 3, 6, 7]
+///     .into_iter()
+///     .feed_into(SlidingWindowMax::new(3, vec![]));
+///
+/// assert_eq!(maxima, [3, 3, 5, 5, 6, 7]);
+/// ```
+///
+/// Alerting on the first spike of a metric:
+///
+/// ```
+/// use komadori::{prelude::*, cmp::SlidingWindowMax, iter::Find};
+///
+/// let latencies_ms = [12, 15, 11, 240, 230, 250, 14, 13];
+///
+/// let first_spike = latencies_ms
+///     .into_iter()
+///     .feed_into(SlidingWindowMax::new(2, Find::new(|&max: &i32| max > 200)));
+///
+/// assert_eq!(first_spike, Some(240));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlidingWindowMax<C, T> {
+    collector: C,
+    k: usize,
+    // Items in the current window that are greater than every later item,
+    // paired with their indices, in decreasing order.
+    deque: VecDeque<(usize, T)>,
+    idx: usize,
+}
+
+impl<C, T> SlidingWindowMax<C, T>
+where
+    C: Collector<T>,
+    T: Ord + Clone,
+{
+    /// Creates a new instance of this collector with the window size of `k`,
+    /// feeding maxima into a collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is 0.
+    #[inline]
+    #[track_caller]
+    pub fn new(k: usize, collector: impl IntoCollector<T, IntoCollector = C>) -> Self {
+        assert!(k != 0, "the window size must be non-zero");
+
+        Self {
+            collector: collector.into_collector(),
+            k,
+            deque: VecDeque::new(),
+            idx: 0,
+        }
+    }
+}
+
+impl<C, T> CollectorBase for SlidingWindowMax<C, T>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        // The first `k - 1` items (of the whole stream) produce nothing.
+        let unfilled = (self.k - 1).saturating_sub(self.idx);
+        self.collector.reserve(
+            additional_min.saturating_sub(unfilled),
+            additional_max.map(|max| max.saturating_sub(unfilled)),
+        );
+    }
+}
+
+impl<C, T> Collector<T> for SlidingWindowMax<C, T>
+where
+    C: Collector<T>,
+    T: Ord + Clone,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        while self.deque.back().is_some_and(|(_, back)| *back <= item) {
+            self.deque.pop_back();
+        }
+        self.deque.push_back((self.idx, item));
+
+        if self
+            .deque
+            .front()
+            .is_some_and(|&(front_idx, _)| front_idx + self.k <= self.idx)
+        {
+            self.deque.pop_front();
+        }
+
+        self.idx += 1;
+
+        if self.idx < self.k {
+            return ControlFlow::Continue(());
+        }
+
+        match self.deque.front() {
+            Some((_, max)) => self.collector.collect(max.clone()),
+            // The item just pushed is always in the deque.
+            None => unreachable!(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(-5..5, ..8),
+            k in 1..=4_usize,
+            take_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, k, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, k: usize, take_count: usize) -> TestCaseResult {
+        let maxima = |nums: &[i32]| -> Vec<i32> {
+            nums.windows(k)
+                .map(|window| *window.iter().max().unwrap())
+                .collect()
+        };
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                SlidingWindowMax::new(k, vec![].into_collector().take(take_count))
+            },
+            should_break_pred: |iter| maxima(&iter.collect::<Vec<_>>()).len() >= take_count,
+            pred: |iter, output, remaining| {
+                let nums: Vec<_> = iter.collect();
+                let expected: Vec<_> = maxima(&nums).into_iter().take(take_count).collect();
+                // The item producing the last fed maximum is the last one consumed.
+                let consumed = if take_count == 0 {
+                    0
+                } else if expected.len() >= take_count {
+                    take_count + k - 1
+                } else {
+                    nums.len()
+                };
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if nums[consumed.min(nums.len())..]
+                    .iter()
+                    .copied()
+                    .ne(remaining)
+                {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,131 @@
+use std::{cmp::Ordering, ops::ControlFlow};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::{TopKBy, TopKByKey, k_selection::KSelection};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that computes the `k` greatest items among the items it collects.
+///
+/// Its [`Output`](CollectorBase::Output) is a [`Vec`] of at most `k` items,
+/// from the greatest to the least.
+/// The selection is stable: among equal items, those collected first are kept
+/// and come first in the output.
+///
+/// It keeps a bounded binary heap of `k` items, so each item is compared
+/// in `O(log k)` time. If `k` is 0, it stops accumulating from construction.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::TopK};
+///
+/// let top = [3, 1, 4, 1, 5, 9, 2, 6].into_iter().feed_into(TopK::new(3));
+///
+/// assert_eq!(top, [9, 6, 5]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+    selection: KSelection<T>,
+}
+
+impl<T> TopK<T> {
+    /// Creates a new instance of this collector, keeping `k` items.
+    #[inline]
+    pub const fn new(k: usize) -> Self
+    where
+        T: Ord,
+    {
+        assert_collector(Self {
+            selection: KSelection::new(k),
+        })
+    }
+
+    /// Creates a new instance of [`TopKBy`] with a given comparison function.
+    #[inline]
+    pub const fn by<F>(k: usize, f: F) -> TopKBy<T, F>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        assert_collector(TopKBy::new(k, f))
+    }
+
+    /// Creates a new instance of [`TopKByKey`] with a given key-extraction function.
+    #[inline]
+    pub const fn by_key<K, F>(k: usize, f: F) -> TopKByKey<T, K, F>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        assert_collector(TopKByKey::new(k, f))
+    }
+}
+
+impl<T: Ord> CollectorBase for TopK<T> {
+    type Output = Vec<T>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.selection.into_sorted(Ord::cmp)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.selection.break_hint()
+    }
+}
+
+impl<T: Ord> Collector<T> for TopK<T> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.selection.push(item, Ord::cmp);
+        self.selection.break_hint()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cmp::Reverse;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=8),
+            k in ..=8_usize,
+        ) {
+            all_collect_methods_impl(nums, k)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, k: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || TopK::new(k),
+            should_break_pred: |_| k == 0,
+            pred: |iter, output, remaining| {
+                let mut expected: Vec<_> = iter.clone().collect();
+                expected.sort_by_key(|&num| Reverse(num));
+                expected.truncate(k);
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != if k == 0 { iter.count() } else { 0 } {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
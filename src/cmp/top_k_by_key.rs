@@ -0,0 +1,139 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::{k_selection::KSelection, value_key::ValueKey};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that computes the `k` items among the items it collects
+/// that give the greatest values from a function.
+///
+/// Its [`Output`](CollectorBase::Output) is a [`Vec`] of at most `k` items,
+/// from the greatest key to the least.
+/// The selection is stable: among items with equal keys, those collected first
+/// are kept and come first in the output.
+///
+/// The key of each item is computed only once.
+///
+/// This collector is constructed by [`TopK::by_key()`](super::TopK::by_key).
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::TopK};
+///
+/// let slowest = [("GET /", 12), ("GET /feed", 230), ("POST /login", 230), ("GET /about", 8)]
+///     .into_iter()
+///     .feed_into(TopK::by_key(2, |&(_, ms): &(&str, u32)| ms));
+///
+/// assert_eq!(slowest, [("GET /feed", 230), ("POST /login", 230)]);
+/// ```
+#[derive(Clone)]
+pub struct TopKByKey<T, K, F> {
+    selection: KSelection<ValueKey<T, K>>,
+    f: F,
+}
+
+impl<T, K, F> TopKByKey<T, K, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    #[inline]
+    pub(super) const fn new(k: usize, f: F) -> Self {
+        Self {
+            selection: KSelection::new(k),
+            f,
+        }
+    }
+}
+
+impl<T, K, F> CollectorBase for TopKByKey<T, K, F>
+where
+    K: Ord,
+{
+    type Output = Vec<T>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.selection
+            .into_sorted(Ord::cmp)
+            .into_iter()
+            .map(ValueKey::into_value)
+            .collect()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.selection.break_hint()
+    }
+}
+
+impl<T, K, F> Collector<T> for TopKByKey<T, K, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.selection
+            .push(ValueKey::new(item, &mut self.f), Ord::cmp);
+        self.selection.break_hint()
+    }
+}
+
+impl<T: Debug, K: Debug, F> Debug for TopKByKey<T, K, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TopKByKey")
+            .field("selection", &self.selection)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cmp::Reverse;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::cmp::TopK;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            keys in propvec(0..3, ..=8),
+            k in ..=8_usize,
+        ) {
+            all_collect_methods_impl(keys, k)?;
+        }
+    }
+
+    // Items are paired with their indices to check that ties keep the first-seen ones.
+    fn all_collect_methods_impl(keys: Vec<i32>, k: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || keys.iter().copied().enumerate(),
+            collector_factory: || TopK::by_key(k, |&(_, key): &(usize, i32)| key),
+            should_break_pred: |_| k == 0,
+            pred: |iter, output, remaining| {
+                let mut expected: Vec<_> = iter.clone().collect();
+                // A stable sort.
+                expected.sort_by_key(|&(_, key)| Reverse(key));
+                expected.truncate(k);
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != if k == 0 { iter.count() } else { 0 } {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -34,11 +34,91 @@ use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
 #[cfg(feature = "alloc")]
 use std::cmp::Ord;
 
+/// Preallocation for collections, used by [`CollectorBase::reserve()`].
+///
+/// Collections without a notion of capacity ignore the hint.
+trait ReserveHint {
+    fn reserve_hint(&mut self, additional: usize);
+}
+
+macro_rules! reserve_hint_impl {
+    (
+        $feature:literal, $coll_name:ident<$($generic:ident),*>, $reserve:expr
+        $(, $gen_bound:ident: $bound:path)* $(,)?
+    ) => {
+        #[cfg(feature = $feature)]
+        impl<$($generic),*> ReserveHint for $coll_name<$($generic),*>
+        where
+            $($gen_bound: $bound,)*
+        {
+            #[inline]
+            fn reserve_hint(&mut self, additional: usize) {
+                ($reserve)(self, additional)
+            }
+        }
+    };
+}
+
+reserve_hint_impl!(
+    "std", HashMap<K, V, S>, HashMap::reserve,
+    K: Hash, K: Eq, S: BuildHasher,
+);
+reserve_hint_impl!(
+    "std", HashSet<T, S>, HashSet::reserve,
+    T: Hash, T: Eq, S: BuildHasher,
+);
+reserve_hint_impl!("alloc", BTreeMap<K, V>, |_, _| {});
+reserve_hint_impl!("alloc", BTreeSet<T>, |_, _| {});
+reserve_hint_impl!("alloc", BinaryHeap<T>, BinaryHeap::reserve);
+reserve_hint_impl!("alloc", LinkedList<T>, |_, _| {});
+reserve_hint_impl!("alloc", VecDeque<T>, VecDeque::reserve);
+
+/// Converts a double-ended collection into collectors that push items into its front.
+///
+/// The collectors from [`IntoCollectorBase`] push items into the back instead.
+///
+/// This trait is sealed and for providing methods only.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use komadori::{prelude::*, collections::IntoCollectorFront};
+///
+/// let (reversed, nums) = [1, 2, 3]
+///     .into_iter()
+///     .feed_into(VecDeque::new().into_collector_front().tee(vec![]));
+///
+/// assert_eq!(reversed, [3, 2, 1]);
+/// assert_eq!(nums, [1, 2, 3]);
+/// ```
+#[allow(private_bounds)]
+pub trait IntoCollectorFront: Sized + IntoCollectorFrontSealed {
+    /// Which collector being produced by [`into_collector_front()`](IntoCollectorFront::into_collector_front)?
+    type IntoCollectorFront: CollectorBase<Output = Self>;
+
+    /// Which collector being produced by [`collector_front_mut()`](IntoCollectorFront::collector_front_mut)?
+    type CollectorFrontMut<'a>: CollectorBase<Output = &'a mut Self>
+    where
+        Self: 'a;
+
+    /// Creates a collector that pushes items into the front of this collection.
+    fn into_collector_front(self) -> Self::IntoCollectorFront;
+
+    /// Creates a collector that pushes items into the front of this collection
+    /// through a mutable reference.
+    fn collector_front_mut(&mut self) -> Self::CollectorFrontMut<'_>;
+}
+
+trait IntoCollectorFrontSealed {}
+
 macro_rules! collector_impl {
     (
         $feature:literal, $mod:ident::$coll_name:ident<$($generic:ident),*>, $item_ty:ty,
         $item_pat:pat_param, $push_method_name:ident($($item_args:expr),*)
         $(, $gen_bound:ident: $bound:path)* $(,)?
+        // Bounds of the `CollectorBase` impls, only needed by `reserve()`.
+        $(; base: $($base_gen_bound:ident: $base_bound:path),+ $(,)?)?
     ) => {
         #[cfg(feature = $feature)]
         // So that doc.rs doesn't put both "std" and "alloc" in feature flag.
@@ -75,13 +155,21 @@ macro_rules! collector_impl {
         #[cfg(feature = $feature)]
         // So that doc.rs doesn't put both "std" and "alloc" in feature flag.
         #[cfg_attr(docsrs, doc(cfg(feature = $feature)))]
-        impl<$($generic),*> CollectorBase for $mod::IntoCollector<$($generic),*> {
+        impl<$($generic),*> CollectorBase for $mod::IntoCollector<$($generic),*>
+        where
+            $($($base_gen_bound: $base_bound,)+)?
+        {
             type Output = $coll_name<$($generic),*>;
 
             #[inline]
             fn finish(self) -> Self::Output {
                 self.0
             }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+                self.0.reserve_hint(additional_min);
+            }
         }
 
         #[cfg(feature = $feature)]
@@ -176,13 +264,21 @@ macro_rules! collector_impl {
         #[cfg(feature = $feature)]
         // So that doc.rs doesn't put both "std" and "alloc" in feature flag.
         #[cfg_attr(docsrs, doc(cfg(feature = $feature)))]
-        impl<'a, $($generic),*> CollectorBase for $mod::CollectorMut<'a, $($generic),*> {
+        impl<'a, $($generic),*> CollectorBase for $mod::CollectorMut<'a, $($generic),*>
+        where
+            $($($base_gen_bound: $base_bound,)+)?
+        {
             type Output = &'a mut $coll_name<$($generic),*>;
 
             #[inline]
             fn finish(self) -> Self::Output {
                 self.0
             }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+                self.0.reserve_hint(additional_min);
+            }
         }
 
         #[cfg(feature = $feature)]
@@ -363,7 +459,8 @@ macro_rules! copy_collector_impl {
 collector_impl!(
     "std", hash_map::HashMap<K, V, S>, (K, V),
     (key, value), insert(key, value),
-    K: Hash, K: Eq, S: BuildHasher,
+    K: Hash, K: Eq, S: BuildHasher;
+    base: K: Hash, K: Eq, S: BuildHasher,
 );
 copy_collector_impl!(
     "std", hash_map::HashMap<'k ,'v; K, V, S>, (&'k K, &'v V),
@@ -381,7 +478,8 @@ copy_collector_impl!(
 collector_impl!(
     "std", hash_set::HashSet<T, S>, T,
     item, insert(item),
-    T: Hash, T: Eq, S: BuildHasher,
+    T: Hash, T: Eq, S: BuildHasher;
+    base: T: Hash, T: Eq, S: BuildHasher,
 );
 copy_collector_impl!(
     "std", hash_set::HashSet<'i; T, S>, &'i T,
@@ -485,3 +583,167 @@ copy_collector_impl!(
     T: Copy,
     |items| items.into_iter().map(|&mut item| item);
 );
+
+macro_rules! front_collector_impl {
+    ($mod:ident::$coll_name:ident) => {
+        #[cfg(feature = "alloc")]
+        impl<T> IntoCollectorFrontSealed for $coll_name<T> {}
+
+        #[cfg(feature = "alloc")]
+        // So that doc.rs doesn't put both "std" and "alloc" in feature flag.
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        impl<T> IntoCollectorFront for $coll_name<T> {
+            type IntoCollectorFront = $mod::IntoCollectorFront<T>;
+
+            type CollectorFrontMut<'a>
+                = $mod::CollectorFrontMut<'a, T>
+            where
+                T: 'a;
+
+            #[inline]
+            fn into_collector_front(self) -> Self::IntoCollectorFront {
+                $mod::IntoCollectorFront(self)
+            }
+
+            #[inline]
+            fn collector_front_mut(&mut self) -> Self::CollectorFrontMut<'_> {
+                $mod::CollectorFrontMut(self)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T> CollectorBase for $mod::IntoCollectorFront<T> {
+            type Output = $coll_name<T>;
+
+            #[inline]
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+                self.0.reserve_hint(additional_min);
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, T> CollectorBase for $mod::CollectorFrontMut<'a, T> {
+            type Output = &'a mut $coll_name<T>;
+
+            #[inline]
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+                self.0.reserve_hint(additional_min);
+            }
+        }
+
+        front_collector_impl!(@collect <> $mod::IntoCollectorFront<T>, T, item, item);
+        front_collector_impl!(@collect <'i> $mod::IntoCollectorFront<T>, &'i T, &item, item, T: Copy);
+        front_collector_impl!(@collect <'i> $mod::IntoCollectorFront<T>, &'i mut T, &mut item, item, T: Copy);
+        front_collector_impl!(@collect <'a> $mod::CollectorFrontMut<'a, T>, T, item, item);
+        front_collector_impl!(@collect <'a, 'i> $mod::CollectorFrontMut<'a, T>, &'i T, &item, item, T: Copy);
+        front_collector_impl!(@collect <'a, 'i> $mod::CollectorFrontMut<'a, T>, &'i mut T, &mut item, item, T: Copy);
+    };
+    (
+        @collect <$($lt:lifetime),*> $collector:ty, $item_ty:ty,
+        $item_pat:pat_param, $item:ident $(, $gen_bound:ident: $bound:path)?
+    ) => {
+        #[cfg(feature = "alloc")]
+        impl<$($lt,)* T> Collector<$item_ty> for $collector
+        where
+            $($gen_bound: $bound,)?
+        {
+            #[inline]
+            fn collect(&mut self, $item_pat: $item_ty) -> ControlFlow<()> {
+                self.0.push_front($item);
+                ControlFlow::Continue(())
+            }
+        }
+    };
+}
+
+front_collector_impl!(linked_list::LinkedList);
+front_collector_impl!(vec_deque::VecDeque);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn vec_deque_front(
+            starting_nums in propvec(any::<i32>(), ..5),
+            nums in propvec(any::<i32>(), ..5),
+        ) {
+            vec_deque_front_impl(starting_nums, nums)?;
+        }
+
+        #[test]
+        fn linked_list_front(
+            starting_nums in propvec(any::<i32>(), ..5),
+            nums in propvec(any::<i32>(), ..5),
+        ) {
+            linked_list_front_impl(starting_nums, nums)?;
+        }
+    }
+
+    fn vec_deque_front_impl(starting_nums: Vec<i32>, nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || VecDeque::from(starting_nums.clone()).into_collector_front(),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut expected: Vec<_> = iter.collect();
+                expected.reverse();
+                expected.extend(&starting_nums);
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn linked_list_front_impl(starting_nums: Vec<i32>, nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter(),
+            collector_factory: || {
+                starting_nums
+                    .iter()
+                    .copied()
+                    .collect::<LinkedList<_>>()
+                    .into_collector_front()
+            },
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut expected: Vec<_> = iter.copied().collect();
+                expected.reverse();
+                expected.extend(&starting_nums);
+
+                if !output.iter().eq(&expected) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -34,6 +34,15 @@ use std::collections::BinaryHeap;
 ///
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(
+        serialize = "BinaryHeap<T>: serde::Serialize",
+        deserialize = "BinaryHeap<T>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct IntoCollector<T>(pub(super) BinaryHeap<T>);
 
 /// A collector that pushes collected items into a [`&mut BinaryHeap`](BinaryHeap).
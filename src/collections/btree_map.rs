@@ -2,10 +2,14 @@
 //!
 //! This module corresponds to [`std::collections::btree_map`].
 
+use std::{fmt::Debug, ops::ControlFlow};
+
 #[cfg(not(feature = "std"))]
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, btree_map::Entry};
 #[cfg(feature = "std")]
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, btree_map::Entry};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
 
 // #[cfg(all(not(feature = "std"), feature = "unstable"))]
 // use alloc::collections::btree_map::{Entry, OccupiedEntry, VacantEntry};
@@ -22,6 +26,15 @@ use std::collections::BTreeMap;
 ///
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(
+        serialize = "BTreeMap<K, V>: serde::Serialize",
+        deserialize = "BTreeMap<K, V>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct IntoCollector<K, V>(pub(super) BTreeMap<K, V>);
 
 /// A collector that inserts collected items into a [`&mut BTreeMap`](BTreeMap).
@@ -108,3 +121,137 @@ pub struct CollectorMut<'a, K, V>(pub(super) &'a mut BTreeMap<K, V>);
 //         }
 //     }
 // }
+
+/// A collector that inserts collected key-value pairs into a [`BTreeMap`],
+/// resolving duplicate keys with a function.
+/// Its [`Output`] is [`BTreeMap`].
+///
+/// When a key is already present, the function is called with a mutable reference
+/// to the existing value and the new value, instead of overwriting the existing one.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use komadori::{prelude::*, collections::btree_map::MergeWith};
+///
+/// let totals = [("apple", 3), ("pear", 2), ("apple", 4)]
+///     .into_iter()
+///     .feed_into(MergeWith::new(BTreeMap::new(), |total, units| *total += units));
+///
+/// assert_eq!(totals, BTreeMap::from([("apple", 7), ("pear", 2)]));
+///
+/// // Keeps the earliest value.
+/// let first_seen = [("alice", 1), ("bob", 2), ("alice", 3)]
+///     .into_iter()
+///     .feed_into(MergeWith::new(BTreeMap::new(), |_, _| {}));
+///
+/// assert_eq!(first_seen, BTreeMap::from([("alice", 1), ("bob", 2)]));
+/// ```
+///
+/// [`Output`]: crate::collector::CollectorBase::Output
+#[derive(Clone)]
+pub struct MergeWith<K, V, F> {
+    map: BTreeMap<K, V>,
+    f: F,
+}
+
+impl<K, V, F> MergeWith<K, V, F>
+where
+    K: Ord,
+{
+    /// Creates a new instance of this collector inserting into the given map,
+    /// with a function that merges a new value into the existing one of the same key.
+    #[inline]
+    pub fn new(map: BTreeMap<K, V>, f: F) -> Self
+    where
+        F: FnMut(&mut V, V),
+    {
+        assert_collector::<_, (K, V)>(Self { map, f })
+    }
+}
+
+impl<K, V, F> CollectorBase for MergeWith<K, V, F>
+where
+    K: Ord,
+{
+    type Output = BTreeMap<K, V>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.map
+    }
+}
+
+impl<K, V, F> Collector<(K, V)> for MergeWith<K, V, F>
+where
+    K: Ord,
+    F: FnMut(&mut V, V),
+{
+    #[inline]
+    fn collect(&mut self, (key, value): (K, V)) -> ControlFlow<()> {
+        match self.map.entry(key) {
+            Entry::Occupied(mut entry) => (self.f)(entry.get_mut(), value),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<K, V, F> Debug for MergeWith<K, V, F>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeWith").field("map", &self.map).finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::collections::BTreeMap as Model;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            pairs in propvec((..5_u8, any::<i16>()), ..9),
+        ) {
+            all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    fn all_collect_methods_impl(pairs: Vec<(u8, i16)>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || pairs.iter().map(|&(key, value)| (key, i32::from(value))),
+            collector_factory: || MergeWith::new(BTreeMap::new(), |total, value| *total += value),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut expected = Model::new();
+                for (key, value) in iter {
+                    *expected.entry(key).or_insert(0) += value;
+                }
+
+                if output.into_iter().collect::<Model<_, _>>() != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -14,6 +14,15 @@ use std::collections::BTreeSet;
 ///
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(
+        serialize = "BTreeSet<T>: serde::Serialize",
+        deserialize = "BTreeSet<T>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct IntoCollector<T>(pub(super) BTreeSet<T>);
 
 /// A collector that inserts collected items into a [`&mut BTreeSet`](BTreeSet).
@@ -2,7 +2,15 @@
 //!
 //! This module corresponds to [`std::collections::hash_map`].
 
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fmt::Debug,
+    hash::{BuildHasher, Hash, RandomState},
+    ops::ControlFlow,
+};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
 // #[cfg(feature = "unstable")]
 // use std::{
 //     collections::hash_map::{Entry, OccupiedEntry, VacantEntry},
@@ -19,6 +27,15 @@ use std::collections::HashMap;
 ///
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(
+        serialize = "HashMap<K, V, S>: serde::Serialize",
+        deserialize = "HashMap<K, V, S>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct IntoCollector<K, V, S>(pub(super) HashMap<K, V, S>);
 
 /// A collector that inserts collected items into a [`&mut HashMap`](HashMap).
@@ -99,3 +116,260 @@ pub struct CollectorMut<'a, K, V, S>(pub(super) &'a mut HashMap<K, V, S>);
 //         }
 //     }
 // }
+
+/// A collector that inserts collected key-value pairs into a [`HashMap`],
+/// resolving duplicate keys with a function.
+/// Its [`Output`] is [`HashMap`].
+///
+/// When a key is already present, the function is called with a mutable reference
+/// to the existing value and the new value, instead of overwriting the existing one.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use komadori::{prelude::*, collections::hash_map::MergeWith};
+///
+/// let totals = [("apple", 3), ("pear", 2), ("apple", 4)]
+///     .into_iter()
+///     .feed_into(MergeWith::new(HashMap::new(), |total, units| *total += units));
+///
+/// assert_eq!(totals, HashMap::from([("apple", 7), ("pear", 2)]));
+///
+/// // Keeps the earliest value.
+/// let first_seen = [("alice", 1), ("bob", 2), ("alice", 3)]
+///     .into_iter()
+///     .feed_into(MergeWith::new(HashMap::new(), |_, _| {}));
+///
+/// assert_eq!(first_seen, HashMap::from([("alice", 1), ("bob", 2)]));
+/// ```
+///
+/// [`Output`]: crate::collector::CollectorBase::Output
+#[derive(Clone)]
+pub struct MergeWith<K, V, S, F> {
+    map: HashMap<K, V, S>,
+    f: F,
+}
+
+impl<K, V, S, F> MergeWith<K, V, S, F>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Creates a new instance of this collector inserting into the given map,
+    /// with a function that merges a new value into the existing one of the same key.
+    #[inline]
+    pub fn new(map: HashMap<K, V, S>, f: F) -> Self
+    where
+        F: FnMut(&mut V, V),
+    {
+        assert_collector::<_, (K, V)>(Self { map, f })
+    }
+}
+
+impl<K, V, S, F> CollectorBase for MergeWith<K, V, S, F>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = HashMap<K, V, S>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.map
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        self.map.reserve(additional_min);
+    }
+}
+
+impl<K, V, S, F> Collector<(K, V)> for MergeWith<K, V, S, F>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+    F: FnMut(&mut V, V),
+{
+    #[inline]
+    fn collect(&mut self, (key, value): (K, V)) -> ControlFlow<()> {
+        match self.map.entry(key) {
+            Entry::Occupied(mut entry) => (self.f)(entry.get_mut(), value),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<K, V, S, F> Debug for MergeWith<K, V, S, F>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeWith").field("map", &self.map).finish()
+    }
+}
+
+/// A collector that groups collected key-value pairs into a [`HashMap`]
+/// from keys to containers of values, such as `HashMap<K, Vec<V>>`.
+/// Its [`Output`] is [`HashMap`].
+///
+/// Values of the same key are [`extend`](Extend::extend)ed into the same container,
+/// which is created with [`Default`] on the first occurrence of the key.
+/// [`Vec`] is the default container, but any container
+/// implementing both [`Default`] and [`Extend`] works.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::{BTreeSet, HashMap};
+/// use komadori::{prelude::*, collections::hash_map::Multi};
+///
+/// let pairs = [("fruit", "apple"), ("veggie", "leek"), ("fruit", "pear"), ("fruit", "apple")];
+///
+/// let groups = pairs.into_iter().feed_into(Multi::new());
+///
+/// assert_eq!(groups["fruit"], ["apple", "pear", "apple"]);
+/// assert_eq!(groups["veggie"], ["leek"]);
+///
+/// // With another container.
+/// let groups = pairs
+///     .into_iter()
+///     .feed_into(Multi::with_map(HashMap::<_, BTreeSet<_>>::new()));
+///
+/// assert_eq!(groups["fruit"], BTreeSet::from(["apple", "pear"]));
+/// ```
+///
+/// [`Output`]: crate::collector::CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct Multi<K, C, S = RandomState> {
+    map: HashMap<K, C, S>,
+}
+
+impl<K, V> Multi<K, Vec<V>> {
+    /// Creates a new instance of this collector grouping values into [`Vec`]s.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+}
+
+impl<K, C, S> Multi<K, C, S> {
+    /// Creates a new instance of this collector grouping values into the given map.
+    #[inline]
+    pub const fn with_map(map: HashMap<K, C, S>) -> Self {
+        Self { map }
+    }
+}
+
+impl<K, V> Default for Multi<K, Vec<V>> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, C, S> CollectorBase for Multi<K, C, S> {
+    type Output = HashMap<K, C, S>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.map
+    }
+}
+
+impl<K, V, C, S> Collector<(K, V)> for Multi<K, C, S>
+where
+    K: Hash + Eq,
+    C: Default + Extend<V>,
+    S: BuildHasher,
+{
+    #[inline]
+    fn collect(&mut self, (key, value): (K, V)) -> ControlFlow<()> {
+        self.map.entry(key).or_default().extend(Some(value));
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::collections::BTreeMap as Model;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            pairs in propvec((..5_u8, any::<i16>()), ..9),
+        ) {
+            all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    fn all_collect_methods_impl(pairs: Vec<(u8, i16)>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || pairs.iter().map(|&(key, value)| (key, i32::from(value))),
+            collector_factory: || MergeWith::new(HashMap::new(), |total, value| *total += value),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut expected = Model::new();
+                for (key, value) in iter {
+                    *expected.entry(key).or_insert(0) += value;
+                }
+
+                if output.into_iter().collect::<Model<_, _>>() != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    proptest! {
+        #[test]
+        fn multi_all_collect_methods(
+            pairs in propvec((..5_u8, any::<i16>()), ..9),
+        ) {
+            multi_all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    fn multi_all_collect_methods_impl(pairs: Vec<(u8, i16)>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || pairs.iter().copied(),
+            collector_factory: Multi::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut expected = Model::<_, Vec<_>>::new();
+                for (key, value) in iter {
+                    expected.entry(key).or_default().push(value);
+                }
+
+                if output.into_iter().collect::<Model<_, _>>() != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
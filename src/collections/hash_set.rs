@@ -11,6 +11,15 @@ use std::collections::HashSet;
 ///
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(
+        serialize = "HashSet<T, S>: serde::Serialize",
+        deserialize = "HashSet<T, S>: serde::Deserialize<'de>"
+    ))
+)]
 pub struct IntoCollector<T, S>(pub(super) HashSet<T, S>);
 
 /// A collector that inserts collected items into a [`&mut HashSet`](HashSet).
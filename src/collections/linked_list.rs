@@ -14,6 +14,11 @@ use std::collections::LinkedList;
 ///
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct IntoCollector<T>(pub(super) LinkedList<T>);
 
 /// A collector that pushes collected items into the back of a [`&mut LinkedList`](LinkedList).
@@ -24,3 +29,30 @@ pub struct IntoCollector<T>(pub(super) LinkedList<T>);
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug)]
 pub struct CollectorMut<'a, T>(pub(super) &'a mut LinkedList<T>);
+
+/// A collector that pushes collected items into the front of a [`LinkedList`].
+/// Its [`Output`] is [`LinkedList`].
+///
+/// Items end up in the reverse order of collection.
+///
+/// This struct is created by `LinkedList::into_collector_front()`.
+///
+/// [`Output`]: crate::collector::CollectorBase::Output
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct IntoCollectorFront<T>(pub(super) LinkedList<T>);
+
+/// A collector that pushes collected items into the front of a [`&mut LinkedList`](LinkedList).
+/// Its [`Output`] is [`&mut LinkedList`](LinkedList).
+///
+/// Items end up in the reverse order of collection.
+///
+/// This struct is created by `LinkedList::collector_front_mut()`.
+///
+/// [`Output`]: crate::collector::CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorFrontMut<'a, T>(pub(super) &'a mut LinkedList<T>);
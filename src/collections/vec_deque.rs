@@ -14,6 +14,11 @@ use std::collections::VecDeque;
 ///
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct IntoCollector<T>(pub(super) VecDeque<T>);
 
 /// A collector that pushes collected items into the back of a [`&mut VecDeque`](VecDeque).
@@ -24,3 +29,30 @@ pub struct IntoCollector<T>(pub(super) VecDeque<T>);
 /// [`Output`]: crate::collector::CollectorBase::Output
 #[derive(Debug)]
 pub struct CollectorMut<'a, T>(pub(super) &'a mut VecDeque<T>);
+
+/// A collector that pushes collected items into the front of a [`VecDeque`].
+/// Its [`Output`] is [`VecDeque`].
+///
+/// Items end up in the reverse order of collection.
+///
+/// This struct is created by `VecDeque::into_collector_front()`.
+///
+/// [`Output`]: crate::collector::CollectorBase::Output
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct IntoCollectorFront<T>(pub(super) VecDeque<T>);
+
+/// A collector that pushes collected items into the front of a [`&mut VecDeque`](VecDeque).
+/// Its [`Output`] is [`&mut VecDeque`](VecDeque).
+///
+/// Items end up in the reverse order of collection.
+///
+/// This struct is created by `VecDeque::collector_front_mut()`.
+///
+/// [`Output`]: crate::collector::CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorFrontMut<'a, T>(pub(super) &'a mut VecDeque<T>);
@@ -45,6 +45,8 @@
 //! complex reduction patterns.
 //!
 //! All adapters are defined on [`CollectorBase`] and nowhere else.
+//! To list them in the order items go through them instead,
+//! start from [`pipeline()`].
 //!
 //! There are different adapters to "tee" items into multiple collectors
 //! (let each item be collected by multiple collectors).
@@ -197,21 +199,50 @@
 //! [`Break(())`]: std::ops::ControlFlow::Break
 
 mod adapters;
+mod as_extend;
+#[cfg(feature = "alloc")]
+mod box_collector;
 #[allow(clippy::module_inception)]
 mod collector;
 mod collector_base;
 mod collector_by_mut;
 mod collector_by_ref;
+#[cfg(feature = "alloc")]
+mod dyn_collector;
+#[cfg(feature = "itertools")]
+mod either;
+mod extend_collector;
 mod into_collector;
+mod option;
+pub mod pipeline;
+mod shared_state;
 mod sink;
+mod snapshot_collector;
+#[cfg(feature = "alloc")]
+mod spread;
 
 pub use adapters::*;
+pub use as_extend::*;
+#[cfg(feature = "alloc")]
+pub use box_collector::*;
 pub use collector::*;
 pub use collector_base::*;
 pub use collector_by_mut::*;
 pub use collector_by_ref::*;
+#[cfg(feature = "alloc")]
+pub use dyn_collector::*;
+pub use extend_collector::*;
 pub use into_collector::*;
+pub use pipeline::pipeline;
+pub use shared_state::*;
 pub use sink::*;
+pub use snapshot_collector::*;
+#[cfg(feature = "alloc")]
+pub use spread::*;
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use komadori_derive::Collector;
 
 #[inline(always)]
 pub(crate) const fn assert_collector_base<C>(collector: C) -> C
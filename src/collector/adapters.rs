@@ -1,63 +1,135 @@
 #[cfg(feature = "unstable")]
 mod alt_break_hint;
+mod alternate;
+mod array_chunks;
+mod cancel_on;
+mod cancel_when;
 mod chain;
+mod checkpoint_every;
+mod chunk_by;
 mod cloning;
+mod context;
+mod convert;
 mod copying;
+mod dedup;
+mod dedup_by_key;
+#[cfg(feature = "std")]
+mod detach;
+mod enumerate;
 mod filter;
+mod filter_with;
+mod finalize_on_drop;
 mod flat_map;
 mod flatten;
 mod funnel;
 mod fuse;
 mod inspect;
+mod inspect_output;
+#[cfg(feature = "std")]
+mod instrument;
 mod map;
 mod map_output;
-#[cfg(feature = "unstable")]
+mod map_with;
+mod named_output;
 mod nest_family;
+mod on_break;
 mod partition;
 #[cfg(feature = "itertools")]
 mod partition_map;
+mod partition_result;
+mod sample_adaptive;
 mod skip;
+mod split_when;
 mod take;
+mod take_budget;
+#[cfg(feature = "std")]
+mod take_until;
 mod take_while;
 mod tee;
 mod tee_clone;
 mod tee_funnel;
+mod tee_map;
 mod tee_mut;
 #[cfg(feature = "unstable")]
 mod tee_with;
+mod try_convert;
 mod unbatching;
+#[cfg(feature = "std")]
+mod unique;
+#[cfg(feature = "std")]
+mod unique_by;
 mod unzip;
 #[cfg(feature = "itertools")]
 mod update;
+mod weigh_by;
+#[cfg(feature = "alloc")]
+mod windows;
+mod zip_with;
 
 #[cfg(feature = "unstable")]
 pub use alt_break_hint::*;
+pub use alternate::*;
+pub use array_chunks::*;
+pub use cancel_on::*;
+pub use cancel_when::*;
 pub use chain::*;
+pub use checkpoint_every::*;
+pub use chunk_by::*;
 pub use cloning::*;
+pub use context::*;
+pub use convert::*;
 pub use copying::*;
+pub use dedup::*;
+pub use dedup_by_key::*;
+#[cfg(feature = "std")]
+pub use detach::*;
+pub use enumerate::*;
 pub use filter::*;
+pub use filter_with::*;
+pub use finalize_on_drop::*;
 pub use flat_map::*;
 pub use flatten::*;
 pub use funnel::*;
 pub use fuse::*;
 pub use inspect::*;
+pub use inspect_output::*;
+#[cfg(feature = "std")]
+pub use instrument::*;
 pub use map::*;
 pub use map_output::*;
-#[cfg(feature = "unstable")]
+pub use map_with::*;
+pub use named_output::*;
 pub use nest_family::*;
+pub use on_break::*;
 pub use partition::*;
 #[cfg(feature = "itertools")]
 pub use partition_map::*;
+pub use partition_result::*;
+pub use sample_adaptive::*;
 pub use skip::*;
+pub use split_when::*;
 pub use take::*;
+pub use take_budget::*;
+#[cfg(feature = "std")]
+pub use take_until::*;
 pub use take_while::*;
 pub use tee::*;
 pub use tee_clone::*;
 pub use tee_funnel::*;
+pub use tee_map::*;
 pub use tee_mut::*;
 #[cfg(feature = "unstable")]
 pub use tee_with::*;
+pub use try_convert::*;
 pub use unbatching::*;
+#[cfg(feature = "std")]
+pub use unique::*;
+#[cfg(feature = "std")]
+pub use unique_by::*;
 pub use unzip::*;
 #[cfg(feature = "itertools")]
 pub use update::*;
+pub use weigh_by::*;
+#[cfg(feature = "alloc")]
+pub use windows::*;
+pub use zip_with::*;
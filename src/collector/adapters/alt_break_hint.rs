@@ -34,6 +34,11 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         (self.f)(&self.collector)
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
 }
 
 impl<C, T, F> Collector<T> for AltBreakHint<C, F>
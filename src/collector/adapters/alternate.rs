@@ -0,0 +1,147 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase, Fuse};
+
+/// A collector that sends items to two collectors in turn.
+///
+/// This `struct` is created by [`CollectorBase::alternate()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Alternate<C1, C2> {
+    // `Fuse` is necessary since a stopped collector still has its turns.
+    first: Fuse<C1>,
+    second: Fuse<C2>,
+    // Whether the next item goes to the second collector.
+    second_next: bool,
+}
+
+impl<C1, C2> Alternate<C1, C2>
+where
+    C1: CollectorBase,
+    C2: CollectorBase,
+{
+    pub(in crate::collector) fn new(first: C1, second: C2) -> Self {
+        Self {
+            first: Fuse::new(first),
+            second: Fuse::new(second),
+            second_next: false,
+        }
+    }
+}
+
+#[inline]
+fn both_break(cf1: ControlFlow<()>, cf2: ControlFlow<()>) -> ControlFlow<()> {
+    if cf1.is_break() && cf2.is_break() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<C1, C2> CollectorBase for Alternate<C1, C2>
+where
+    C1: CollectorBase,
+    C2: CollectorBase,
+{
+    type Output = (C1::Output, C2::Output);
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        (self.first.finish(), self.second.finish())
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        both_break(self.first.break_hint(), self.second.break_hint())
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        // The collector whose turn is next gets the extra item of an odd count.
+        let (first_extra, second_extra) = if self.second_next { (0, 1) } else { (1, 0) };
+        let half = |additional: usize, extra: usize| additional / 2 + (additional % 2).min(extra);
+
+        self.first.reserve(
+            half(additional_min, first_extra),
+            additional_max.map(|max| half(max, first_extra)),
+        );
+        self.second.reserve(
+            half(additional_min, second_extra),
+            additional_max.map(|max| half(max, second_extra)),
+        );
+    }
+}
+
+impl<C1, C2, T> Collector<T> for Alternate<C1, C2>
+where
+    C1: Collector<T>,
+    C2: Collector<T>,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let second_next = self.second_next;
+        self.second_next = !second_next;
+
+        if second_next {
+            both_break(self.second.collect(item), self.first.break_hint())
+        } else {
+            both_break(self.first.collect(item), self.second.break_hint())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=8),
+            first_count in ..=5_usize,
+            second_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, first_count, second_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        first_count: usize,
+        second_count: usize,
+    ) -> TestCaseResult {
+        // The number of items until both have had enough turns.
+        let stop_at = (2 * first_count).saturating_sub(1).max(2 * second_count);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(first_count)
+                    .alternate(vec![].into_collector().take(second_count))
+            },
+            should_break_pred: |iter| iter.count() >= stop_at,
+            pred: |mut iter, (firsts, seconds), remaining| {
+                let consumed: Vec<_> = iter.by_ref().take(stop_at).collect();
+                let expected_firsts = consumed.iter().step_by(2).take(first_count);
+                let expected_seconds = consumed.iter().skip(1).step_by(2).take(second_count);
+
+                if expected_firsts.ne(&firsts) || expected_seconds.ne(&seconds) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,129 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase, Fuse};
+
+/// A collector that groups items into arrays of `N` items, then collects the arrays.
+///
+/// This `struct` is created by [`CollectorBase::array_chunks()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct ArrayChunks<C, T, const N: usize> {
+    // Fused so that the buffer is not filled after the collector has stopped.
+    collector: Fuse<C>,
+    // The first `len` slots are filled.
+    buf: [Option<T>; N],
+    len: usize,
+}
+
+impl<C, T, const N: usize> ArrayChunks<C, T, N>
+where
+    C: CollectorBase,
+{
+    #[track_caller]
+    pub(in crate::collector) fn new(collector: C) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        Self {
+            collector: Fuse::new(collector),
+            buf: std::array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+}
+
+impl<C, T, const N: usize> CollectorBase for ArrayChunks<C, T, N>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        // The remainder is discarded, similar to `[_]::chunks_exact()`.
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        let chunks = |additional: usize| self.len.saturating_add(additional) / N;
+        self.collector
+            .reserve(chunks(additional_min), additional_max.map(chunks));
+    }
+}
+
+impl<C, T, const N: usize> Collector<T> for ArrayChunks<C, T, N>
+where
+    C: Collector<[T; N]>,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.collector.break_hint()?;
+
+        self.buf[self.len] = Some(item);
+        self.len += 1;
+
+        if self.len < N {
+            return ControlFlow::Continue(());
+        }
+
+        self.len = 0;
+        // All slots have just been filled.
+        let chunk = self.buf.each_mut().map(|slot| slot.take().unwrap());
+        self.collector.collect(chunk)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=12),
+            take_count in ..=4_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .array_chunks::<3, _>()
+            },
+            should_break_pred: |iter| take_count == 0 || iter.count() >= take_count * 3,
+            pred: |iter, output, remaining| {
+                let expected: Vec<_> = nums
+                    .chunks_exact(3)
+                    .take(take_count)
+                    .map(|chunk| [chunk[0], chunk[1], chunk[2]])
+                    .collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(take_count * 3).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
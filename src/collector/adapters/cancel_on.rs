@@ -0,0 +1,77 @@
+use std::{
+    ops::ControlFlow,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+use super::cancel_when::{collect, collect_many, until_cancelled};
+
+/// A collector that stops accumulating once a flag is set.
+///
+/// This `struct` is created by [`CollectorBase::cancel_on()`].
+/// See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct CancelOn<'a, C> {
+    collector: C,
+    flag: &'a AtomicBool,
+}
+
+impl<'a, C> CancelOn<'a, C> {
+    pub(in crate::collector) fn new(collector: C, flag: &'a AtomicBool) -> Self {
+        Self { collector, flag }
+    }
+
+    #[inline]
+    fn cancelled(flag: &AtomicBool) -> impl Fn() -> bool {
+        move || flag.load(Ordering::Acquire)
+    }
+}
+
+impl<C> CollectorBase for CancelOn<'_, C>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.flag.load(Ordering::Acquire) {
+            ControlFlow::Break(())
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, T> Collector<T> for CancelOn<'_, C>
+where
+    C: Collector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        collect(&mut self.collector, item, Self::cancelled(self.flag))
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        collect_many(&mut self.collector, items, Self::cancelled(self.flag))
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector
+            .collect_then_finish(until_cancelled(items, Self::cancelled(self.flag)))
+    }
+}
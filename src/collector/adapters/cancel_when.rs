@@ -0,0 +1,182 @@
+use std::{fmt::Debug, iter, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that stops accumulating once a closure returns `true`.
+///
+/// This `struct` is created by [`CollectorBase::cancel_when()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct CancelWhen<C, F> {
+    collector: C,
+    cancelled: F,
+}
+
+impl<C, F> CancelWhen<C, F> {
+    pub(in crate::collector) fn new(collector: C, cancelled: F) -> Self {
+        Self {
+            collector,
+            cancelled,
+        }
+    }
+}
+
+impl<C, F> CollectorBase for CancelWhen<C, F>
+where
+    C: CollectorBase,
+    F: Fn() -> bool,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if (self.cancelled)() {
+            ControlFlow::Break(())
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, T, F> Collector<T> for CancelWhen<C, F>
+where
+    C: Collector<T>,
+    F: Fn() -> bool,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        collect(&mut self.collector, item, &self.cancelled)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        collect_many(&mut self.collector, items, &self.cancelled)
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector
+            .collect_then_finish(until_cancelled(items, &self.cancelled))
+    }
+}
+
+impl<C: Debug, F> Debug for CancelWhen<C, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancelWhen")
+            .field("collector", &self.collector)
+            .finish_non_exhaustive()
+    }
+}
+
+// Shared with `CancelOn`.
+
+#[inline]
+pub(super) fn collect<C, T>(
+    collector: &mut C,
+    item: T,
+    cancelled: impl Fn() -> bool,
+) -> ControlFlow<()>
+where
+    C: Collector<T>,
+{
+    if cancelled() {
+        return ControlFlow::Break(());
+    }
+
+    let cf = collector.collect(item);
+    // Checked again so that the caller stops before pulling the next item.
+    if cancelled() {
+        ControlFlow::Break(())
+    } else {
+        cf
+    }
+}
+
+#[inline]
+pub(super) fn collect_many<C, T>(
+    collector: &mut C,
+    items: impl IntoIterator<Item = T>,
+    cancelled: impl Fn() -> bool,
+) -> ControlFlow<()>
+where
+    C: Collector<T>,
+{
+    let cf = collector.collect_many(until_cancelled(items, &cancelled));
+    if cancelled() {
+        ControlFlow::Break(())
+    } else {
+        cf
+    }
+}
+
+// Checked before pulling, so that no item is pulled only to be dropped.
+#[inline]
+pub(super) fn until_cancelled<T>(
+    items: impl IntoIterator<Item = T>,
+    cancelled: impl Fn() -> bool,
+) -> impl Iterator<Item = T> {
+    let mut items = items.into_iter();
+    iter::from_fn(move || if cancelled() { None } else { items.next() })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cell::Cell;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::inspect()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            cancel_after in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, cancel_after)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, cancel_after: usize) -> TestCaseResult {
+        let collected = Cell::new(0);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                collected.set(0);
+                vec![]
+                    .into_collector()
+                    .inspect(|_| collected.set(collected.get() + 1))
+                    .cancel_when(|| collected.get() >= cancel_after)
+            },
+            should_break_pred: |iter| iter.count() >= cancel_after,
+            pred: |mut iter, output, remaining| {
+                if iter.by_ref().take(cancel_after).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
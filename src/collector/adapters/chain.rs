@@ -7,6 +7,7 @@ use crate::collector::{Collector, CollectorBase, Fuse};
 ///
 /// This `struct` is created by [`CollectorBase::chain()`]. See its documentation for more.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chain<C1, C2> {
     collector1: Fuse<C1>,
     collector2: C2,
@@ -52,6 +53,13 @@ where
             ControlFlow::Continue(())
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // The first collector may stop at any point.
+        self.collector1.reserve(0, additional_max);
+        self.collector2.reserve(0, additional_max);
+    }
 }
 
 impl<T, C1, C2> Collector<T> for Chain<C1, C2>
@@ -98,7 +106,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
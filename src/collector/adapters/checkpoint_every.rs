@@ -0,0 +1,145 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase, Fuse};
+
+/// A collector that periodically feeds clones of the underlying collector
+/// to another collector.
+///
+/// This `struct` is created by [`CollectorBase::checkpoint_every()`].
+/// See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct CheckpointEvery<C, S> {
+    collector: C,
+    sink: Fuse<S>,
+    every: usize,
+    // Items collected since the last checkpoint.
+    count: usize,
+}
+
+impl<C, S> CheckpointEvery<C, S>
+where
+    S: CollectorBase,
+{
+    pub(in crate::collector) fn new(collector: C, every: usize, sink: S) -> Self {
+        assert!(every != 0, "the checkpoint interval must be non-zero");
+
+        Self {
+            collector,
+            sink: Fuse::new(sink),
+            every,
+            count: 0,
+        }
+    }
+}
+
+impl<C, S> CollectorBase for CheckpointEvery<C, S>
+where
+    C: CollectorBase,
+    S: CollectorBase,
+{
+    type Output = (C::Output, S::Output);
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        (self.collector.finish(), self.sink.finish())
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+        self.sink.reserve(
+            additional_min / self.every,
+            additional_max.map(|max| max / self.every),
+        );
+    }
+}
+
+impl<C, S, T> Collector<T> for CheckpointEvery<C, S>
+where
+    C: Collector<T> + Clone,
+    S: Collector<C>,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let cf = self.collector.collect(item);
+
+        self.count += 1;
+        if self.count == self.every {
+            self.count = 0;
+            // The sink stopping does not affect the underlying collector.
+            // It merely receives no more checkpoints.
+            if self.sink.break_hint().is_continue() {
+                let _ = self.sink.collect(self.collector.clone());
+            }
+        }
+
+        cf
+    }
+
+    // The default implementations for `collect_many` and `collect_then_finish` are sufficient,
+    // since every item may be followed by a checkpoint.
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=9),
+            take_count in ..=9_usize,
+            every in 1..=4_usize,
+            sink_take_count in ..=3_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count, every, sink_take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        take_count: usize,
+        every: usize,
+        sink_take_count: usize,
+    ) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .checkpoint_every(every, vec![].into_collector().take(sink_take_count))
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let consumed: Vec<_> = iter.by_ref().take(take_count).collect();
+                let snapshots: Vec<_> = (1..=consumed.len())
+                    .filter(|len| len % every == 0)
+                    .take(sink_take_count)
+                    .map(|len| consumed[..len].to_vec())
+                    .collect();
+                let (output, checkpoints) = output;
+                let checkpoints: Vec<_> =
+                    checkpoints.into_iter().map(CollectorBase::finish).collect();
+
+                if (output, checkpoints) != (consumed, snapshots) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
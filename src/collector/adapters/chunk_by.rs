@@ -0,0 +1,172 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, Fuse};
+
+/// A collector that groups consecutive items with equal keys,
+/// then collects the key and the output of each group.
+///
+/// This `struct` is created by [`CollectorBase::chunk_by()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct ChunkBy<CO, CI, F, K> {
+    // The outer may stop while a group is being collected,
+    // after which the remaining group must not be collected on finishing.
+    outer: Fuse<CO>,
+    inner_cloner: CI,
+    // Fused since it may stop before the group ends.
+    group: Option<(K, Fuse<CI>)>,
+    f: F,
+}
+
+impl<CO, CI, F, K> ChunkBy<CO, CI, F, K>
+where
+    CO: CollectorBase,
+    CI: CollectorBase + Clone,
+{
+    pub(in crate::collector) fn new(outer: CO, inner: CI, f: F) -> Self {
+        Self {
+            outer: Fuse::new(outer),
+            inner_cloner: inner,
+            group: None,
+            f,
+        }
+    }
+}
+
+impl<CO, CI, F, K> CollectorBase for ChunkBy<CO, CI, F, K>
+where
+    CO: Collector<(K, CI::Output)>,
+    CI: CollectorBase + Clone,
+{
+    type Output = CO::Output;
+
+    fn finish(mut self) -> Self::Output {
+        // Unlike `split_when()`, there is no group if no items were collected.
+        if let Some((key, inner)) = self.group {
+            let _ = self.outer.collect((key, inner.finish()));
+        }
+        self.outer.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.outer.break_hint()
+    }
+}
+
+impl<CO, CI, F, K, T> Collector<T> for ChunkBy<CO, CI, F, K>
+where
+    CO: Collector<(K, CI::Output)>,
+    CI: Collector<T> + Clone,
+    F: FnMut(&T) -> K,
+    K: PartialEq,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let key = (self.f)(&item);
+
+        match &mut self.group {
+            Some((group_key, inner)) if *group_key == key => {
+                // The inner stopping only ends the current group, not this collector.
+                let _ = inner.collect(item);
+                ControlFlow::Continue(())
+            }
+            group => {
+                if let Some((group_key, inner)) = group.take() {
+                    self.outer.collect((group_key, inner.finish()))?;
+                }
+
+                let mut inner = Fuse::new(self.inner_cloner.clone());
+                let _ = inner.collect(item);
+                *group = Some((key, inner));
+                ControlFlow::Continue(())
+            }
+        }
+    }
+}
+
+impl<CO, CI, F, K> Debug for ChunkBy<CO, CI, F, K>
+where
+    CO: Debug,
+    CI: Debug,
+    K: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChunkBy")
+            .field("outer", &self.outer)
+            .field("inner_cloner", &self.inner_cloner)
+            .field("group", &self.group)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..6_i32, ..=10),
+            groups in ..=4_usize,
+            group_len in ..=3_usize,
+        ) {
+            all_collect_methods_impl(nums, groups, group_len)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, groups: usize, group_len: usize) -> TestCaseResult {
+        let key = |&num: &i32| num / 2;
+        let chunks: Vec<_> = nums.chunk_by(|a, b| key(a) == key(b)).collect();
+        // The outer stops on the first item of the group after its last one.
+        let consumed = if groups == 0 {
+            0
+        } else if chunks.len() > groups {
+            chunks[..groups]
+                .iter()
+                .map(|chunk| chunk.len())
+                .sum::<usize>()
+                + 1
+        } else {
+            nums.len()
+        };
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(groups)
+                    .chunk_by(key, vec![].into_collector().take(group_len))
+            },
+            should_break_pred: |_| groups == 0 || chunks.len() > groups,
+            pred: |_, output, remaining| {
+                let expected: Vec<_> = chunks
+                    .iter()
+                    .take(groups)
+                    .map(|chunk| {
+                        (
+                            key(&chunk[0]),
+                            chunk.iter().copied().take(group_len).collect(),
+                        )
+                    })
+                    .collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if nums.iter().copied().skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::ControlFlow,
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that tags its output with a label if the underlying collector
+/// has stopped accumulating.
+///
+/// This `struct` is created by [`CollectorBase::context()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Context<C, L> {
+    collector: C,
+    label: L,
+    stopped: bool,
+}
+
+/// The output of a collector that has stopped accumulating, tagged with its label.
+///
+/// This `struct` is produced by [`Context`] on finishing.
+/// See [`CollectorBase::context()`] for more.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stopped<O, L> {
+    output: O,
+    label: L,
+}
+
+impl<C, L> Context<C, L> {
+    pub(in crate::collector) fn new(collector: C, label: L) -> Self {
+        Self {
+            collector,
+            label,
+            stopped: false,
+        }
+    }
+
+    #[inline]
+    fn track(&mut self, cf: ControlFlow<()>) -> ControlFlow<()> {
+        self.stopped |= cf.is_break();
+        cf
+    }
+}
+
+impl<C, L> CollectorBase for Context<C, L>
+where
+    C: CollectorBase,
+{
+    type Output = Result<C::Output, Stopped<C::Output, L>>;
+
+    fn finish(self) -> Self::Output {
+        // The hint is only consulted if no `Break` has been returned,
+        // since it is unspecified afterward.
+        let stopped = self.stopped || self.collector.break_hint().is_break();
+        let output = self.collector.finish();
+
+        if stopped {
+            Err(Stopped {
+                output,
+                label: self.label,
+            })
+        } else {
+            Ok(output)
+        }
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, L, T> Collector<T> for Context<C, L>
+where
+    C: Collector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let cf = self.collector.collect(item);
+        self.track(cf)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let cf = self.collector.collect_many(items);
+        self.track(cf)
+    }
+
+    // The default implementation for `collect_then_finish()` is sufficient.
+    // Forwarding it would lose whether the collector has stopped.
+}
+
+impl<O, L> Stopped<O, L> {
+    /// Returns a reference to the label of the collector.
+    #[inline]
+    pub const fn label(&self) -> &L {
+        &self.label
+    }
+
+    /// Returns a reference to the output of the collector.
+    #[inline]
+    pub const fn output(&self) -> &O {
+        &self.output
+    }
+
+    /// Returns the output of the collector, discarding the label.
+    #[inline]
+    pub fn into_output(self) -> O {
+        self.output
+    }
+
+    /// Returns the output and the label of the collector, in order.
+    #[inline]
+    pub fn into_parts(self) -> (O, L) {
+        (self.output, self.label)
+    }
+}
+
+impl<O, L: Display> Display for Stopped<O, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` stopped accumulating", self.label)
+    }
+}
+
+impl<O: Debug, L: Debug + Display> std::error::Error for Stopped<O, L> {}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            take_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || vec![].into_collector().take(take_count).context("vec"),
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let taken: Vec<_> = iter.by_ref().take(take_count).collect();
+                let expected = if nums.len() >= take_count {
+                    Err((taken, "vec"))
+                } else {
+                    Ok(taken)
+                };
+
+                if output.map_err(|stopped| stopped.into_parts()) != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
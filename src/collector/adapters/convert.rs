@@ -0,0 +1,125 @@
+use std::{fmt::Debug, marker::PhantomData, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that converts every collected item with [`From`] before collecting.
+///
+/// This `struct` is created by [`CollectorBase::convert()`]. See its documentation for more.
+pub struct Convert<C, U> {
+    collector: C,
+    _marker: PhantomData<fn(U)>,
+}
+
+impl<C, U> Convert<C, U> {
+    pub(in crate::collector) fn new(collector: C) -> Self {
+        Self {
+            collector,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, U> CollectorBase for Convert<C, U>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, U, T> Collector<T> for Convert<C, U>
+where
+    C: Collector<U>,
+    U: From<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.collector.collect(U::from(item))
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.collector.collect_many(items.into_iter().map(U::from))
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector
+            .collect_then_finish(items.into_iter().map(U::from))
+    }
+}
+
+// Derives would bound `U`, which is only a marker.
+impl<C: Clone, U> Clone for Convert<C, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.collector.clone())
+    }
+}
+
+impl<C: Debug, U> Debug for Convert<C, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Convert")
+            .field("collector", &self.collector)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            take_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                Vec::<i64>::new()
+                    .into_collector()
+                    .take(take_count)
+                    .convert::<i64>()
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                if iter.by_ref().take(take_count).map(i64::from).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -29,6 +29,11 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         self.0.break_hint()
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.0.reserve(additional_min, additional_max);
+    }
 }
 
 impl<'a, C, T> Collector<&'a T> for Copying<C>
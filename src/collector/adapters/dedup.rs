@@ -0,0 +1,136 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that skips items equal to the previous one.
+///
+/// This `struct` is created by [`CollectorBase::dedup()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Dedup<C, T> {
+    collector: C,
+    last: Option<T>,
+}
+
+impl<C, T> Dedup<C, T> {
+    pub(in crate::collector) fn new(collector: C) -> Self {
+        Self {
+            collector,
+            last: None,
+        }
+    }
+}
+
+impl<C, T> CollectorBase for Dedup<C, T>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        // The first item is never a duplicate.
+        self.collector
+            .reserve(additional_min.min(1), additional_max);
+    }
+}
+
+impl<C, T> Collector<T> for Dedup<C, T>
+where
+    C: Collector<T>,
+    T: PartialEq + Clone,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if is_new(&mut self.last, &item) {
+            self.collector.collect(item)
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let last = &mut self.last;
+        self.collector
+            .collect_many(items.into_iter().filter(|item| is_new(last, item)))
+    }
+
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector.collect_then_finish(
+            items
+                .into_iter()
+                .filter(|item| is_new(&mut self.last, item)),
+        )
+    }
+}
+
+// Remembers the item if it differs from the last one.
+#[inline]
+fn is_new<T>(last: &mut Option<T>, item: &T) -> bool
+where
+    T: PartialEq + Clone,
+{
+    if last.as_ref() == Some(item) {
+        false
+    } else {
+        *last = Some(item.clone());
+        true
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..3, ..=8),
+            take_count in ..=8_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || vec![].into_collector().take(take_count).dedup(),
+            should_break_pred: |iter| iter.filter(is_new()).count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let expected = iter.by_ref().filter(is_new()).take(take_count);
+
+                if expected.ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn is_new() -> impl FnMut(&i32) -> bool {
+        let mut last = None;
+        move |&num| last.replace(num) != Some(num)
+    }
+}
@@ -0,0 +1,151 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that skips items whose key equals that of the previous item.
+///
+/// This `struct` is created by [`CollectorBase::dedup_by_key()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct DedupByKey<C, F, K> {
+    collector: C,
+    f: F,
+    last: Option<K>,
+}
+
+impl<C, F, K> DedupByKey<C, F, K> {
+    pub(in crate::collector) fn new(collector: C, f: F) -> Self {
+        Self {
+            collector,
+            f,
+            last: None,
+        }
+    }
+}
+
+impl<C, F, K> CollectorBase for DedupByKey<C, F, K>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        // The first item is never a duplicate.
+        self.collector
+            .reserve(additional_min.min(1), additional_max);
+    }
+}
+
+impl<C, T, F, K> Collector<T> for DedupByKey<C, F, K>
+where
+    C: Collector<T>,
+    F: FnMut(&T) -> K,
+    K: PartialEq,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if is_new(&mut self.last, (self.f)(&item)) {
+            self.collector.collect(item)
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let Self { collector, f, last } = self;
+        collector.collect_many(items.into_iter().filter(|item| is_new(last, f(item))))
+    }
+
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let Self {
+            collector,
+            mut f,
+            mut last,
+        } = self;
+        collector.collect_then_finish(items.into_iter().filter(|item| is_new(&mut last, f(item))))
+    }
+}
+
+impl<C: Debug, F, K: Debug> Debug for DedupByKey<C, F, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DedupByKey")
+            .field("collector", &self.collector)
+            .field("last", &self.last)
+            .finish_non_exhaustive()
+    }
+}
+
+// Remembers the key, and tells whether it differs from the last one.
+#[inline]
+fn is_new<K>(last: &mut Option<K>, key: K) -> bool
+where
+    K: PartialEq,
+{
+    let is_new = last.as_ref() != Some(&key);
+    *last = Some(key);
+    is_new
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..6, ..=8),
+            take_count in ..=8_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .dedup_by_key(|&num| num / 2)
+            },
+            should_break_pred: |iter| iter.filter(is_new()).count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let expected = iter.by_ref().filter(is_new()).take(take_count);
+
+                if expected.ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn is_new() -> impl FnMut(&i32) -> bool {
+        let mut last = None;
+        move |&num| last.replace(num / 2) != Some(num / 2)
+    }
+}
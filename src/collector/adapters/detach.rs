@@ -0,0 +1,164 @@
+use std::{
+    fmt::Debug,
+    ops::ControlFlow,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, SyncSender},
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that sends items to a collector running on a background thread.
+///
+/// This `struct` is created by [`CollectorBase::detach()`]. See its documentation for more.
+pub struct Detach<T, O> {
+    sender: SyncSender<T>,
+    stopped: Arc<AtomicBool>,
+    handle: JoinHandle<O>,
+}
+
+impl<T, O> Detach<T, O>
+where
+    T: Send + 'static,
+    O: Send + 'static,
+{
+    pub(in crate::collector) fn new<C>(collector: C, bound: usize) -> Self
+    where
+        C: Collector<T, Output = O> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(bound);
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let handle = thread::spawn({
+            let stopped = Arc::clone(&stopped);
+
+            move || {
+                let mut collector = collector;
+                // The receiver is dropped right after this, so the sender
+                // will also notice that the collector has stopped.
+                let _ = collector.collect_many(receiver);
+                stopped.store(true, Ordering::Release);
+                collector.finish()
+            }
+        });
+
+        Self {
+            sender,
+            stopped,
+            handle,
+        }
+    }
+}
+
+impl<T, O> CollectorBase for Detach<T, O> {
+    type Output = JoinHandle<O>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        // Hang up so that the background collector can finish.
+        drop(self.sender);
+        self.handle
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.stopped.load(Ordering::Acquire) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<T, O> Collector<T> for Detach<T, O> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.break_hint()?;
+
+        match self.sender.send(item) {
+            Ok(()) => ControlFlow::Continue(()),
+            // The background collector has stopped and dropped the receiver.
+            Err(_) => ControlFlow::Break(()),
+        }
+    }
+}
+
+impl<T, O> Debug for Detach<T, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Detach")
+            .field("sender", &self.sender)
+            .field("stopped", &self.stopped)
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            bound in ..=3_usize,
+        ) {
+            all_collect_methods_impl(nums, bound)?;
+        }
+
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn lost_items(
+            take_count in ..=5_usize,
+            bound in ..=3_usize,
+        ) {
+            lost_items_impl(take_count, bound)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, bound: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || vec![].into_collector().detach(bound),
+            should_break_pred: |_| false,
+            pred: |iter, handle, remaining| {
+                if iter.ne(handle.join().unwrap()) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn lost_items_impl(take_count: usize, bound: usize) -> TestCaseResult {
+        let mut collector = vec![].into_collector().take(take_count).detach(bound);
+        let accepted = (0..)
+            .take_while(|&num| collector.collect(num).is_continue())
+            .count();
+
+        prop_assert_eq!(
+            collector.finish().join().unwrap(),
+            (0..take_count).collect::<Vec<_>>()
+        );
+        // Every item after the first `take_count` ones that got `Continue` is lost.
+        prop_assert!((take_count..=take_count + bound).contains(&accepted));
+
+        Ok(())
+    }
+}
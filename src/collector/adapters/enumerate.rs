@@ -0,0 +1,133 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pairs each item with its index before collecting.
+///
+/// `N` is the type of the index, either [`usize`] or [`u64`].
+///
+/// This `struct` is created by [`CollectorBase::enumerate()`],
+/// [`CollectorBase::enumerate_from()`] and [`CollectorBase::enumerate_u64()`].
+/// See their documentation for more.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enumerate<C, N = usize> {
+    collector: C,
+    index: N,
+}
+
+impl<C, N> Enumerate<C, N> {
+    pub(in crate::collector) fn new(collector: C, start: N) -> Self {
+        Self {
+            collector,
+            index: start,
+        }
+    }
+}
+
+impl<C, N> CollectorBase for Enumerate<C, N>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+macro_rules! collector_impl {
+    ($($ty:ty)*) => {
+        $(
+            impl<C, T> Collector<T> for Enumerate<C, $ty>
+            where
+                C: Collector<($ty, T)>,
+            {
+                #[inline]
+                fn collect(&mut self, item: T) -> ControlFlow<()> {
+                    let index = self.index;
+                    self.index += 1;
+                    self.collector.collect((index, item))
+                }
+
+                fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+                    let index = &mut self.index;
+                    self.collector.collect_many(items.into_iter().map(|item| {
+                        let i = *index;
+                        *index += 1;
+                        (i, item)
+                    }))
+                }
+
+                fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+                    let mut index = self.index;
+                    self.collector.collect_then_finish(items.into_iter().map(|item| {
+                        let i = index;
+                        index += 1;
+                        (i, item)
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+collector_impl!(usize u64);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=6),
+            start in ..=1000_usize,
+            take_count in ..=6_usize,
+        ) {
+            all_collect_methods_impl(nums, start, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, start: usize, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .enumerate_from(start)
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                if (start..).zip(iter.by_ref().take(take_count)).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
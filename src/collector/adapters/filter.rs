@@ -1,4 +1,4 @@
-use crate::collector::{Collector, CollectorBase};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector};
 
 use std::{fmt::Debug, ops::ControlFlow};
 
@@ -32,6 +32,22 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         self.collector.break_hint()
     }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, F> SnapshotCollector for Filter<C, F>
+where
+    C: SnapshotCollector,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.collector.peek_output()
+    }
 }
 
 impl<C, F, T> Collector<T> for Filter<C, F>
@@ -74,7 +90,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
@@ -0,0 +1,164 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, SharedState};
+
+/// A collector that uses a closure, with access to shared state,
+/// to determine whether an item should be accumulated.
+///
+/// This `struct` is created by [`CollectorBase::filter_with()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct FilterWith<C, S, F> {
+    collector: C,
+    state: S,
+    pred: F,
+}
+
+impl<C, S, F> FilterWith<C, S, F> {
+    pub(in crate::collector) fn new(collector: C, state: S, pred: F) -> Self {
+        Self {
+            collector,
+            state,
+            pred,
+        }
+    }
+}
+
+impl<C, S, F> CollectorBase for FilterWith<C, S, F>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, S, F, T> Collector<T> for FilterWith<C, S, F>
+where
+    C: Collector<T>,
+    S: SharedState,
+    F: FnMut(&S::State, &T) -> bool,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.state.with(|state| (self.pred)(state, &item)) {
+            self.collector.collect(item)
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let Self {
+            collector,
+            state,
+            pred,
+        } = self;
+
+        collector.collect_many(
+            items
+                .into_iter()
+                .filter(|item| state.with(|state| pred(state, item))),
+        )
+    }
+
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let Self {
+            collector,
+            state,
+            mut pred,
+        } = self;
+
+        collector.collect_then_finish(
+            items
+                .into_iter()
+                .filter(|item| state.with(|state| pred(state, item))),
+        )
+    }
+}
+
+impl<C: Debug, S: Debug, F> Debug for FilterWith<C, S, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilterWith")
+            .field("collector", &self.collector)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::{cell::RefCell, collections::HashSet};
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::collector::CollectorBase::inspect()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..5, ..=8),
+            take_count in ..=8_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    // The state is modified after each item passes, so that only unseen items pass.
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        let seen = RefCell::new(HashSet::new());
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                seen.borrow_mut().clear();
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .inspect(|&num| {
+                        seen.borrow_mut().insert(num);
+                    })
+                    .filter_with(&seen, |seen: &HashSet<i32>, num| !seen.contains(num))
+            },
+            should_break_pred: |iter| iter.filter(is_new()).count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let expected = iter.by_ref().filter(is_new()).take(take_count);
+
+                if expected.ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn is_new() -> impl FnMut(&i32) -> bool {
+        let mut seen = HashSet::new();
+        move |&num| seen.insert(num)
+    }
+}
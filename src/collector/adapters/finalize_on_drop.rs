@@ -0,0 +1,207 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that calls a closure with the underlying collector
+/// if it is dropped without being finished.
+///
+/// This `struct` is created by [`CollectorBase::finalize_on_drop()`].
+/// See its documentation for more.
+pub struct FinalizeOnDrop<C, F>
+where
+    F: FnOnce(C),
+{
+    // Always `Some` until finished, dropped or disarmed.
+    inner: Option<(C, F)>,
+}
+
+impl<C, F> FinalizeOnDrop<C, F>
+where
+    F: FnOnce(C),
+{
+    pub(in crate::collector) fn new(collector: C, f: F) -> Self {
+        Self {
+            inner: Some((collector, f)),
+        }
+    }
+
+    #[inline]
+    fn collector(&self) -> &C {
+        match &self.inner {
+            Some((collector, _)) => collector,
+            None => unreachable!("the collector is only taken out on finishing or dropping"),
+        }
+    }
+
+    #[inline]
+    fn collector_mut(&mut self) -> &mut C {
+        match &mut self.inner {
+            Some((collector, _)) => collector,
+            None => unreachable!("the collector is only taken out on finishing or dropping"),
+        }
+    }
+
+    #[inline]
+    fn take_collector(mut self) -> C {
+        match self.inner.take() {
+            Some((collector, _)) => collector,
+            None => unreachable!("the collector is only taken out on finishing or dropping"),
+        }
+    }
+
+    /// Returns the underlying collector without calling the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let collector = vec![1, 2]
+    ///     .into_collector()
+    ///     .finalize_on_drop(|_| panic!("should not be called"));
+    ///
+    /// assert_eq!(collector.into_inner().finish(), [1, 2]);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.take_collector()
+    }
+}
+
+impl<C, F> CollectorBase for FinalizeOnDrop<C, F>
+where
+    C: CollectorBase,
+    F: FnOnce(C),
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.take_collector().finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector().break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector_mut().reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, T, F> Collector<T> for FinalizeOnDrop<C, F>
+where
+    C: Collector<T>,
+    F: FnOnce(C),
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.collector_mut().collect(item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.collector_mut().collect_many(items)
+    }
+
+    // `collect_then_finish()` is not forwarded, so that the closure is still called
+    // if the iterator panics.
+}
+
+impl<C, F> Drop for FinalizeOnDrop<C, F>
+where
+    F: FnOnce(C),
+{
+    fn drop(&mut self) {
+        if let Some((collector, f)) = self.inner.take() {
+            f(collector);
+        }
+    }
+}
+
+impl<C: Debug, F> Debug for FinalizeOnDrop<C, F>
+where
+    F: FnOnce(C),
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FinalizeOnDrop")
+            .field(
+                "collector",
+                &self.inner.as_ref().map(|(collector, _)| collector),
+            )
+            .field("f", &std::any::type_name::<F>())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cell::RefCell;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            take_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+
+        /// Precondition:
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn finalized_on_drop(
+            nums in propvec(any::<i32>(), ..=5),
+        ) {
+            let finalized = RefCell::new(None);
+            let mut collector = vec![]
+                .into_collector()
+                .finalize_on_drop(|collector| *finalized.borrow_mut() = Some(collector.finish()));
+
+            let _ = collector.collect_many(nums.iter().copied());
+            drop(collector);
+
+            prop_assert_eq!(finalized.into_inner(), Some(nums));
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        let finalized = RefCell::new(false);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .finalize_on_drop(|_| *finalized.borrow_mut() = true)
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                if output != iter.by_ref().take(take_count).collect::<Vec<_>>() {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else if *finalized.borrow() {
+                    // Finishing must not call the closure.
+                    Err(PredError::IncorrectOutput)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::ControlFlow;
 
-use crate::collector::{Collector, CollectorBase};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector};
 
 /// A collector that can "safely" collect items even after
 /// the underlying collector has stopped accumulating,
@@ -8,11 +8,46 @@ use crate::collector::{Collector, CollectorBase};
 ///
 /// This `struct` is created by [`CollectorBase::fuse()`]. See its documentation for more.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fuse<C> {
     collector: C,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "break_hint_serde", rename = "stopped")
+    )]
     break_hint: ControlFlow<()>,
 }
 
+// `ControlFlow` does not implement the serde traits,
+// so it is (de)serialized as whether the underlying collector has stopped.
+#[cfg(feature = "serde")]
+mod break_hint_serde {
+    use std::ops::ControlFlow;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S>(
+        break_hint: &ControlFlow<()>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(break_hint.is_break())
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<ControlFlow<()>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(if bool::deserialize(deserializer)? {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        })
+    }
+}
+
 impl<C> Fuse<C>
 where
     C: CollectorBase,
@@ -58,6 +93,23 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         self.break_hint
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        if self.break_hint.is_continue() {
+            self.collector.reserve(additional_min, additional_max);
+        }
+    }
+}
+
+impl<C> SnapshotCollector for Fuse<C>
+where
+    C: SnapshotCollector,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.collector.peek_output()
+    }
 }
 
 impl<C, T> Collector<T> for Fuse<C>
@@ -91,7 +143,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{CollectorTestParts, CollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{CollectorTestParts, CollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// We use
@@ -129,7 +181,7 @@ mod proptests {
 
         fn collector_test_parts<'a>(
             &'a mut self,
-        ) -> crate::test_utils::CollectorTestParts<
+        ) -> crate::testkit::CollectorTestParts<
             impl Iterator<Item = Self::Item<'a>>,
             impl Collector<Self::Item<'a>, Output = Self::Output<'a>>,
             impl FnMut(
@@ -1,6 +1,6 @@
 use std::{fmt::Debug, ops::ControlFlow};
 
-use crate::collector::{Collector, CollectorBase};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector};
 
 /// A collector that calls a closure on each item before collecting.
 ///
@@ -31,6 +31,21 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         self.collector.break_hint()
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, F> SnapshotCollector for Inspect<C, F>
+where
+    C: SnapshotCollector,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.collector.peek_output()
+    }
 }
 
 impl<C, T, F> Collector<T> for Inspect<C, F>
@@ -72,7 +87,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     // Precondition:
     // - `Vec::IntoCollector`
@@ -0,0 +1,78 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that calls a closure on the output on finishing.
+///
+/// This `struct` is created by [`CollectorBase::inspect_output()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct InspectOutput<C, F> {
+    collector: C,
+    f: F,
+}
+
+impl<C, F> InspectOutput<C, F> {
+    pub(in crate::collector) fn new(collector: C, f: F) -> Self {
+        Self { collector, f }
+    }
+}
+
+impl<C, F> CollectorBase for InspectOutput<C, F>
+where
+    C: CollectorBase,
+    F: FnOnce(&C::Output),
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        let output = self.collector.finish();
+        (self.f)(&output);
+        output
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, T, F> Collector<T> for InspectOutput<C, F>
+where
+    C: Collector<T>,
+    F: FnOnce(&C::Output),
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.collector.collect(item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.collector.collect_many(items)
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let output = self.collector.collect_then_finish(items);
+        (self.f)(&output);
+        output
+    }
+}
+
+impl<C, F> Debug for InspectOutput<C, F>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InspectOutput")
+            .field("collector", &self.collector)
+            .finish_non_exhaustive()
+    }
+}
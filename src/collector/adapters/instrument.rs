@@ -0,0 +1,243 @@
+use std::{
+    ops::ControlFlow,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    collector::{Collector, CollectorBase},
+    time::{Clock, SystemClock},
+};
+
+/// A collector that measures the time spent in the underlying collector.
+///
+/// This `struct` is created by [`CollectorBase::instrument()`].
+/// See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Instrument<C, K = SystemClock> {
+    collector: C,
+    clock: K,
+    measurements: Measurements,
+}
+
+/// The measurements of an [`Instrument`] collector.
+///
+/// This `struct` is produced alongside the output of the underlying collector
+/// by [`CollectorBase::instrument()`].
+/// See its documentation for more.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Measurements {
+    items: usize,
+    busy: Duration,
+    max_latency: Duration,
+}
+
+impl<C> Instrument<C> {
+    pub(in crate::collector) fn new(collector: C) -> Self {
+        Self {
+            collector,
+            clock: SystemClock,
+            measurements: Measurements::default(),
+        }
+    }
+}
+
+impl<C, K> Instrument<C, K> {
+    /// Reads the time from another [`Clock`], such as a [`MockClock`] in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{rc::Rc, time::Duration};
+    /// use komadori::{prelude::*, time::MockClock};
+    ///
+    /// let clock = Rc::new(MockClock::new());
+    /// let advancing = Rc::clone(&clock);
+    ///
+    /// let (output, measurements) = [3, 1, 2]
+    ///     .into_iter()
+    ///     .feed_into(
+    ///         vec![]
+    ///             .into_collector()
+    ///             .inspect(move |&secs| advancing.advance(Duration::from_secs(secs)))
+    ///             .instrument()
+    ///             .with_clock(clock),
+    ///     );
+    ///
+    /// assert_eq!(output, [3, 1, 2]);
+    /// assert_eq!(measurements.busy(), Duration::from_secs(6));
+    /// assert_eq!(measurements.max_latency(), Duration::from_secs(3));
+    /// assert_eq!(measurements.items_per_sec(), Some(0.5));
+    /// ```
+    ///
+    /// [`MockClock`]: crate::time::MockClock
+    #[inline]
+    pub fn with_clock<K2>(self, clock: K2) -> Instrument<C, K2>
+    where
+        K2: Clock,
+    {
+        Instrument {
+            collector: self.collector,
+            clock,
+            measurements: self.measurements,
+        }
+    }
+
+    /// Returns the measurements so far.
+    #[inline]
+    pub const fn measurements(&self) -> &Measurements {
+        &self.measurements
+    }
+}
+
+impl<C, K> Instrument<C, K>
+where
+    K: Clock,
+{
+    #[inline]
+    fn record(&mut self, start: Instant) {
+        let latency = self.clock.now() - start;
+
+        self.measurements.items += 1;
+        self.measurements.busy += latency;
+        self.measurements.max_latency = self.measurements.max_latency.max(latency);
+    }
+}
+
+impl Measurements {
+    /// Returns the number of items collected.
+    #[inline]
+    pub const fn items(&self) -> usize {
+        self.items
+    }
+
+    /// Returns the total time spent in the underlying collector,
+    /// collecting items and finishing.
+    #[inline]
+    pub const fn busy(&self) -> Duration {
+        self.busy
+    }
+
+    /// Returns the longest time spent collecting a single item.
+    #[inline]
+    pub const fn max_latency(&self) -> Duration {
+        self.max_latency
+    }
+
+    /// Returns the number of items the underlying collector can collect per second,
+    /// based on the time it was [busy](Measurements::busy),
+    /// or `None` if it was not busy at all.
+    #[inline]
+    pub fn items_per_sec(&self) -> Option<f64> {
+        (!self.busy.is_zero()).then(|| self.items as f64 / self.busy.as_secs_f64())
+    }
+}
+
+impl<C, K> CollectorBase for Instrument<C, K>
+where
+    C: CollectorBase,
+    K: Clock,
+{
+    type Output = (C::Output, Measurements);
+
+    fn finish(self) -> Self::Output {
+        let Self {
+            collector,
+            clock,
+            mut measurements,
+        } = self;
+
+        let start = clock.now();
+        let output = collector.finish();
+        measurements.busy += clock.now() - start;
+
+        (output, measurements)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, K, T> Collector<T> for Instrument<C, K>
+where
+    C: Collector<T>,
+    K: Clock,
+{
+    // The default `collect_many()` feeds the items one by one, so each of them is timed.
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let start = self.clock.now();
+        let cf = self.collector.collect(item);
+        self.record(start);
+
+        cf
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::{rc::Rc, time::Duration};
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::time::MockClock;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::inspect()`]
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            millis in propvec(..100_u64, ..=6),
+            take_count in ..=6_usize,
+        ) {
+            all_collect_methods_impl(millis, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(millis: Vec<u64>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || millis.iter().copied(),
+            collector_factory: || {
+                let clock = Rc::new(MockClock::new());
+                let advancing = Rc::clone(&clock);
+
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .inspect(move |&millis| advancing.advance(Duration::from_millis(millis)))
+                    .instrument()
+                    .with_clock(clock)
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, (output, measurements), remaining| {
+                let collected: Vec<_> = iter.by_ref().take(take_count).collect();
+
+                if collected != output
+                    || measurements.items() != collected.len()
+                    || measurements.busy() != Duration::from_millis(collected.iter().sum())
+                    || measurements.max_latency()
+                        != Duration::from_millis(collected.iter().copied().max().unwrap_or(0))
+                {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,6 +1,6 @@
 use std::{fmt::Debug, ops::ControlFlow};
 
-use crate::collector::{Collector, CollectorBase};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector};
 
 /// A collector that calls a closure on each item before collecting.
 ///
@@ -32,6 +32,21 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         self.collector.break_hint()
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, F> SnapshotCollector for Map<C, F>
+where
+    C: SnapshotCollector,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.collector.peek_output()
+    }
 }
 
 impl<C, T, U, F> Collector<T> for Map<C, F>
@@ -33,6 +33,11 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         self.collector.break_hint()
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
 }
 
 impl<C, T, F, R> Collector<T> for MapOutput<C, F>
@@ -0,0 +1,97 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, SharedState};
+
+/// A collector that calls a closure on each item, with access to shared state,
+/// before collecting.
+///
+/// This `struct` is created by [`CollectorBase::map_with()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct MapWith<C, S, F> {
+    collector: C,
+    state: S,
+    f: F,
+}
+
+impl<C, S, F> MapWith<C, S, F> {
+    pub(in crate::collector) fn new(collector: C, state: S, f: F) -> Self {
+        Self {
+            collector,
+            state,
+            f,
+        }
+    }
+}
+
+impl<C, S, F> CollectorBase for MapWith<C, S, F>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, S, F, T, U> Collector<T> for MapWith<C, S, F>
+where
+    C: Collector<U>,
+    S: SharedState,
+    F: FnMut(&S::State, T) -> U,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let mapped = self.state.with(|state| (self.f)(state, item));
+        self.collector.collect(mapped)
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let Self {
+            collector,
+            state,
+            f,
+        } = self;
+
+        collector.collect_many(
+            items
+                .into_iter()
+                .map(|item| state.with(|state| f(state, item))),
+        )
+    }
+
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let Self {
+            collector,
+            state,
+            mut f,
+        } = self;
+
+        collector.collect_then_finish(
+            items
+                .into_iter()
+                .map(|item| state.with(|state| f(state, item))),
+        )
+    }
+}
+
+impl<C: Debug, S: Debug, F> Debug for MapWith<C, S, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapWith")
+            .field("collector", &self.collector)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,63 @@
+use std::ops::ControlFlow;
+
+use crate::{
+    collector::{Collector, CollectorBase},
+    fmt::Named,
+};
+
+/// A collector that names its output.
+///
+/// This `struct` is created by [`CollectorBase::named_output()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct NamedOutput<C> {
+    collector: C,
+    name: &'static str,
+}
+
+impl<C> NamedOutput<C> {
+    pub(in crate::collector) fn new(collector: C, name: &'static str) -> Self {
+        Self { collector, name }
+    }
+}
+
+impl<C> CollectorBase for NamedOutput<C>
+where
+    C: CollectorBase,
+{
+    type Output = Named<C::Output>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        Named::new(self.name, self.collector.finish())
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, T> Collector<T> for NamedOutput<C>
+where
+    C: Collector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.collector.collect(item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.collector.collect_many(items)
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        Named::new(self.name, self.collector.collect_then_finish(items))
+    }
+}
@@ -7,8 +7,6 @@ use super::{super::strategy::CloneStrategy, with_strategy::WithStrategy};
 /// A collector that collects all outputs produced by an inner collector.
 ///
 /// This `struct` is created by [`CollectorBase::nest()`]. See its documentation for more.
-// Needed because the "Available on crate feature" does not show up on doc.rs
-#[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
 #[derive(Clone)]
 pub struct Nest<CO, CI>(WithStrategy<CO, CloneStrategy<CI>>)
 where
@@ -82,7 +80,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
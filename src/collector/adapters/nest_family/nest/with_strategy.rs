@@ -86,9 +86,9 @@ where
         };
 
         if inner.collect(item).is_break() {
+            // `Option` is a collector too, so `take()` must be called explicitly.
             self.outer.collect(
-                self.inner
-                    .take()
+                Option::take(&mut self.inner)
                     .expect("inner collector should exist")
                     .finish(),
             )
@@ -0,0 +1,148 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that calls a closure the first time the underlying collector
+/// returns [`Break(())`](ControlFlow::Break).
+///
+/// This `struct` is created by [`CollectorBase::on_break()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct OnBreak<C, F> {
+    collector: C,
+    // `None` once called.
+    f: Option<F>,
+}
+
+impl<C, F> OnBreak<C, F>
+where
+    F: FnOnce(),
+{
+    pub(in crate::collector) fn new(collector: C, f: F) -> Self {
+        Self {
+            collector,
+            f: Some(f),
+        }
+    }
+
+    #[inline]
+    fn track(&mut self, cf: ControlFlow<()>) -> ControlFlow<()> {
+        if cf.is_break()
+            && let Some(f) = self.f.take()
+        {
+            f();
+        }
+
+        cf
+    }
+}
+
+impl<C, F> CollectorBase for OnBreak<C, F>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, T, F> Collector<T> for OnBreak<C, F>
+where
+    C: Collector<T>,
+    F: FnOnce(),
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let cf = self.collector.collect(item);
+        self.track(cf)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let cf = self.collector.collect_many(items);
+        self.track(cf)
+    }
+
+    // The default implementation for `collect_then_finish()` is sufficient.
+    // Forwarding it would lose whether the collector has stopped.
+}
+
+impl<C, F> Debug for OnBreak<C, F>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnBreak")
+            .field("collector", &self.collector)
+            .field("called", &self.f.is_none())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cell::Cell;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            take_count in 1..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        let calls = Cell::new(0);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                calls.set(0);
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .on_break(|| calls.set(calls.get() + 1))
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let expected_calls = usize::from(nums.len() >= take_count);
+
+                if iter.by_ref().take(take_count).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else if calls.get() != expected_calls {
+                    Err(PredError::IncorrectOutput)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -65,6 +65,13 @@ where
             self.collector_if_false.break_hint()
         )
     }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may go to either side.
+        self.collector_if_true.reserve(0, additional_max);
+        self.collector_if_false.reserve(0, additional_max);
+    }
 }
 
 impl<CT, CF, F, T> Collector<T> for Partition<CT, CF, F>
@@ -180,7 +187,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
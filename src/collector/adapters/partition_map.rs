@@ -60,6 +60,13 @@ where
             self.collector_right.break_hint()
         )
     }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may go to either side.
+        self.collector_left.reserve(0, additional_max);
+        self.collector_right.reserve(0, additional_max);
+    }
 }
 
 impl<CL, CR, F, T, L, R> Collector<T> for PartitionMap<CL, CR, F>
@@ -169,7 +176,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
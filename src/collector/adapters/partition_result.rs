@@ -0,0 +1,227 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, Fuse};
+
+/// A collector that distributes [`Ok`] and [`Err`] values between two collectors.
+///
+/// This `struct` is created by [`CollectorBase::partition_result()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct PartitionResult<CO, CE> {
+    // `Fuse` is neccessary since we need to assess one's finishing state while assessing another,
+    // like in `collect`.
+    collector_ok: Fuse<CO>,
+    collector_err: Fuse<CE>,
+}
+
+impl<CO, CE> PartitionResult<CO, CE>
+where
+    CO: CollectorBase,
+    CE: CollectorBase,
+{
+    pub(in crate::collector) fn new(collector_ok: CO, collector_err: CE) -> Self {
+        Self {
+            collector_ok: Fuse::new(collector_ok),
+            collector_err: Fuse::new(collector_err),
+        }
+    }
+}
+
+// Put in a macro instead of function so that the short-circuit nature of `&&` is pertained.
+macro_rules! cf_and {
+    ($cf:expr, $pred:expr) => {
+        // Can't swap, since we have to collect regardless.
+        if $cf.is_break() && $pred.is_break() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    };
+}
+
+impl<CO, CE> CollectorBase for PartitionResult<CO, CE>
+where
+    CO: CollectorBase,
+    CE: CollectorBase,
+{
+    type Output = (CO::Output, CE::Output);
+
+    fn finish(self) -> Self::Output {
+        (self.collector_ok.finish(), self.collector_err.finish())
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        cf_and!(
+            self.collector_ok.break_hint(),
+            self.collector_err.break_hint()
+        )
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may go to either side.
+        self.collector_ok.reserve(0, additional_max);
+        self.collector_err.reserve(0, additional_max);
+    }
+}
+
+impl<CO, CE, T, E> Collector<Result<T, E>> for PartitionResult<CO, CE>
+where
+    CO: Collector<T>,
+    CE: Collector<E>,
+{
+    fn collect(&mut self, item: Result<T, E>) -> ControlFlow<()> {
+        match item {
+            Ok(item) => cf_and!(
+                self.collector_ok.collect(item),
+                self.collector_err.break_hint()
+            ),
+            Err(item) => cf_and!(
+                self.collector_err.collect(item),
+                self.collector_ok.break_hint()
+            ),
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = Result<T, E>>) -> ControlFlow<()> {
+        // Avoid consuming one item prematurely.
+        self.break_hint()?;
+
+        let mut items = items.into_iter();
+
+        match items.try_for_each(|item| match item {
+            Ok(item) => self.collector_ok.collect(item).map_break(|_| true),
+            Err(item) => self.collector_err.collect(item).map_break(|_| false),
+        }) {
+            ControlFlow::Break(true) => {
+                cf_and!(
+                    self.collector_err
+                        .collect_many(items.filter_map(Result::err)),
+                    self.collector_ok.break_hint()
+                )
+            }
+            ControlFlow::Break(false) => {
+                cf_and!(
+                    self.collector_ok.collect_many(items.filter_map(Result::ok)),
+                    self.collector_err.break_hint()
+                )
+            }
+            ControlFlow::Continue(_) => ControlFlow::Continue(()),
+        }
+    }
+
+    fn collect_then_finish(
+        mut self,
+        items: impl IntoIterator<Item = Result<T, E>>,
+    ) -> Self::Output {
+        // Avoid consuming one item prematurely.
+        if self.break_hint().is_break() {
+            return self.finish();
+        }
+
+        let mut items = items.into_iter();
+
+        match items.try_for_each(|item| match item {
+            Ok(item) => self.collector_ok.collect(item).map_break(|_| true),
+            Err(item) => self.collector_err.collect(item).map_break(|_| false),
+        }) {
+            ControlFlow::Break(true) => (
+                self.collector_ok.finish(),
+                self.collector_err
+                    .collect_then_finish(items.filter_map(Result::err)),
+            ),
+            ControlFlow::Break(false) => (
+                self.collector_ok
+                    .collect_then_finish(items.filter_map(Result::ok)),
+                self.collector_err.finish(),
+            ),
+            ControlFlow::Continue(_) => self.finish(),
+        }
+    }
+}
+
+impl<CO: Debug, CE: Debug> Debug for PartitionResult<CO, CE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PartitionResult")
+            .field("collector_ok", &self.collector_ok)
+            .field("collector_err", &self.collector_err)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<Result<i32, i32>>(), ..=5),
+            ok_count in ..=5_usize,
+            err_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, ok_count, err_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<Result<i32, i32>>,
+        ok_count: usize,
+        err_count: usize,
+    ) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(ok_count)
+                    .partition_result(vec![].into_collector().take(err_count))
+            },
+            should_break_pred: |iter| {
+                iter.clone().filter(Result::is_ok).count() >= ok_count
+                    && iter.filter(Result::is_err).count() >= err_count
+            },
+            pred: |mut iter, output, remaining| {
+                let (mut oks, mut errs) = (output.0.into_iter(), output.1.into_iter());
+                let (mut ok_count, mut err_count) = (ok_count, err_count);
+
+                while (ok_count > 0 || err_count > 0)
+                    && let Some(num) = iter.next()
+                {
+                    match num {
+                        Ok(num) if ok_count > 0 => {
+                            ok_count -= 1;
+                            if oks.next() != Some(num) {
+                                return Err(PredError::IncorrectOutput);
+                            }
+                        }
+                        Err(num) if err_count > 0 => {
+                            err_count -= 1;
+                            if errs.next() != Some(num) {
+                                return Err(PredError::IncorrectOutput);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                if oks.len() > 0 || errs.len() > 0 {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
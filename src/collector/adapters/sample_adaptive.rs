@@ -0,0 +1,221 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that accumulates each item with a probability given by a closure.
+///
+/// This `struct` is created by [`CollectorBase::sample_adaptive()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct SampleAdaptive<C, F> {
+    collector: C,
+    rate: F,
+    rng: SplitMix64,
+}
+
+impl<C, F> SampleAdaptive<C, F> {
+    pub(in crate::collector) fn new(collector: C, rate: F) -> Self {
+        Self {
+            collector,
+            rate,
+            rng: SplitMix64(DEFAULT_SEED),
+        }
+    }
+
+    /// Seeds the pseudo-random number generator deciding which items are sampled.
+    ///
+    /// Without it, a fixed seed is used, so the same items are sampled on every run
+    /// given the same rates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let sample = |seed| {
+    ///     (0..1000).feed_into(vec![].into_collector().sample_adaptive(|| 0.5).seed(seed))
+    /// };
+    ///
+    /// assert_eq!(sample(42), sample(42));
+    /// ```
+    #[inline]
+    pub fn seed(self, seed: u64) -> Self {
+        Self {
+            rng: SplitMix64(seed),
+            ..self
+        }
+    }
+}
+
+impl<C, F> SampleAdaptive<C, F>
+where
+    F: FnMut() -> f64,
+{
+    #[inline]
+    fn sampled(rate: &mut F, rng: &mut SplitMix64) -> bool {
+        rng.next_f64() < rate()
+    }
+}
+
+impl<C, F> CollectorBase for SampleAdaptive<C, F>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, T, F> Collector<T> for SampleAdaptive<C, F>
+where
+    C: Collector<T>,
+    F: FnMut() -> f64,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if Self::sampled(&mut self.rate, &mut self.rng) {
+            self.collector.collect(item)
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let Self {
+            collector,
+            rate,
+            rng,
+        } = self;
+
+        collector.collect_many(items.into_iter().filter(|_| Self::sampled(rate, rng)))
+    }
+
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let Self {
+            collector,
+            mut rate,
+            mut rng,
+        } = self;
+
+        collector.collect_then_finish(
+            items
+                .into_iter()
+                .filter(|_| Self::sampled(&mut rate, &mut rng)),
+        )
+    }
+}
+
+impl<C: Debug, F> Debug for SampleAdaptive<C, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SampleAdaptive")
+            .field("collector", &self.collector)
+            .finish_non_exhaustive()
+    }
+}
+
+const DEFAULT_SEED: u64 = 0x853C_49E6_748F_EA9B;
+
+/// A small and fast pseudo-random number generator. Not for cryptographic use.
+///
+/// See: https://prng.di.unimi.it/splitmix64.c
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `[0, 1)`.
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        // The top 53 bits fill the mantissa exactly.
+        (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=6),
+            take_count in ..=6_usize,
+            every_other in any::<bool>(),
+        ) {
+            all_collect_methods_impl(nums, take_count, every_other)?;
+        }
+    }
+
+    // The rate alternates between 0 and 1, or stays at 1.
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        take_count: usize,
+        every_other: bool,
+    ) -> TestCaseResult {
+        let is_sampled = |idx: usize| !every_other || idx.is_multiple_of(2);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                let mut idx = 0;
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .sample_adaptive(move || {
+                        idx += 1;
+                        if is_sampled(idx - 1) { 1.0 } else { 0.0 }
+                    })
+            },
+            should_break_pred: |iter| {
+                iter.enumerate().filter(|&(idx, _)| is_sampled(idx)).count() >= take_count
+            },
+            pred: |mut iter, output, remaining| {
+                let expected = iter
+                    .by_ref()
+                    .enumerate()
+                    .filter(|&(idx, _)| is_sampled(idx))
+                    .map(|(_, num)| num)
+                    .take(take_count);
+
+                if expected.ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,12 +1,13 @@
 use std::ops::ControlFlow;
 
-use crate::collector::{Collector, CollectorBase};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector};
 
 /// A collector that skips the first `n` collected items before it begins
 /// accumulating them.
 ///
 /// This `struct` is created by [`CollectorBase::skip()`]. See its documentation for more.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Skip<C> {
     collector: C,
     remaining: usize,
@@ -36,6 +37,24 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         self.collector.break_hint()
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(
+            additional_min.saturating_sub(self.remaining),
+            additional_max.map(|max| max.saturating_sub(self.remaining)),
+        );
+    }
+}
+
+impl<C> SnapshotCollector for Skip<C>
+where
+    C: SnapshotCollector,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.collector.peek_output()
+    }
 }
 
 impl<C, T> Collector<T> for Skip<C>
@@ -122,7 +141,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
     use crate::{mem::Dropping, prelude::*};
 
     // We need to use `take()` to simulate the break case when enough items are skipped.
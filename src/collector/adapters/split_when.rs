@@ -0,0 +1,151 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, Fuse};
+
+/// A collector that splits items into groups separated by items matching a predicate,
+/// then collects the output of each group.
+///
+/// This `struct` is created by [`CollectorBase::split_when()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct SplitWhen<CO, CI, F> {
+    // The outer may stop while a group is being collected,
+    // after which the remaining group must not be collected on finishing.
+    outer: Fuse<CO>,
+    inner_cloner: CI,
+    // Fused since it may stop before the delimiter arrives.
+    inner: Fuse<CI>,
+    pred: F,
+}
+
+impl<CO, CI, F> SplitWhen<CO, CI, F>
+where
+    CO: CollectorBase,
+    CI: CollectorBase + Clone,
+{
+    pub(in crate::collector) fn new(outer: CO, inner: CI, pred: F) -> Self {
+        Self {
+            outer: Fuse::new(outer),
+            inner: Fuse::new(inner.clone()),
+            inner_cloner: inner,
+            pred,
+        }
+    }
+}
+
+impl<CO, CI, F> CollectorBase for SplitWhen<CO, CI, F>
+where
+    CO: Collector<CI::Output>,
+    CI: CollectorBase + Clone,
+{
+    type Output = CO::Output;
+
+    fn finish(mut self) -> Self::Output {
+        // Similar to `[_]::split()`, the last group is always collected, even if empty.
+        let _ = self.outer.collect(self.inner.finish());
+        self.outer.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.outer.break_hint()
+    }
+}
+
+impl<CO, CI, F, T> Collector<T> for SplitWhen<CO, CI, F>
+where
+    CO: Collector<CI::Output>,
+    CI: Collector<T> + Clone,
+    F: FnMut(&T) -> bool,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if (self.pred)(&item) {
+            let inner = std::mem::replace(&mut self.inner, Fuse::new(self.inner_cloner.clone()));
+            self.outer.collect(inner.finish())
+        } else {
+            // The inner stopping only ends the current group, not this collector.
+            let _ = self.inner.collect(item);
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<CO, CI, F> Debug for SplitWhen<CO, CI, F>
+where
+    CO: Debug,
+    CI: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SplitWhen")
+            .field("outer", &self.outer)
+            .field("inner_cloner", &self.inner_cloner)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4_i32, ..=10),
+            groups in ..=4_usize,
+            group_len in ..=3_usize,
+        ) {
+            all_collect_methods_impl(nums, groups, group_len)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, groups: usize, group_len: usize) -> TestCaseResult {
+        // Index of the delimiter that gives the outer its last group, if any.
+        let stop_at = || {
+            nums.iter()
+                .enumerate()
+                .filter(|&(_, &num)| num == 0)
+                .map(|(i, _)| i)
+                .nth(groups.wrapping_sub(1))
+        };
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(groups)
+                    .split_when(|&num| num == 0, vec![].into_collector().take(group_len))
+            },
+            should_break_pred: |_| groups == 0 || stop_at().is_some(),
+            pred: |_, output, remaining| {
+                let consumed = match stop_at() {
+                    _ if groups == 0 => 0,
+                    Some(i) => i + 1,
+                    None => nums.len(),
+                };
+                let expected: Vec<_> = nums
+                    .split(|&num| num == 0)
+                    .take(groups)
+                    .map(|group| group.iter().copied().take(group_len).collect::<Vec<_>>())
+                    .collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if nums.iter().copied().skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,11 +1,12 @@
 use std::ops::ControlFlow;
 
-use crate::collector::{Collector, CollectorBase};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector};
 
 /// A collector that stops accumulating after collecting the first `n` items.
 ///
 /// This `struct` is created by [`CollectorBase::take()`]. See its documentation for more.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Take<C> {
     collector: C,
     // Unspecified if the underlying collector stops accumulating.
@@ -57,6 +58,23 @@ where
             self.collector.break_hint()
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        let additional_max = additional_max.map_or(self.remaining, |max| max.min(self.remaining));
+        self.collector
+            .reserve(additional_min.min(self.remaining), Some(additional_max));
+    }
+}
+
+impl<C> SnapshotCollector for Take<C>
+where
+    C: SnapshotCollector,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.collector.peek_output()
+    }
 }
 
 impl<C, T> Collector<T> for Take<C>
@@ -68,23 +86,6 @@ where
         self.collect_impl(|collector| collector.collect(item))
     }
 
-    // fn size_hint(&self) -> (usize, Option<usize>) {
-    //     let (lower, upper) = self.collector.size_hint();
-    //     (
-    //         lower.min(self.remaining),
-    //         upper.map(|u| u.min(self.remaining)),
-    //     )
-    // }
-
-    // fn reserve(&mut self, mut additional_min: usize, mut additional_max: Option<usize>) {
-    //     additional_min = additional_min.min(self.remaining);
-    //     additional_max = Some(additional_max.map_or(self.remaining, |additional_max| {
-    //         additional_max.min(self.remaining)
-    //     }));
-
-    //     self.collector.reserve(additional_min, additional_max);
-    // }
-
     fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
         // FIXED: utilize specialization after it's stabilized.
 
@@ -141,7 +142,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         #[test]
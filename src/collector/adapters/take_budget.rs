@@ -0,0 +1,233 @@
+use std::{fmt::Debug, iter, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that accumulates items as long as their total cost fits in a budget.
+///
+/// This `struct` is created by [`CollectorBase::take_budget()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct TakeBudget<C, F> {
+    collector: C,
+    cost: F,
+    remaining: usize,
+    inclusive: bool,
+}
+
+impl<C, F> TakeBudget<C, F> {
+    pub(in crate::collector) fn new(collector: C, budget: usize, cost: F) -> Self {
+        Self {
+            collector,
+            cost,
+            remaining: budget,
+            inclusive: false,
+        }
+    }
+
+    /// Also collects the item that exceeds the budget, before stopping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let lines = ["abc", "de", "fgh", "ij"].into_iter().feed_into(
+    ///     vec![]
+    ///         .into_collector()
+    ///         .take_budget(6, |s: &&str| s.len())
+    ///         .inclusive(),
+    /// );
+    ///
+    /// assert_eq!(lines, ["abc", "de", "fgh"]);
+    /// ```
+    #[inline]
+    pub fn inclusive(self) -> Self {
+        Self {
+            inclusive: true,
+            ..self
+        }
+    }
+}
+
+impl<C, F> CollectorBase for TakeBudget<C, F>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        // Items may cost nothing, so an exhausted budget alone does not stop it.
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, T, F> Collector<T> for TakeBudget<C, F>
+where
+    C: Collector<T>,
+    F: FnMut(&T) -> usize,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let cost = (self.cost)(&item);
+
+        if let Some(remaining) = self.remaining.checked_sub(cost) {
+            self.remaining = remaining;
+            self.collector.collect(item)
+        } else {
+            if self.inclusive {
+                let _ = self.collector.collect(item);
+            }
+            ControlFlow::Break(())
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let mut exceeded = false;
+        let cf = self.collector.collect_many(within_budget(
+            items,
+            &mut self.cost,
+            &mut self.remaining,
+            self.inclusive,
+            &mut exceeded,
+        ));
+
+        if exceeded { ControlFlow::Break(()) } else { cf }
+    }
+
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector.collect_then_finish(within_budget(
+            items,
+            &mut self.cost,
+            &mut self.remaining,
+            self.inclusive,
+            &mut false,
+        ))
+    }
+}
+
+impl<C: Debug, F> Debug for TakeBudget<C, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TakeBudget")
+            .field("collector", &self.collector)
+            .field("remaining", &self.remaining)
+            .field("inclusive", &self.inclusive)
+            .finish_non_exhaustive()
+    }
+}
+
+// Checked before pulling, so that no item is pulled past the one exceeding the budget.
+fn within_budget<T>(
+    items: impl IntoIterator<Item = T>,
+    mut cost: impl FnMut(&T) -> usize,
+    remaining: &mut usize,
+    inclusive: bool,
+    exceeded: &mut bool,
+) -> impl Iterator<Item = T> {
+    let mut items = items.into_iter();
+    iter::from_fn(move || {
+        if *exceeded {
+            return None;
+        }
+
+        let item = items.next()?;
+        if let Some(new_remaining) = remaining.checked_sub(cost(&item)) {
+            *remaining = new_remaining;
+            Some(item)
+        } else {
+            *exceeded = true;
+            inclusive.then_some(item)
+        }
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4_usize, ..=6),
+            budget in ..=10_usize,
+            take_count in ..=6_usize,
+            inclusive in any::<bool>(),
+        ) {
+            all_collect_methods_impl(nums, budget, take_count, inclusive)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<usize>,
+        budget: usize,
+        take_count: usize,
+        inclusive: bool,
+    ) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                let collector = vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .take_budget(budget, |&num| num);
+
+                if inclusive {
+                    collector.inclusive()
+                } else {
+                    collector
+                }
+            },
+            should_break_pred: |iter| {
+                iter.len() > within(iter.clone(), budget) || iter.len() >= take_count
+            },
+            pred: |iter, output, remaining| {
+                let within = within(iter.clone(), budget);
+                let exceeded = iter.len() > within;
+                let accepted = within + usize::from(exceeded && inclusive);
+                let collected = accepted.min(take_count);
+                let consumed = if take_count <= within {
+                    take_count
+                } else {
+                    within + usize::from(exceeded)
+                };
+
+                if iter.clone().take(collected).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The number of leading items whose total cost fits in the budget.
+    fn within(iter: impl Iterator<Item = usize>, budget: usize) -> usize {
+        let mut total = 0;
+        iter.take_while(|&num| {
+            total += num;
+            total <= budget
+        })
+        .count()
+    }
+}
@@ -0,0 +1,253 @@
+use std::{ops::ControlFlow, time::Instant};
+
+use crate::{
+    collector::{Collector, CollectorBase},
+    time::{Clock, SystemClock},
+};
+
+/// A collector that stops accumulating once a deadline has passed.
+///
+/// This `struct` is created by [`CollectorBase::take_until()`].
+/// See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct TakeUntil<C, K = SystemClock> {
+    collector: C,
+    deadline: Instant,
+    clock: K,
+    every: usize,
+    // Items collected since the clock was last read.
+    count: usize,
+    expired: bool,
+}
+
+impl<C> TakeUntil<C> {
+    pub(in crate::collector) fn new(collector: C, deadline: Instant) -> Self {
+        Self {
+            collector,
+            deadline,
+            clock: SystemClock,
+            every: 1,
+            count: 0,
+            expired: false,
+        }
+    }
+}
+
+impl<C, K> TakeUntil<C, K> {
+    /// Reads the clock only after every `n` items collected, instead of after every item.
+    ///
+    /// This lowers the overhead when collecting each item is cheap compared to reading the clock,
+    /// at the cost of collecting up to `n` items past the deadline,
+    /// since the clock is read after collecting an item, not before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use komadori::prelude::*;
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(50);
+    ///
+    /// let sum = (1..=1000).feed_into(i32::adding().take_until(deadline).check_every(64));
+    ///
+    /// // Unless this machine is very slow.
+    /// assert_eq!(sum, 500500);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn check_every(self, n: usize) -> Self {
+        assert!(n != 0, "the check interval must be non-zero");
+
+        Self { every: n, ..self }
+    }
+
+    /// Reads the time from another [`Clock`], such as a [`MockClock`] in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use komadori::{prelude::*, time::{Clock, MockClock}};
+    ///
+    /// let clock = MockClock::new();
+    /// let mut collector = vec![]
+    ///     .into_collector()
+    ///     .take_until(clock.now() + Duration::from_secs(1))
+    ///     .with_clock(&clock);
+    ///
+    /// assert!(collector.collect(1).is_continue());
+    /// clock.advance(Duration::from_secs(1));
+    /// assert!(collector.collect(2).is_break());
+    ///
+    /// assert_eq!(collector.finish(), [1, 2]);
+    /// ```
+    ///
+    /// [`MockClock`]: crate::time::MockClock
+    #[inline]
+    pub fn with_clock<K2>(self, clock: K2) -> TakeUntil<C, K2>
+    where
+        K2: Clock,
+    {
+        TakeUntil {
+            collector: self.collector,
+            deadline: self.deadline,
+            clock,
+            every: self.every,
+            count: self.count,
+            expired: self.expired,
+        }
+    }
+}
+
+impl<C, K> TakeUntil<C, K>
+where
+    K: Clock,
+{
+    #[inline]
+    fn has_expired(&self) -> bool {
+        self.expired || self.clock.now() >= self.deadline
+    }
+
+    #[inline]
+    fn tick(&mut self) -> ControlFlow<()> {
+        self.count += 1;
+        if self.count >= self.every {
+            self.count = 0;
+            self.expired = self.has_expired();
+        }
+
+        if self.expired {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<C, K> CollectorBase for TakeUntil<C, K>
+where
+    C: CollectorBase,
+    K: Clock,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        // The clock is read here so that no item is collected past an already passed deadline.
+        if self.has_expired() {
+            ControlFlow::Break(())
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, K, T> Collector<T> for TakeUntil<C, K>
+where
+    C: Collector<T>,
+    K: Clock,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.expired {
+            return ControlFlow::Break(());
+        }
+
+        let cf = self.collector.collect(item);
+        self.tick()?;
+        cf
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        if self.expired {
+            return ControlFlow::Break(());
+        }
+
+        items.into_iter().try_for_each(|item| {
+            let cf = self.collector.collect(item);
+            self.tick()?;
+            cf
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::{
+        rc::Rc,
+        time::{Duration, Instant},
+    };
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::time::MockClock;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::inspect()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=6),
+            deadline_secs in 1..=6_u64,
+            every in 1..=3_usize,
+        ) {
+            all_collect_methods_impl(nums, deadline_secs, every)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        deadline_secs: u64,
+        every: usize,
+    ) -> TestCaseResult {
+        let start = Instant::now();
+        let deadline = start + Duration::from_secs(deadline_secs);
+        // The clock advances by a second before each item is collected,
+        // and is read after every `every` items.
+        let stop_after = (deadline_secs as usize).div_ceil(every) * every;
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                let clock = Rc::new(MockClock::starting_at(start));
+
+                vec![]
+                    .into_collector()
+                    .take_until(deadline)
+                    .check_every(every)
+                    .with_clock(Rc::clone(&clock))
+                    .inspect(move |_| clock.advance(Duration::from_secs(1)))
+            },
+            should_break_pred: |iter| iter.count() >= stop_after,
+            pred: |mut iter, output, remaining| {
+                if iter.by_ref().take(stop_after).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -34,6 +34,12 @@ where
         // do anything besides delegating to the underlying collector.
         self.collector.break_hint()
     }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
 }
 
 impl<C, T, F> Collector<T> for TakeWhile<C, F>
@@ -85,7 +91,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     // Precondition:
     // - `Vec::IntoCollector`
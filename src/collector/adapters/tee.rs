@@ -1,12 +1,13 @@
 use std::{iter, ops::ControlFlow};
 
-use crate::collector::{Collector, CollectorBase, Fuse};
+use crate::collector::{Collector, CollectorBase, Fuse, SnapshotCollector};
 
 /// A collector that lets both collectors collect the same item.
 ///
 /// This `struct` is created by [`CollectorBase::tee()`].
 /// See its documentation for more.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tee<C1, C2> {
     collector1: Fuse<C1>,
     collector2: Fuse<C2>,
@@ -45,6 +46,23 @@ where
             ControlFlow::Continue(())
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector1.reserve(additional_min, additional_max);
+        self.collector2.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C1, C2> SnapshotCollector for Tee<C1, C2>
+where
+    C1: SnapshotCollector,
+    C2: SnapshotCollector,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        (self.collector1.peek_output(), self.collector2.peek_output())
+    }
 }
 
 impl<T, C1, C2> Collector<T> for Tee<C1, C2>
@@ -120,7 +138,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
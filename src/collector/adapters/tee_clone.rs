@@ -45,6 +45,12 @@ where
             ControlFlow::Continue(())
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector1.reserve(additional_min, additional_max);
+        self.collector2.reserve(additional_min, additional_max);
+    }
 }
 
 impl<T, C1, C2> Collector<T> for TeeClone<C1, C2>
@@ -141,7 +147,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
@@ -55,6 +55,12 @@ where
             ControlFlow::Continue(())
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector1.reserve(additional_min, additional_max);
+        self.collector2.reserve(additional_min, additional_max);
+    }
 }
 
 impl<T, C1, C2> Collector<T> for TeeFunnel<C1, C2>
@@ -73,27 +79,6 @@ where
         }
     }
 
-    // fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
-    //     let (lower1, upper1) = self.collector1.size_hint();
-
-    //     // Both have the same theme: the 2nd collector reserves the left-over amount.
-    //     let (reserve_lower1, reserve_lower2) = if additional_min > lower1 {
-    //         (lower1, additional_min - lower1)
-    //     } else {
-    //         (additional_min, 0)
-    //     };
-
-    //     let (reserve_upper1, reserve_upper2) = match (additional_max, upper1) {
-    //         (Some(additional_max), Some(upper1)) if additional_max > upper1 => {
-    //             (Some(upper1), Some(additional_max - upper1))
-    //         }
-    //         (additional_max, _) => (additional_max, Some(0)),
-    //     };
-
-    //     self.collector1.reserve(reserve_lower1, reserve_upper1);
-    //     self.collector2.reserve(reserve_lower2, reserve_upper2);
-    // }
-
     // fn size_hint(&self) -> (usize, Option<usize>) {
     //     let (lower1, upper1) = self.collector1.size_hint();
     //     let (lower2, upper2) = self.collector2.size_hint();
@@ -214,7 +199,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
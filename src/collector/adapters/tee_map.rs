@@ -0,0 +1,149 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, Tee};
+
+/// A collector that lets both collectors collect the same item,
+/// then combines their outputs with a closure.
+///
+/// This `struct` is created by [`CollectorBase::tee_map()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct TeeMap<C1, C2, F> {
+    tee: Tee<C1, C2>,
+    f: F,
+}
+
+impl<C1, C2, F> TeeMap<C1, C2, F>
+where
+    C1: CollectorBase,
+    C2: CollectorBase,
+{
+    pub(in crate::collector) fn new(collector1: C1, collector2: C2, f: F) -> Self {
+        Self {
+            tee: Tee::new(collector1, collector2),
+            f,
+        }
+    }
+}
+
+impl<C1, C2, F, R> CollectorBase for TeeMap<C1, C2, F>
+where
+    C1: CollectorBase,
+    C2: CollectorBase,
+    F: FnOnce(C1::Output, C2::Output) -> R,
+{
+    type Output = R;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        let (output1, output2) = self.tee.finish();
+        (self.f)(output1, output2)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.tee.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.tee.reserve(additional_min, additional_max);
+    }
+}
+
+impl<T, C1, C2, F, R> Collector<T> for TeeMap<C1, C2, F>
+where
+    C1: Collector<T>,
+    C2: Collector<T>,
+    T: Copy,
+    F: FnOnce(C1::Output, C2::Output) -> R,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.tee.collect(item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.tee.collect_many(items)
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let (output1, output2) = self.tee.collect_then_finish(items);
+        (self.f)(output1, output2)
+    }
+}
+
+impl<C1, C2, F> Debug for TeeMap<C1, C2, F>
+where
+    C1: Debug,
+    C2: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TeeMap")
+            .field("tee", &self.tee)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::collector::CollectorBase::tee()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=4),
+            first_count in ..=4_usize,
+            second_count in ..=4_usize,
+        ) {
+            all_collect_methods_impl(nums, first_count, second_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        first_count: usize,
+        second_count: usize,
+    ) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![].into_collector().take(first_count).tee_map(
+                    vec![].into_collector().take(second_count),
+                    |mut output1, output2| {
+                        output1.extend(output2);
+                        output1
+                    },
+                )
+            },
+            should_break_pred: |iter| iter.count() >= first_count.max(second_count),
+            pred: |iter, output, remaining| {
+                let max_len = first_count.max(second_count);
+
+                if output.into_iter().ne(iter
+                    .clone()
+                    .take(first_count)
+                    .chain(iter.clone().take(second_count)))
+                {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(max_len).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
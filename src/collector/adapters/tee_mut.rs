@@ -55,6 +55,12 @@ where
             ControlFlow::Continue(())
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector1.reserve(additional_min, additional_max);
+        self.collector2.reserve(additional_min, additional_max);
+    }
 }
 
 impl<'i, T, C1, C2> Collector<&'i mut T> for TeeMut<C1, C2>
@@ -130,7 +136,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{
+    use crate::testkit::{
         CollectorTestParts, CollectorTester, CollectorTesterExt, PredError, none_iter_for_fuse_test,
     };
 
@@ -46,6 +46,12 @@ where
             ControlFlow::Continue(())
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector1.reserve(additional_min, additional_max);
+        self.collector2.reserve(additional_min, additional_max);
+    }
 }
 
 // The implementation here is basically similar to `tee_clone`.
@@ -142,7 +148,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Precondition:
@@ -0,0 +1,295 @@
+use std::{fmt::Debug, marker::PhantomData, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, IntoCollectorBase, PartitionResult};
+
+/// A collector that converts every collected item with [`TryFrom`] before collecting,
+/// discarding items that fail to convert.
+///
+/// This `struct` is created by [`CollectorBase::try_convert()`]. See its documentation for more.
+pub struct TryConvert<C, U> {
+    collector: C,
+    _marker: PhantomData<fn(U)>,
+}
+
+/// A collector that converts every collected item with [`TryFrom`] before collecting,
+/// sending the conversion errors to another collector.
+///
+/// This `struct` is created by [`TryConvert::errors_into()`]. See its documentation for more.
+pub struct TryConvertErrorsInto<C, U, E> {
+    // The conversion is `map(U::try_from)` followed by this.
+    partition: PartitionResult<C, E>,
+    _marker: PhantomData<fn(U)>,
+}
+
+impl<C, U> TryConvert<C, U> {
+    pub(in crate::collector) fn new(collector: C) -> Self {
+        Self {
+            collector,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, U> TryConvert<C, U>
+where
+    C: CollectorBase,
+{
+    /// Sends the conversion errors to another collector instead of discarding them.
+    ///
+    /// The [`Output`](CollectorBase::Output) is then a tuple containing the outputs of
+    /// the underlying collector and the error collector, in order.
+    /// Similar to [`partition_result()`](CollectorBase::partition_result),
+    /// it only stops when **both** collectors have stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let (bytes, errs) = [300, 5, -1, 255].into_iter().feed_into(
+    ///     vec![]
+    ///         .into_collector()
+    ///         .try_convert::<u8>()
+    ///         .errors_into(vec![]),
+    /// );
+    ///
+    /// assert_eq!(bytes, [5, 255]);
+    /// assert_eq!(errs.len(), 2);
+    /// ```
+    #[inline]
+    pub fn errors_into<E>(self, errors: E) -> TryConvertErrorsInto<C, U, E::IntoCollector>
+    where
+        E: IntoCollectorBase,
+    {
+        TryConvertErrorsInto {
+            partition: PartitionResult::new(self.collector, errors.into_collector()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, U> CollectorBase for TryConvert<C, U>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may fail to convert.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, U, T> Collector<T> for TryConvert<C, U>
+where
+    C: Collector<U>,
+    U: TryFrom<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match U::try_from(item) {
+            Ok(item) => self.collector.collect(item),
+            Err(_) => ControlFlow::Continue(()),
+        }
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.collector
+            .collect_many(items.into_iter().filter_map(|item| U::try_from(item).ok()))
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector
+            .collect_then_finish(items.into_iter().filter_map(|item| U::try_from(item).ok()))
+    }
+}
+
+impl<C, U, E> CollectorBase for TryConvertErrorsInto<C, U, E>
+where
+    C: CollectorBase,
+    E: CollectorBase,
+{
+    type Output = (C::Output, E::Output);
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.partition.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.partition.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.partition.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, U, E, T> Collector<T> for TryConvertErrorsInto<C, U, E>
+where
+    C: Collector<U>,
+    U: TryFrom<T>,
+    E: Collector<U::Error>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.partition.collect(U::try_from(item))
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.partition
+            .collect_many(items.into_iter().map(U::try_from))
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.partition
+            .collect_then_finish(items.into_iter().map(U::try_from))
+    }
+}
+
+// Derives would bound `U`, which is only a marker.
+impl<C: Clone, U> Clone for TryConvert<C, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.collector.clone())
+    }
+}
+
+impl<C: Debug, U> Debug for TryConvert<C, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TryConvert")
+            .field("collector", &self.collector)
+            .finish()
+    }
+}
+
+impl<C: Clone, U, E: Clone> Clone for TryConvertErrorsInto<C, U, E> {
+    fn clone(&self) -> Self {
+        Self {
+            partition: self.partition.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: Debug, U, E: Debug> Debug for TryConvertErrorsInto<C, U, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TryConvertErrorsInto")
+            .field("partition", &self.partition)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(-300..300_i32, ..=5),
+            take_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        /// - [`crate::collector::Collector::partition_result()`]
+        #[test]
+        fn errors_into_all_collect_methods(
+            nums in propvec(-300..300_i32, ..=5),
+            take_count in ..=5_usize,
+        ) {
+            errors_into_all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || vec![].into_collector().take(take_count).try_convert::<u8>(),
+            should_break_pred: |iter| {
+                take_count == 0
+                    || iter
+                        .filter_map(|num| u8::try_from(num).ok())
+                        .nth(take_count - 1)
+                        .is_some()
+            },
+            pred: |mut iter, output, remaining| {
+                let expected: Vec<_> = iter
+                    .by_ref()
+                    .filter_map(|num| u8::try_from(num).ok())
+                    .take(take_count)
+                    .collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn errors_into_all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .try_convert::<u8>()
+                    .errors_into(vec![])
+                    .map_output(|(bytes, errs)| (bytes, errs.len()))
+            },
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let expected = (
+                    iter.clone()
+                        .filter_map(|num| u8::try_from(num).ok())
+                        .take(take_count)
+                        .collect::<Vec<_>>(),
+                    iter.filter(|&num| u8::try_from(num).is_err()).count(),
+                );
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,132 @@
+use std::{collections::HashSet, hash::Hash, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that skips items equal to any item before it.
+///
+/// This `struct` is created by [`CollectorBase::unique()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Unique<C, T> {
+    collector: C,
+    seen: HashSet<T>,
+}
+
+impl<C, T> Unique<C, T> {
+    pub(in crate::collector) fn new(collector: C) -> Self {
+        Self {
+            collector,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<C, T> CollectorBase for Unique<C, T>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, T> Collector<T> for Unique<C, T>
+where
+    C: Collector<T>,
+    T: Eq + Hash + Clone,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if is_new(&mut self.seen, &item) {
+            self.collector.collect(item)
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let seen = &mut self.seen;
+        self.collector
+            .collect_many(items.into_iter().filter(|item| is_new(seen, item)))
+    }
+
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector.collect_then_finish(
+            items
+                .into_iter()
+                .filter(|item| is_new(&mut self.seen, item)),
+        )
+    }
+}
+
+// Only clones the item if it has not been seen.
+#[inline]
+fn is_new<T>(seen: &mut HashSet<T>, item: &T) -> bool
+where
+    T: Eq + Hash + Clone,
+{
+    !seen.contains(item) && seen.insert(item.clone())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::collections::HashSet;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..5, ..=8),
+            take_count in ..=8_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || vec![].into_collector().take(take_count).unique(),
+            should_break_pred: |iter| iter.filter(is_new()).count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let expected = iter.by_ref().filter(is_new()).take(take_count);
+
+                if expected.ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn is_new() -> impl FnMut(&i32) -> bool {
+        let mut seen = HashSet::new();
+        move |&num| seen.insert(num)
+    }
+}
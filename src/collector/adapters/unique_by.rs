@@ -0,0 +1,142 @@
+use std::{collections::HashSet, fmt::Debug, hash::Hash, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that skips items whose key equals that of any item before it.
+///
+/// This `struct` is created by [`CollectorBase::unique_by()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct UniqueBy<C, F, K> {
+    collector: C,
+    f: F,
+    seen: HashSet<K>,
+}
+
+impl<C, F, K> UniqueBy<C, F, K> {
+    pub(in crate::collector) fn new(collector: C, f: F) -> Self {
+        Self {
+            collector,
+            f,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<C, F, K> CollectorBase for UniqueBy<C, F, K>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, _additional_min: usize, additional_max: Option<usize>) {
+        // Any number of items may be rejected.
+        self.collector.reserve(0, additional_max);
+    }
+}
+
+impl<C, T, F, K> Collector<T> for UniqueBy<C, F, K>
+where
+    C: Collector<T>,
+    F: FnMut(&T) -> K,
+    K: Eq + Hash,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.seen.insert((self.f)(&item)) {
+            self.collector.collect(item)
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let Self { collector, f, seen } = self;
+        collector.collect_many(items.into_iter().filter(|item| seen.insert(f(item))))
+    }
+
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let Self {
+            collector,
+            mut f,
+            mut seen,
+        } = self;
+
+        collector.collect_then_finish(items.into_iter().filter(|item| seen.insert(f(item))))
+    }
+}
+
+impl<C: Debug, F, K: Debug> Debug for UniqueBy<C, F, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UniqueBy")
+            .field("collector", &self.collector)
+            .field("seen", &self.seen)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::collections::HashSet;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..10, ..=8),
+            take_count in ..=8_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .unique_by(|&num| num / 2)
+            },
+            should_break_pred: |iter| iter.filter(is_new()).count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let expected = iter.by_ref().filter(is_new()).take(take_count);
+
+                if expected.ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn is_new() -> impl FnMut(&i32) -> bool {
+        let mut seen = HashSet::new();
+        move |&num| seen.insert(num / 2)
+    }
+}
@@ -7,6 +7,7 @@ use crate::collector::{Collector, CollectorBase, Fuse};
 ///
 /// This `struct` is created by [`CollectorBase::unzip()`]. See its documentation for more.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Unzip<C1, C2> {
     // `Fuse` is neccessary since either may end earlier.
     // It can ease the implementation.
@@ -54,6 +55,12 @@ where
             ControlFlow::Continue(())
         }
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector1.reserve(additional_min, additional_max);
+        self.collector2.reserve(additional_min, additional_max);
+    }
 }
 
 impl<C1, C2, T1, T2> Collector<(T1, T2)> for Unzip<C1, C2>
@@ -138,7 +145,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         /// Since `unzip()` is essentially just `combine()` (but used for destructuring),
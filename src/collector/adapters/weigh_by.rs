@@ -0,0 +1,253 @@
+use std::{fmt::Debug, iter, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that keeps a running total of the cost of collected items.
+///
+/// This `struct` is created by [`CollectorBase::weigh_by()`].
+/// See its documentation for more.
+#[derive(Clone)]
+pub struct WeighBy<C, F> {
+    collector: C,
+    cost: F,
+    total: usize,
+    ceiling: Option<usize>,
+}
+
+impl<C, F> WeighBy<C, F> {
+    pub(in crate::collector) fn new(collector: C, cost: F) -> Self {
+        Self {
+            collector,
+            cost,
+            total: 0,
+            ceiling: None,
+        }
+    }
+
+    /// Returns the total cost of the items collected so far.
+    #[inline]
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Stops accumulating once the total cost reaches `max`.
+    ///
+    /// The item that makes the total reach `max` is still collected.
+    /// To reject that item instead, see [`take_budget()`](CollectorBase::take_budget).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = vec![]
+    ///     .into_collector()
+    ///     .weigh_by(|s: &&str| s.len())
+    ///     .ceiling(5);
+    ///
+    /// assert!(collector.collect("abc").is_continue());
+    /// assert_eq!(collector.total(), 3);
+    ///
+    /// // Reaches the ceiling.
+    /// assert!(collector.collect("defg").is_break());
+    ///
+    /// assert_eq!(collector.finish(), (vec!["abc", "defg"], 7));
+    /// ```
+    #[inline]
+    pub fn ceiling(self, max: usize) -> Self {
+        Self {
+            ceiling: Some(max),
+            ..self
+        }
+    }
+}
+
+#[inline]
+fn has_reached(total: usize, ceiling: Option<usize>) -> bool {
+    ceiling.is_some_and(|ceiling| total >= ceiling)
+}
+
+impl<C, F> CollectorBase for WeighBy<C, F>
+where
+    C: CollectorBase,
+{
+    type Output = (C::Output, usize);
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        (self.collector.finish(), self.total)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if has_reached(self.total, self.ceiling) {
+            ControlFlow::Break(())
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        if self.ceiling.is_some() {
+            // Any number of items may be rejected.
+            self.collector.reserve(0, additional_max);
+        } else {
+            self.collector.reserve(additional_min, additional_max);
+        }
+    }
+}
+
+impl<C, T, F> Collector<T> for WeighBy<C, F>
+where
+    C: Collector<T>,
+    F: FnMut(&T) -> usize,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.total += (self.cost)(&item);
+        let cf = self.collector.collect(item);
+
+        if has_reached(self.total, self.ceiling) {
+            ControlFlow::Break(())
+        } else {
+            cf
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let cf = self.collector.collect_many(weighed(
+            items,
+            &mut self.cost,
+            &mut self.total,
+            self.ceiling,
+        ));
+
+        if has_reached(self.total, self.ceiling) {
+            ControlFlow::Break(())
+        } else {
+            cf
+        }
+    }
+
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let Self {
+            collector,
+            mut cost,
+            mut total,
+            ceiling,
+        } = self;
+
+        let output = collector.collect_then_finish(weighed(items, &mut cost, &mut total, ceiling));
+        (output, total)
+    }
+}
+
+impl<C: Debug, F> Debug for WeighBy<C, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeighBy")
+            .field("collector", &self.collector)
+            .field("total", &self.total)
+            .field("ceiling", &self.ceiling)
+            .finish_non_exhaustive()
+    }
+}
+
+// Checked before pulling, so that no item is pulled past the one reaching the ceiling.
+fn weighed<T>(
+    items: impl IntoIterator<Item = T>,
+    mut cost: impl FnMut(&T) -> usize,
+    total: &mut usize,
+    ceiling: Option<usize>,
+) -> impl Iterator<Item = T> {
+    let mut items = items.into_iter();
+    iter::from_fn(move || {
+        if has_reached(*total, ceiling) {
+            return None;
+        }
+
+        let item = items.next()?;
+        *total += cost(&item);
+        Some(item)
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4_usize, ..=6),
+            ceiling in proptest::option::of(..=10_usize),
+            take_count in ..=6_usize,
+        ) {
+            all_collect_methods_impl(nums, ceiling, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<usize>,
+        ceiling: Option<usize>,
+        take_count: usize,
+    ) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                let collector = vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .weigh_by(|&num| num);
+
+                match ceiling {
+                    Some(max) => collector.ceiling(max),
+                    None => collector,
+                }
+            },
+            should_break_pred: |iter| stop_at(iter, ceiling, take_count).is_some(),
+            pred: |iter, (output, total), remaining| {
+                let consumed = stop_at(iter.clone(), ceiling, take_count).unwrap_or(iter.len());
+
+                if iter.clone().take(consumed).ne(output)
+                    || iter.clone().take(consumed).sum::<usize>() != total
+                {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The number of items consumed before stopping, if it stops.
+    fn stop_at(
+        iter: impl ExactSizeIterator<Item = usize>,
+        ceiling: Option<usize>,
+        take_count: usize,
+    ) -> Option<usize> {
+        let len = iter.len();
+        let mut total = 0;
+        let prefix_totals = iter.map(|num| {
+            total += num;
+            total
+        });
+
+        std::iter::once(0)
+            .chain(prefix_totals)
+            .position(|total| ceiling.is_some_and(|ceiling| total >= ceiling))
+            .into_iter()
+            .chain((take_count <= len).then_some(take_count))
+            .min()
+    }
+}
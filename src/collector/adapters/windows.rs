@@ -0,0 +1,133 @@
+use std::ops::ControlFlow;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that collects each window of the last `n` items as a slice.
+///
+/// This `struct` is created by [`CollectorBase::windows()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Windows<C, T> {
+    collector: C,
+    n: usize,
+    // The current window is the last `n` items.
+    // Earlier items are drained before it would hold `2 * n` items,
+    // so that each item is moved at most once.
+    buf: Vec<T>,
+}
+
+impl<C, T> Windows<C, T> {
+    #[track_caller]
+    pub(in crate::collector) fn new(collector: C, n: usize) -> Self {
+        assert!(n != 0, "window size must be non-zero");
+
+        Self {
+            collector,
+            n,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl<C, T> CollectorBase for Windows<C, T>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        // Items needed before the first window is full.
+        let missing = (self.n - 1).saturating_sub(self.buf.len());
+        let windows = |additional: usize| additional.saturating_sub(missing);
+        self.collector
+            .reserve(windows(additional_min), additional_max.map(windows));
+    }
+}
+
+impl<C, T> Collector<T> for Windows<C, T>
+where
+    C: for<'a> Collector<&'a [T]>,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.buf.len() == 2 * self.n - 1 {
+            self.buf.drain(..self.n);
+        }
+        self.buf.push(item);
+
+        match self.buf.len().checked_sub(self.n) {
+            Some(start) => self.collector.collect(&self.buf[start..]),
+            None => self.collector.break_hint(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::clb_mut;
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::map()`]
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=8),
+            n in 1..=4_usize,
+            take_count in ..=6_usize,
+        ) {
+            all_collect_methods_impl(nums, n, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, n: usize, take_count: usize) -> TestCaseResult {
+        let window_count = (nums.len() + 1).saturating_sub(n);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .map(clb_mut!(|window: &[i32]| -> Vec<i32> { window.to_vec() }))
+                    .windows(n)
+            },
+            should_break_pred: |_| window_count >= take_count,
+            pred: |iter, output, remaining| {
+                let consumed = match take_count {
+                    0 => 0,
+                    _ if window_count >= take_count => n - 1 + take_count,
+                    _ => nums.len(),
+                };
+
+                if nums.windows(n).take(take_count).ne(&output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,167 @@
+use std::{iter, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pairs each item with the next element of an iterator.
+///
+/// This `struct` is created by [`CollectorBase::zip_with()`].
+/// See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct ZipWith<C, I>
+where
+    I: Iterator,
+{
+    collector: C,
+    iter: I,
+    // Pulled ahead, so that `break_hint()` knows whether the iterator is exhausted.
+    // Once it is `None`, the iterator is never pulled again.
+    next: Option<I::Item>,
+}
+
+impl<C, I> ZipWith<C, I>
+where
+    I: Iterator,
+{
+    pub(in crate::collector) fn new(collector: C, mut iter: I) -> Self {
+        Self {
+            collector,
+            next: iter.next(),
+            iter,
+        }
+    }
+}
+
+impl<C, I> CollectorBase for ZipWith<C, I>
+where
+    C: CollectorBase,
+    I: Iterator,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.next.is_none() {
+            ControlFlow::Break(())
+        } else {
+            self.collector.break_hint()
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        // At most as many items as the iterator has left are paired.
+        let pending = usize::from(self.next.is_some());
+        let (lower, upper) = self.iter.size_hint();
+        let lower = lower.saturating_add(pending);
+        let upper = upper.and_then(|upper| upper.checked_add(pending));
+
+        let additional_max = match (additional_max, upper) {
+            (Some(max), Some(upper)) => Some(max.min(upper)),
+            (max, upper) => max.or(upper),
+        };
+        self.collector
+            .reserve(additional_min.min(lower), additional_max);
+    }
+}
+
+impl<C, T, I> Collector<T> for ZipWith<C, I>
+where
+    C: Collector<(T, I::Item)>,
+    I: Iterator,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let Some(other) = self.next.take() else {
+            return ControlFlow::Break(());
+        };
+
+        self.collector.collect((item, other))?;
+        self.next = self.iter.next();
+        self.break_hint()
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.collector
+            .collect_many(zipped(items, &mut self.iter, &mut self.next))?;
+        self.break_hint()
+    }
+
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.collector
+            .collect_then_finish(zipped(items, &mut self.iter, &mut self.next))
+    }
+}
+
+// Checked before pulling, so that no item is pulled once the iterator is exhausted.
+fn zipped<T, I: Iterator>(
+    items: impl IntoIterator<Item = T>,
+    iter: &mut I,
+    next: &mut Option<I::Item>,
+) -> impl Iterator<Item = (T, I::Item)> {
+    let mut items = items.into_iter();
+    iter::from_fn(move || {
+        next.as_ref()?;
+
+        let item = items.next()?;
+        let other = next.take()?;
+        *next = iter.next();
+        Some((item, other))
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=6),
+            other_len in ..=6_usize,
+            take_count in ..=6_usize,
+        ) {
+            all_collect_methods_impl(nums, other_len, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        other_len: usize,
+        take_count: usize,
+    ) -> TestCaseResult {
+        let stop_at = other_len.min(take_count);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                vec![]
+                    .into_collector()
+                    .take(take_count)
+                    .zip_with(0..other_len)
+            },
+            should_break_pred: |iter| iter.count() >= stop_at,
+            pred: |mut iter, output, remaining| {
+                if iter.by_ref().take(stop_at).zip(0..).ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,141 @@
+use std::ops::ControlFlow;
+
+use super::{Collector, CollectorBase};
+
+/// A wrapper that implements [`Extend`] for a collector.
+///
+/// This allows a collector to be handed to APIs that only know how to
+/// [`extend()`](Extend::extend) a sink, then be finished later.
+/// Pass [`by_ref()`](CollectorBase::by_ref) to keep the collector
+/// on the caller's side.
+///
+/// Since [`Extend`] has no way to signal it, items are silently
+/// not consumed once the collector has stopped accumulating.
+///
+/// It is also a collector itself, forwarding everything to the underlying collector.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::Max, collector::AsExtend};
+///
+/// // Some code that only knows `Extend`.
+/// fn fill(sink: &mut impl Extend<i32>) {
+///     sink.extend([4, 2, 6]);
+///     sink.extend([3]);
+/// }
+///
+/// let mut collector = AsExtend::new(Max::new().tee(vec![]));
+/// fill(&mut collector);
+///
+/// assert_eq!(collector.finish(), (Some(6), vec![4, 2, 6, 3]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AsExtend<C>(C);
+
+impl<C> AsExtend<C>
+where
+    C: CollectorBase,
+{
+    /// Wraps a collector.
+    #[inline]
+    pub const fn new(collector: C) -> Self {
+        Self(collector)
+    }
+
+    /// Returns the underlying collector.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+
+    /// Returns a reference to the underlying collector.
+    #[inline]
+    pub const fn get_ref(&self) -> &C {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the underlying collector.
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut C {
+        &mut self.0
+    }
+}
+
+impl<C, T> Extend<T> for AsExtend<C>
+where
+    C: Collector<T>,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let _ = self.0.collect_many(iter);
+    }
+}
+
+impl<C> CollectorBase for AsExtend<C>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.0.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.0.reserve(additional_min, additional_max);
+    }
+}
+
+impl<C, T> Collector<T> for AsExtend<C>
+where
+    C: Collector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.0.collect(item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.0.collect_many(items)
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.0.collect_then_finish(items)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+
+    use crate::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn extend_then_finish(
+            batches in propvec(propvec(any::<i32>(), ..5), ..4),
+            n in ..10_usize,
+        ) {
+            let mut collector = AsExtend::new(vec![].into_collector().take(n));
+            for batch in &batches {
+                collector.extend(batch.iter().copied());
+            }
+
+            let expected: Vec<_> = batches.iter().flatten().copied().take(n).collect();
+            prop_assert_eq!(collector.finish(), expected);
+        }
+    }
+}
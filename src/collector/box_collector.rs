@@ -0,0 +1,174 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+use super::{Collector, CollectorBase};
+
+/// A type-erased collector that collects items of type `T` and outputs `O`.
+///
+/// Unlike `Box<dyn Collector<T>>`, it can still be finished,
+/// since [`finish()`](CollectorBase::finish) cannot be called on a trait object.
+/// This is useful when collectors are chosen at runtime,
+/// or stored alongside others of different types.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::{Max, Min}, collector::BoxCollector};
+///
+/// let want_max = true;
+/// let collector: BoxCollector<'_, i32, Option<i32>> = if want_max {
+///     BoxCollector::new(Max::new())
+/// } else {
+///     BoxCollector::new(Min::new())
+/// };
+///
+/// assert_eq!([4, 2, 6, 3].into_iter().feed_into(collector), Some(6));
+/// ```
+///
+/// A collector can also be erased with [`boxed()`](CollectorBase::boxed).
+pub struct BoxCollector<'a, T, O>(Box<dyn ErasedCollector<T, O> + 'a>);
+
+// The object-safe part of a collector, plus a way to finish it through a `Box`.
+trait ErasedCollector<T, O> {
+    fn collect(&mut self, item: T) -> ControlFlow<()>;
+
+    fn collect_many(&mut self, items: &mut dyn Iterator<Item = T>) -> ControlFlow<()>;
+
+    fn collect_then_finish_boxed(self: Box<Self>, items: &mut dyn Iterator<Item = T>) -> O;
+
+    fn break_hint(&self) -> ControlFlow<()>;
+
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>);
+
+    fn finish_boxed(self: Box<Self>) -> O;
+}
+
+impl<C, T> ErasedCollector<T, C::Output> for C
+where
+    C: Collector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        Collector::collect(self, item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: &mut dyn Iterator<Item = T>) -> ControlFlow<()> {
+        Collector::collect_many(self, items)
+    }
+
+    #[inline]
+    fn collect_then_finish_boxed(self: Box<Self>, items: &mut dyn Iterator<Item = T>) -> C::Output {
+        (*self).collect_then_finish(items)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        CollectorBase::break_hint(self)
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        CollectorBase::reserve(self, additional_min, additional_max);
+    }
+
+    #[inline]
+    fn finish_boxed(self: Box<Self>) -> C::Output {
+        (*self).finish()
+    }
+}
+
+impl<'a, T, O> BoxCollector<'a, T, O> {
+    /// Erases the type of a collector.
+    #[inline]
+    pub fn new<C>(collector: C) -> Self
+    where
+        C: Collector<T, Output = O> + 'a,
+    {
+        Self(Box::new(collector))
+    }
+}
+
+impl<T, O> CollectorBase for BoxCollector<'_, T, O> {
+    type Output = O;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0.finish_boxed()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.0.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.0.reserve(additional_min, additional_max);
+    }
+}
+
+impl<T, O> Collector<T> for BoxCollector<'_, T, O> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.0.collect(item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.0.collect_many(&mut items.into_iter())
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.0.collect_then_finish_boxed(&mut items.into_iter())
+    }
+}
+
+impl<T, O> Debug for BoxCollector<'_, T, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoxCollector").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..5),
+            n in ..5_usize,
+        ) {
+            all_collect_methods_impl(nums, n)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, n: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || BoxCollector::new(vec![].into_collector().take(n)),
+            should_break_pred: |iter| iter.count() >= n,
+            pred: |mut iter, output, remaining| {
+                if output != iter.by_ref().take(n).collect::<Vec<_>>() {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,15 +1,23 @@
-use std::ops::ControlFlow;
+use std::{ops::ControlFlow, sync::atomic::AtomicBool};
 
 #[cfg(feature = "itertools")]
 use itertools::Either;
 
 #[cfg(feature = "unstable")]
-use super::{AltBreakHint, Nest, NestExact, TeeWith};
+use super::{AltBreakHint, TeeWith};
 use super::{
-    Chain, Cloning, Collector, Copying, Filter, FlatMap, Flatten, Funnel, Fuse, Inspect,
-    IntoCollector, IntoCollectorBase, Map, MapOutput, Partition, Skip, Take, TakeWhile, Tee,
-    TeeClone, TeeFunnel, TeeMut, Unbatching, Unzip, assert_collector, assert_collector_base,
+    Alternate, ArrayChunks, CancelOn, CancelWhen, Chain, CheckpointEvery, ChunkBy, Cloning,
+    Collector, Context, Convert, Copying, Dedup, DedupByKey, Enumerate, Filter, FilterWith,
+    FinalizeOnDrop, FlatMap, Flatten, Funnel, Fuse, Inspect, InspectOutput, IntoCollector,
+    IntoCollectorBase, Map, MapOutput, MapWith, NamedOutput, Nest, NestExact, OnBreak, Partition,
+    PartitionResult, SampleAdaptive, SharedState, Skip, SplitWhen, Take, TakeBudget, TakeWhile,
+    Tee, TeeClone, TeeFunnel, TeeMap, TeeMut, TryConvert, Unbatching, Unzip, WeighBy, ZipWith,
+    assert_collector, assert_collector_base,
 };
+#[cfg(feature = "alloc")]
+use super::{BoxCollector, Windows};
+#[cfg(feature = "std")]
+use super::{Detach, Instrument, TakeUntil, Unique, UniqueBy};
 #[cfg(feature = "itertools")]
 use super::{PartitionMap, Update};
 
@@ -34,8 +42,9 @@ use super::{PartitionMap, Update};
 /// The compiler will even emit a warning if you add the
 /// [`Output`](CollectorBase::Output) type.
 ///
-/// However, as a trait object, it is pretty much useless, as the only method
-/// available is [`break_hint()`](CollectorBase::break_hint).
+/// However, as a trait object, it is pretty much useless, as the only methods
+/// available are [`break_hint()`](CollectorBase::break_hint)
+/// and [`reserve()`](CollectorBase::reserve).
 pub trait CollectorBase {
     /// The result this collector yields, via the [`finish()`](CollectorBase::finish) method.
     ///
@@ -143,6 +152,42 @@ pub trait CollectorBase {
         ControlFlow::Continue(())
     }
 
+    /// Hints the collector that at least `additional_min` and at most `additional_max`
+    /// (or an unknown number, if [`None`]) more items are about to be collected,
+    /// so that it may preallocate.
+    ///
+    /// The bounds follow the same convention as [`Iterator::size_hint()`],
+    /// and [`IteratorExt::feed_into()`] calls this method with the iterator's hint.
+    /// Adaptors propagate the hint to their underlying collectors,
+    /// adjusting it whenever they know how many items pass through
+    /// (e.g., [`take()`](CollectorBase::take) caps it,
+    /// while [`filter()`](CollectorBase::filter) drops the lower bound).
+    ///
+    /// This is only a hint. The default implementation does nothing,
+    /// and implementors must not rely on the bounds for correctness.
+    /// A buggy hint may cause too much or too little memory to be allocated,
+    /// but must not cause any other misbehavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = vec![].into_collector().map(|num: i32| num * 2);
+    /// collector.reserve(10, Some(10));
+    ///
+    /// assert!(collector.collect_many(0..10).is_continue());
+    ///
+    /// let nums = collector.finish();
+    /// assert!(nums.capacity() >= 10);
+    /// ```
+    ///
+    /// [`IteratorExt::feed_into()`]: crate::iter::IteratorExt::feed_into
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        let _ = (additional_min, additional_max);
+    }
+
     /// Creates a collector that can "safely" collect items even after
     /// the underlying collector has stopped accumulating,
     /// without triggering undesired behaviors.
@@ -254,6 +299,35 @@ pub trait CollectorBase {
         assert_collector_base(Tee::new(self, other.into_collector()))
     }
 
+    /// Creates a collector that lets both collectors collect the same item,
+    /// then combines their outputs with a closure.
+    ///
+    /// This is the same as [`tee()`](CollectorBase::tee), except that
+    /// the [`Output`](CollectorBase::Output) is `f(output1, output2)` instead of a tuple.
+    /// It saves the [`map_output()`](CollectorBase::map_output) that otherwise
+    /// follows a `tee()` to combine the outputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, iter::Count};
+    ///
+    /// let mean = [4.0, 2.0, 6.0, 3.0]
+    ///     .into_iter()
+    ///     .feed_into(Count::new().tee_map(f64::adding(), |count, sum| sum / count as f64));
+    ///
+    /// assert_eq!(mean, 3.75);
+    /// ```
+    #[inline]
+    fn tee_map<C, F, R>(self, other: C, f: F) -> TeeMap<Self, C::IntoCollector, F>
+    where
+        Self: Sized,
+        C: IntoCollectorBase,
+        F: FnOnce(Self::Output, C::Output) -> R,
+    {
+        assert_collector_base(TeeMap::new(self, other.into_collector(), f))
+    }
+
     /// Creates a collector that lets both collectors collect the same item.
     ///
     /// For each item collected, the first collector collects the item
@@ -604,6 +678,48 @@ pub trait CollectorBase {
         assert_collector_base(Unzip::new(self, other.into_collector()))
     }
 
+    /// Creates a collector that pairs each item with the next element of an iterator,
+    /// then collects the pair.
+    ///
+    /// It stops accumulating once the iterator is exhausted, like [`Iterator::zip()`].
+    /// To know that without waiting for another item,
+    /// the iterator is always pulled one element ahead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = vec![].into_collector().zip_with(["a", "b"]);
+    ///
+    /// assert!(collector.collect(1).is_continue());
+    ///
+    /// // The iterator is exhausted.
+    /// assert!(collector.collect(2).is_break());
+    ///
+    /// assert_eq!(collector.finish(), [(1, "a"), (2, "b")]);
+    /// ```
+    ///
+    /// Nothing is collected if the iterator is empty.
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut nums = 1..=3;
+    /// let pairs = nums.by_ref().feed_into(vec![].into_collector().zip_with(Vec::<char>::new()));
+    ///
+    /// assert!(pairs.is_empty());
+    /// assert_eq!(nums.next(), Some(1));
+    /// ```
+    #[inline]
+    fn zip_with<I>(self, iter: I) -> ZipWith<Self, I::IntoIter>
+    where
+        Self: Sized,
+        I: IntoIterator,
+    {
+        assert_collector_base(ZipWith::new(self, iter.into_iter()))
+    }
+
     /// Creates a collector that feeds every item in the first collector until it stops accumulating,
     /// then continues feeding items into the second one.
     ///
@@ -677,6 +793,49 @@ pub trait CollectorBase {
         assert_collector_base(MapOutput::new(self, f))
     }
 
+    /// Creates a collector that names its output.
+    ///
+    /// The [`Output`](CollectorBase::Output) is a [`Named`] of the output of this collector,
+    /// which prints as `name=value`. When the outputs of several collectors combined
+    /// (e.g., with [`tee()`](CollectorBase::tee) or tuples) are named, the whole output
+    /// can be printed in one go with [`Fields::summary()`].
+    /// See the [`fmt`](crate::fmt) module for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, cmp::{Max, Min}};
+    ///
+    /// let (min, max) = [4, 2, 6, 3]
+    ///     .into_iter()
+    ///     .feed_into((Min::new().named_output("min"), Max::new().named_output("max")));
+    ///
+    /// assert_eq!(min.to_string(), "min=Some(2)");
+    /// assert_eq!(max.into_value(), Some(6));
+    /// ```
+    ///
+    /// ```
+    /// use komadori::{prelude::*, fmt::Fields, iter::Count};
+    ///
+    /// let output = ["a", "bb", "ccc"].into_iter().feed_into(
+    ///     Count::new()
+    ///         .named_output("count")
+    ///         .tee(usize::adding().map(str::len).named_output("total_len")),
+    /// );
+    ///
+    /// assert_eq!(output.summary().to_string(), "count=3, total_len=6");
+    /// ```
+    ///
+    /// [`Named`]: crate::fmt::Named
+    /// [`Fields::summary()`]: crate::fmt::Fields::summary
+    #[inline]
+    fn named_output(self, name: &'static str) -> NamedOutput<Self>
+    where
+        Self: Sized,
+    {
+        assert_collector_base(NamedOutput::new(self, name))
+    }
+
     /// Creates a collector that feeds the underlying collector with
     /// the mutable reference to the item, "pretending" the collector
     /// accepts owned items.
@@ -744,333 +903,1159 @@ pub trait CollectorBase {
         assert_collector::<_, U>(Map::new(self, f))
     }
 
-    /// Creates a collector that uses a closure to determine whether an item should be accumulated.
+    /// Creates a collector that pairs each item with its index, starting from 0,
+    /// then collects the pair.
     ///
-    /// The underlying collector only collects items for which the given predicate returns `true`.
+    /// The index is a [`usize`], like in [`Iterator::enumerate()`].
+    /// To start from another index, see [`enumerate_from()`](CollectorBase::enumerate_from).
+    /// To count more items than a [`usize`] can hold on 32-bit targets,
+    /// see [`enumerate_u64()`](CollectorBase::enumerate_u64).
     ///
-    /// Note that even if an item is not collected, this adaptor will still return
-    /// [`Continue`] as long as the underlying collector does. If you want the collector to stop
-    /// after the first `false`, consider using [`take_while()`](CollectorBase::take_while) instead.
+    /// # Overflow Behavior
+    ///
+    /// The index is not guarded against overflow,
+    /// so collecting more than [`usize::MAX`] items either panics or wraps around,
+    /// depending on whether overflow checks are enabled.
     ///
     /// # Examples
     ///
     /// ```
     /// use komadori::prelude::*;
     ///
-    /// let mut collector = vec![]
-    ///     .into_collector()
-    ///     .filter(|&x| x % 2 == 0);
-    ///
-    /// assert!(collector.collect(2).is_continue());
-    /// assert!(collector.collect(4).is_continue());
-    /// assert!(collector.collect(0).is_continue());
-    ///
-    /// // Still `Continue` even if an item doesn’t satisfy the predicate.
-    /// assert!(collector.collect(1).is_continue());
+    /// let indexed = ['a', 'b', 'c']
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().enumerate());
     ///
-    /// assert_eq!(collector.finish(), [2, 4, 0]);
+    /// assert_eq!(indexed, [(0, 'a'), (1, 'b'), (2, 'c')]);
     /// ```
-    ///
-    /// [`Continue`]: ControlFlow::Continue
     #[inline]
-    fn filter<F, T>(self, pred: F) -> Filter<Self, F>
+    fn enumerate(self) -> Enumerate<Self>
     where
-        Self: Collector<T> + Sized,
-        F: FnMut(&T) -> bool,
+        Self: Sized,
     {
-        assert_collector::<_, T>(Filter::new(self, pred))
+        assert_collector_base(Enumerate::new(self, 0))
     }
 
-    /// Creates a collector that accumulates items as long as a predicate returns `true`.
+    /// Creates a collector that pairs each item with its index, starting from `start`,
+    /// then collects the pair.
     ///
-    /// `take_while()` collects items until it encounters one for which the predicate returns `false`.
-    /// Conceptually, that item and all subsequent ones will **not** be accumulated.
-    /// However, you should ensure that you do not feed more items after it has signaled
-    /// a stop.
+    /// See [`enumerate()`](CollectorBase::enumerate) for more.
     ///
     /// # Examples
     ///
     /// ```
     /// use komadori::prelude::*;
     ///
-    /// let mut collector = "".to_owned()
-    ///     .into_concat()
-    ///     .take_while(|&s| s != "stop");
-    ///
-    /// assert!(collector.collect("abc").is_continue());
-    /// assert!(collector.collect("def").is_continue());
-    ///
-    /// // Immediately stops after "stop".
-    /// assert!(collector.collect("stop").is_break());
+    /// // Line numbers start from 1.
+    /// let numbered = "first\nsecond"
+    ///     .lines()
+    ///     .feed_into(vec![].into_collector().enumerate_from(1));
     ///
-    /// assert_eq!(collector.finish(), "abcdef");
+    /// assert_eq!(numbered, [(1, "first"), (2, "second")]);
     /// ```
-    fn take_while<F, T>(self, pred: F) -> TakeWhile<Self, F>
+    #[inline]
+    fn enumerate_from(self, start: usize) -> Enumerate<Self>
     where
-        Self: Collector<T> + Sized,
-        F: FnMut(&T) -> bool,
+        Self: Sized,
     {
-        assert_collector::<_, T>(TakeWhile::new(self, pred))
+        assert_collector_base(Enumerate::new(self, start))
     }
 
-    // fn step_by()
-
-    /// Creates a collector that distributes items between two collectors based on a predicate.
+    /// Creates a collector that pairs each item with its index as a [`u64`], starting from 0,
+    /// then collects the pair.
     ///
-    /// Items for which the predicate returns `true` are sent to the first collector,
-    /// and those for which it returns `false` go to the second collector.
+    /// Unlike [`enumerate()`](CollectorBase::enumerate), the index does not overflow
+    /// after 2<sup>32</sup> items on 32-bit targets, which suits long-running streams.
     ///
     /// # Examples
     ///
     /// ```
     /// use komadori::prelude::*;
     ///
-    /// let collector = vec![]
-    ///     .into_collector()
-    ///     .partition(|&mut x| x % 2 == 0, vec![]);
-    /// let (evens, odds) = collector.collect_then_finish(-5..5);
+    /// let indexed = ['a', 'b']
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().enumerate_u64());
     ///
-    /// assert_eq!(evens, [-4, -2, 0, 2, 4]);
-    /// assert_eq!(odds, [-5, -3, -1, 1, 3]);
+    /// assert_eq!(indexed, [(0_u64, 'a'), (1, 'b')]);
     /// ```
     #[inline]
-    fn partition<C, F, T>(self, pred: F, other_if_false: C) -> Partition<Self, C::IntoCollector, F>
+    fn enumerate_u64(self) -> Enumerate<Self, u64>
     where
-        Self: Collector<T> + Sized,
-        C: IntoCollector<T>,
-        F: FnMut(&mut T) -> bool,
+        Self: Sized,
     {
-        assert_collector::<_, T>(Partition::new(self, other_if_false.into_collector(), pred))
+        assert_collector_base(Enumerate::new(self, 0))
     }
 
-    /// Creates a collector that lets both collectors collect the same item.
+    /// Creates a collector that calls a closure on each item, with access to shared state,
+    /// before collecting.
     ///
-    /// For each item collected, the first collector collects the item
-    /// mapped by a given closure before the second collector collects it.
-    /// If the second collector stops accumulating, the item will **not**
-    /// be mapped, and instead is fed directly into the first collector.
+    /// The closure takes a reference to the state behind a [`SharedState`] handle,
+    /// such as an `Rc<RefCell<S>>` or an `Arc<RwLock<S>>`, and the item.
+    /// The state is borrowed only while the closure runs, so several branches
+    /// of a [`tee()`](CollectorBase::tee) or a tuple can read the same state,
+    /// and it can still be modified between items.
     ///
-    /// `tee_with()` only stops when **both** collectors have stopped.
+    /// Other than that, it is the same as [`map()`](CollectorBase::map).
     ///
-    /// If the item type of this adapter is `T`, the first collector must implement
-    /// [`Collector<T>`](super::Collector) and [`Collector<U>`](super::Collector),
-    /// and the second collector must implement [`Collector<T>`](super::Collector).
-    /// Since many collectors do not collect two or more types of items,
-    /// `U` is effectively also `T` in this case.
+    /// # Examples
     ///
-    /// The [`Output`](CollectorBase::Output) is a tuple containing the outputs of
-    /// both underlying collectors, in order.
+    /// ```
+    /// use std::{cell::RefCell, collections::HashMap};
+    /// use komadori::prelude::*;
     ///
-    /// See the [module-level documentation](crate::collector) for
-    /// when this adapter is used and other variants of `tee` adapters.
+    /// let names = RefCell::new(HashMap::from([(1, "ada"), (2, "grace")]));
+    /// let name_of = |names: &HashMap<i32, &'static str>, id| names.get(&id).copied();
+    ///
+    /// let mut collector = vec![]
+    ///     .into_collector()
+    ///     .map_with(&names, name_of)
+    ///     .tee(vec![].into_collector().filter_with(&names, |names: &HashMap<_, _>, id| {
+    ///         !names.contains_key(id)
+    ///     }));
+    ///
+    /// collector.collect_many([1, 3]);
+    /// names.borrow_mut().insert(3, "edsger");
+    /// collector.collect(3);
+    ///
+    /// let (names, unknown) = collector.finish();
+    /// assert_eq!(names, [Some("ada"), None, Some("edsger")]);
+    /// assert_eq!(unknown, [3]);
+    /// ```
     #[inline]
-    #[cfg(feature = "unstable")]
-    fn tee_with<C, F, T, U>(self, f: F, other: C) -> TeeWith<Self, C::IntoCollector, F>
+    fn map_with<S, F, T, U>(self, state: S, f: F) -> MapWith<Self, S, F>
     where
-        Self: Collector<T> + Collector<U> + Sized,
-        C: IntoCollector<T>,
-        F: FnMut(&mut T) -> U,
+        Self: Collector<T> + Sized,
+        S: SharedState,
+        F: FnMut(&S::State, U) -> T,
     {
-        assert_collector::<_, T>(TeeWith::new(self, other.into_collector(), f))
+        assert_collector::<_, U>(MapWith::new(self, state, f))
     }
 
-    /// Creates a collector with a custom collection logic.
+    /// Creates a collector that converts every collected item into `U` with [`From`]
+    /// before collecting.
     ///
-    /// This adaptor is useful for behaviors that cannot be expressed
-    /// through existing adaptors without cloning or intermediate allocations.
+    /// This is [`map(U::from)`](CollectorBase::map) without the inference problems
+    /// that `map(Into::into)` often has, and it accepts every item type convertible into `U`.
     ///
     /// # Examples
     ///
     /// ```
     /// use komadori::prelude::*;
     ///
-    /// let mut collector = Vec::<i32>::new()
-    ///     .into_collector()
-    ///     .unbatching(|v, arr: &[_]| v.collect_many(arr));
+    /// let mut collector = vec![].into_collector().convert::<i64>();
     ///
-    /// assert!(collector.collect(&[1, 2, 3]).is_continue());
-    /// assert!(collector.collect(&[4, 5]).is_continue());
-    /// assert!(collector.collect(&[6, 7, 8, 9]).is_continue());
+    /// assert!(collector.collect(1_i32).is_continue());
+    /// assert!(collector.collect(2_u8).is_continue());
+    /// assert!(collector.collect(true).is_continue());
     ///
-    /// assert_eq!(collector.finish(), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// assert_eq!(collector.finish(), [1, 2, 1]);
     /// ```
-    fn unbatching<F, T>(self, f: F) -> Unbatching<Self, F>
+    #[inline]
+    fn convert<U>(self) -> Convert<Self, U>
     where
-        Self: Sized,
-        F: FnMut(&mut Self, T) -> ControlFlow<()>,
+        Self: Collector<U> + Sized,
     {
-        assert_collector_base(Unbatching::new(self, f))
+        assert_collector_base(Convert::new(self))
     }
 
-    // ///
-    // #[inline]
-    // fn map_ref_ref<F, T, U>(self, f: F) -> Map<Self, F>
-    // where
-    //     Self: for<'a> Collector<&'a T> + Sized,
-    //     F: FnMut(&U) -> &T,
-    //     T: ?Sized,
-    //     U: ?Sized,
-    // {
-    //     assert_collector::<_, &U>(Map::new(self, f))
-    // }
-
-    // ///
-    // #[inline]
-    // fn map_mut_ref<F, T, U>(self, f: F) -> Map<Self, F>
-    // where
-    //     Self: for<'a> Collector<&'a T> + Sized,
-    //     F: FnMut(&mut U) -> &T,
-    //     T: ?Sized,
-    //     U: ?Sized,
-    // {
-    //     assert_collector::<_, &mut U>(Map::new(self, f))
-    // }
-
-    // ///
-    // #[inline]
-    // fn map_mut_mut<F, T, U>(self, f: F) -> Map<Self, F>
-    // where
-    //     Self: for<'a> Collector<&'a mut T> + Sized,
-    //     F: FnMut(&mut U) -> &mut T,
-    //     T: ?Sized,
-    //     U: ?Sized,
-    // {
-    //     assert_collector::<_, &mut U>(Map::new(self, f))
-    // }
-
-    /// A collector that flattens items by one level of nesting before collecting.
+    /// Creates a collector that converts every collected item into `U` with [`TryFrom`]
+    /// before collecting.
     ///
-    /// Each item will be converted into an iterator, then the underlying collector
-    /// collects every element in that iterator.
+    /// Items that fail to convert are discarded.
+    /// Use [`errors_into()`](TryConvert::errors_into) to collect the conversion errors instead.
     ///
     /// # Examples
     ///
     /// ```
     /// use komadori::prelude::*;
     ///
-    /// let mut collector = vec![]
-    ///     .into_collector()
-    ///     .flatten();
-    ///
-    /// assert!(collector.collect([1, 2]).is_continue());
-    /// assert!(collector.collect(&[] as &[i32]).is_continue());
-    /// assert!(collector.collect(vec![3, 4, 5]).is_continue());
+    /// let bytes = [300, 5, -1, 255]
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().try_convert::<u8>());
     ///
-    /// assert_eq!(collector.finish(), [1, 2, 3, 4, 5]);
+    /// assert_eq!(bytes, [5, 255]);
     /// ```
     #[inline]
-    fn flatten(self) -> Flatten<Self>
+    fn try_convert<U>(self) -> TryConvert<Self, U>
     where
-        Self: Sized,
+        Self: Collector<U> + Sized,
     {
-        assert_collector_base(Flatten::new(self))
+        assert_collector_base(TryConvert::new(self))
     }
 
-    /// A collector that collects elements in each iterator item provided by a closure.
+    /// Creates a collector that uses a closure to determine whether an item should be accumulated.
     ///
-    /// Each item will be mapped into an iterator by a closure,
-    /// then the underlying collector collects every element in that iterator.
+    /// The underlying collector only collects items for which the given predicate returns `true`.
+    ///
+    /// Note that even if an item is not collected, this adaptor will still return
+    /// [`Continue`] as long as the underlying collector does. If you want the collector to stop
+    /// after the first `false`, consider using [`take_while()`](CollectorBase::take_while) instead.
     ///
     /// # Examples
     ///
     /// ```
     /// use komadori::prelude::*;
     ///
-    /// let mut collector = String::new()
+    /// let mut collector = vec![]
     ///     .into_collector()
-    ///     .flat_map(str::chars);
-    ///
-    /// assert!(collector.collect("elegance ").is_continue());
-    /// assert!(collector.collect("and ").is_continue());
-    /// assert!(collector.collect("radiance").is_continue());
+    ///     .filter(|&x| x % 2 == 0);
     ///
-    /// assert_eq!(collector.finish(), "elegance and radiance");
+    /// assert!(collector.collect(2).is_continue());
+    /// assert!(collector.collect(4).is_continue());
+    /// assert!(collector.collect(0).is_continue());
+    ///
+    /// // Still `Continue` even if an item doesn’t satisfy the predicate.
+    /// assert!(collector.collect(1).is_continue());
+    ///
+    /// assert_eq!(collector.finish(), [2, 4, 0]);
+    /// ```
+    ///
+    /// [`Continue`]: ControlFlow::Continue
+    #[inline]
+    fn filter<F, T>(self, pred: F) -> Filter<Self, F>
+    where
+        Self: Collector<T> + Sized,
+        F: FnMut(&T) -> bool,
+    {
+        assert_collector::<_, T>(Filter::new(self, pred))
+    }
+
+    /// Creates a collector that uses a closure, with access to shared state,
+    /// to determine whether an item should be accumulated.
+    ///
+    /// The state is passed the same way as in [`map_with()`](CollectorBase::map_with).
+    /// Other than that, it is the same as [`filter()`](CollectorBase::filter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{collections::HashSet, sync::{Arc, RwLock}};
+    /// use komadori::prelude::*;
+    ///
+    /// let blocked = Arc::new(RwLock::new(HashSet::from(["spam.example"])));
+    ///
+    /// let allowed = ["a.example", "spam.example", "b.example"]
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().filter_with(
+    ///         Arc::clone(&blocked),
+    ///         |blocked: &HashSet<&str>, host| !blocked.contains(host),
+    ///     ));
+    ///
+    /// assert_eq!(allowed, ["a.example", "b.example"]);
+    /// ```
+    #[inline]
+    fn filter_with<S, F, T>(self, state: S, pred: F) -> FilterWith<Self, S, F>
+    where
+        Self: Collector<T> + Sized,
+        S: SharedState,
+        F: FnMut(&S::State, &T) -> bool,
+    {
+        assert_collector::<_, T>(FilterWith::new(self, state, pred))
+    }
+
+    /// Creates a collector that skips items equal to the previous one.
+    ///
+    /// Only consecutive duplicates are skipped, similar to [`Vec::dedup()`].
+    /// A [`Clone`] of the last accumulated item is kept to compare with the next one,
+    /// so unlike filtering with a `HashSet`, it needs no extra memory per item
+    /// and works on items that are not [`Hash`](std::hash::Hash).
+    ///
+    /// Like [`filter()`](CollectorBase::filter), a skipped item does not make
+    /// this collector stop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let nums = [1, 1, 2, 3, 3, 3, 1].into_iter().feed_into(vec![].into_collector().dedup());
+    ///
+    /// assert_eq!(nums, [1, 2, 3, 1]);
+    /// ```
+    ///
+    /// [`Vec::dedup()`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup
+    #[inline]
+    fn dedup<T>(self) -> Dedup<Self, T>
+    where
+        Self: Collector<T> + Sized,
+        T: PartialEq + Clone,
+    {
+        assert_collector::<_, T>(Dedup::new(self))
+    }
+
+    /// Creates a collector that skips items whose key equals that of the previous item.
+    ///
+    /// Only the key of the last item is kept, so the items need not be [`Clone`].
+    /// Other than that, it is the same as [`dedup()`](CollectorBase::dedup).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let words = ["apple", "avocado", "banana", "blueberry", "apricot"]
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().dedup_by_key(|s: &&str| s.chars().next()));
+    ///
+    /// assert_eq!(words, ["apple", "banana", "apricot"]);
+    /// ```
+    #[inline]
+    fn dedup_by_key<F, T, K>(self, f: F) -> DedupByKey<Self, F, K>
+    where
+        Self: Collector<T> + Sized,
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        assert_collector::<_, T>(DedupByKey::new(self, f))
+    }
+
+    /// Creates a collector that skips items equal to any item before it.
+    ///
+    /// A [`Clone`] of every accumulated item is kept in a `HashSet`,
+    /// so only the first occurrence of each item is accumulated.
+    /// If only consecutive duplicates need to be skipped,
+    /// [`dedup()`](CollectorBase::dedup) uses less memory.
+    ///
+    /// Like [`filter()`](CollectorBase::filter), a skipped item does not make
+    /// this collector stop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let nums = [3, 1, 3, 2, 1].into_iter().feed_into(vec![].into_collector().unique());
+    ///
+    /// assert_eq!(nums, [3, 1, 2]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn unique<T>(self) -> Unique<Self, T>
+    where
+        Self: Collector<T> + Sized,
+        T: Eq + std::hash::Hash + Clone,
+    {
+        assert_collector::<_, T>(Unique::new(self))
+    }
+
+    /// Creates a collector that skips items whose key equals that of any item before it.
+    ///
+    /// Only the keys are kept, so the items need not be [`Clone`].
+    /// Other than that, it is the same as [`unique()`](CollectorBase::unique).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// struct Tx {
+    ///     id: u32,
+    ///     amount: i64,
+    /// }
+    ///
+    /// let txs = [
+    ///     Tx { id: 1, amount: 100 },
+    ///     Tx { id: 2, amount: -30 },
+    ///     // Delivered twice.
+    ///     Tx { id: 1, amount: 100 },
+    /// ];
+    ///
+    /// let total = txs.into_iter().feed_into(
+    ///     i64::adding()
+    ///         .map(|tx: Tx| tx.amount)
+    ///         .unique_by(|tx: &Tx| tx.id),
+    /// );
+    ///
+    /// assert_eq!(total, 70);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn unique_by<F, T, K>(self, f: F) -> UniqueBy<Self, F, K>
+    where
+        Self: Collector<T> + Sized,
+        F: FnMut(&T) -> K,
+        K: Eq + std::hash::Hash,
+    {
+        assert_collector::<_, T>(UniqueBy::new(self, f))
+    }
+
+    /// Creates a collector that accumulates each item with a probability given by a closure.
+    ///
+    /// The closure is called for every item and returns the current sampling rate,
+    /// where `0.0` skips every item and `1.0` keeps every item.
+    /// Since it is called again for each item, the rate can follow feedback
+    /// from elsewhere, such as how full a channel to a slow sink is,
+    /// so that a pipeline samples less under load instead of blocking the producer.
+    ///
+    /// Items are picked with a small pseudo-random number generator,
+    /// which can be seeded with [`SampleAdaptive::seed()`].
+    /// Like [`filter()`](CollectorBase::filter), a skipped item does not make
+    /// this collector stop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// use komadori::prelude::*;
+    ///
+    /// // Updated by the sink, from 0 (idle) to 100 (saturated).
+    /// let pressure = AtomicU32::new(0);
+    ///
+    /// let mut collector = vec![]
+    ///     .into_collector()
+    ///     .sample_adaptive(|| 1.0 - f64::from(pressure.load(Ordering::Relaxed)) / 100.0);
+    ///
+    /// collector.collect_many(0..100);
+    /// pressure.store(100, Ordering::Relaxed);
+    /// collector.collect_many(100..200);
+    ///
+    /// // Every item is kept when idle, and none when saturated.
+    /// assert_eq!(collector.finish(), Vec::from_iter(0..100));
+    /// ```
+    #[inline]
+    fn sample_adaptive<F>(self, rate: F) -> SampleAdaptive<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> f64,
+    {
+        assert_collector_base(SampleAdaptive::new(self, rate))
+    }
+
+    /// Creates a collector that accumulates items as long as a predicate returns `true`.
+    ///
+    /// `take_while()` collects items until it encounters one for which the predicate returns `false`.
+    /// Conceptually, that item and all subsequent ones will **not** be accumulated.
+    /// However, you should ensure that you do not feed more items after it has signaled
+    /// a stop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = "".to_owned()
+    ///     .into_concat()
+    ///     .take_while(|&s| s != "stop");
+    ///
+    /// assert!(collector.collect("abc").is_continue());
+    /// assert!(collector.collect("def").is_continue());
+    ///
+    /// // Immediately stops after "stop".
+    /// assert!(collector.collect("stop").is_break());
+    ///
+    /// assert_eq!(collector.finish(), "abcdef");
+    /// ```
+    fn take_while<F, T>(self, pred: F) -> TakeWhile<Self, F>
+    where
+        Self: Collector<T> + Sized,
+        F: FnMut(&T) -> bool,
+    {
+        assert_collector::<_, T>(TakeWhile::new(self, pred))
+    }
+
+    /// Creates a collector that accumulates items as long as their total cost fits in a budget.
+    ///
+    /// The cost of each item is computed by `cost`, such as the length of a string.
+    /// Once an item would make the total exceed `budget`, this collector stops.
+    /// That item is **not** accumulated, unless [`TakeBudget::inclusive()`] is called.
+    ///
+    /// It generalizes [`take()`](CollectorBase::take) to size-based limits.
+    /// Since items may cost nothing, this collector does not stop when the budget is
+    /// used up exactly, but only when an item exceeds it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = "".to_owned()
+    ///     .into_concat()
+    ///     .take_budget(8, |s: &&str| s.len());
+    ///
+    /// assert!(collector.collect("abc").is_continue());
+    /// assert!(collector.collect("defgh").is_continue());
+    ///
+    /// // Exceeds the budget of 8 bytes.
+    /// assert!(collector.collect("i").is_break());
+    ///
+    /// assert_eq!(collector.finish(), "abcdefgh");
+    /// ```
+    #[inline]
+    fn take_budget<F, T>(self, budget: usize, cost: F) -> TakeBudget<Self, F>
+    where
+        Self: Collector<T> + Sized,
+        F: FnMut(&T) -> usize,
+    {
+        assert_collector::<_, T>(TakeBudget::new(self, budget, cost))
+    }
+
+    /// Creates a collector that keeps a running total of the cost of collected items.
+    ///
+    /// The cost of each item is computed by `cost`, such as the size of a request,
+    /// and every item is still collected. The running total can be read with
+    /// [`WeighBy::total()`], and the [`Output`](CollectorBase::Output) is a tuple of
+    /// the output of this collector and the total.
+    ///
+    /// To stop once the total reaches a ceiling, call [`WeighBy::ceiling()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let (lines, bytes) = ["GET /", "POST /login", "GET /about"]
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().weigh_by(|s: &&str| s.len()));
+    ///
+    /// assert_eq!(lines.len(), 3);
+    /// assert_eq!(bytes, 26);
+    /// ```
+    #[inline]
+    fn weigh_by<F, T>(self, cost: F) -> WeighBy<Self, F>
+    where
+        Self: Collector<T> + Sized,
+        F: FnMut(&T) -> usize,
+    {
+        assert_collector::<_, T>(WeighBy::new(self, cost))
+    }
+
+    /// Creates a collector that stops accumulating once a closure returns `true`.
+    ///
+    /// The closure is checked in [`break_hint()`](CollectorBase::break_hint),
+    /// and before and after collecting each item.
+    /// Once it returns `true`, this collector returns [`Break(())`](ControlFlow::Break)
+    /// without pulling more items, so none is lost.
+    ///
+    /// It is used to interrupt a long-running [`feed_into()`](crate::iter::IteratorExt::feed_into)
+    /// from elsewhere. To cancel from another thread with an [`AtomicBool`],
+    /// see [`cancel_on()`](CollectorBase::cancel_on).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use komadori::prelude::*;
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    ///
+    /// let nums = (1..=5).feed_into(
+    ///     vec![]
+    ///         .into_collector()
+    ///         .cancel_when(|| Instant::now() >= deadline),
+    /// );
+    ///
+    /// assert_eq!(nums, [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    fn cancel_when<F>(self, cancelled: F) -> CancelWhen<Self, F>
+    where
+        Self: Sized,
+        F: Fn() -> bool,
+    {
+        assert_collector_base(CancelWhen::new(self, cancelled))
+    }
+
+    /// Creates a collector that stops accumulating once a flag is set.
+    ///
+    /// The flag is loaded with [`Ordering::Acquire`](std::sync::atomic::Ordering::Acquire).
+    /// Other than that, it is the same as [`cancel_when()`](CollectorBase::cancel_when).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{sync::atomic::{AtomicBool, Ordering}, thread};
+    /// use komadori::prelude::*;
+    ///
+    /// let cancelled = AtomicBool::new(false);
+    ///
+    /// let count = thread::scope(|s| {
+    ///     let ingestion = s.spawn(|| {
+    ///         // An endless stream, interrupted from the other thread.
+    ///         (0..).feed_into(usize::adding().map(|_| 1).cancel_on(&cancelled))
+    ///     });
+    ///
+    ///     cancelled.store(true, Ordering::Release);
+    ///     ingestion.join().unwrap()
+    /// });
+    ///
+    /// assert!(count < usize::MAX);
+    /// ```
+    #[inline]
+    fn cancel_on(self, flag: &AtomicBool) -> CancelOn<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert_collector_base(CancelOn::new(self, flag))
+    }
+
+    /// Creates a collector that stops accumulating once a deadline has passed.
+    ///
+    /// By default, the clock is read after every item collected, and in
+    /// [`break_hint()`](CollectorBase::break_hint). To lower the overhead,
+    /// [`TakeUntil::check_every()`] reads it only after every `n` items instead.
+    /// The time is read from [`SystemClock`] unless another [`Clock`] is given
+    /// with [`TakeUntil::with_clock()`].
+    ///
+    /// This enables "collect as much as possible within a time budget" patterns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use komadori::{prelude::*, iter::Count};
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(50);
+    ///
+    /// // An endless stream, cut off by the deadline.
+    /// let count = (0..).feed_into(Count::new().take_until(deadline).check_every(1024));
+    ///
+    /// assert!(Instant::now() >= deadline);
+    /// assert_eq!(count % 1024, 0);
+    /// ```
+    ///
+    /// [`SystemClock`]: crate::time::SystemClock
+    /// [`Clock`]: crate::time::Clock
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn take_until(self, deadline: std::time::Instant) -> TakeUntil<Self>
+    where
+        Self: Sized,
+    {
+        assert_collector_base(TakeUntil::new(self, deadline))
+    }
+
+    /// Creates a collector that measures the time spent in this collector.
+    ///
+    /// The [`Output`](CollectorBase::Output) is a tuple of the output of this collector
+    /// and its [`Measurements`]: the number of items collected, the total time spent
+    /// collecting them and finishing, the longest time spent on a single item,
+    /// and the throughput derived from them.
+    /// Instrumenting each stage of a big pipeline shows which one is the bottleneck.
+    ///
+    /// Each item is timed on its own, so the items are fed to this collector one by one,
+    /// even through [`collect_many()`](Collector::collect_many).
+    /// The time is read from [`SystemClock`] unless another [`Clock`] is given
+    /// with [`Instrument::with_clock()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let ((evens, odds), measurements) = (1..=100).feed_into(
+    ///     vec![]
+    ///         .into_collector()
+    ///         .partition(|&mut num| num % 2 == 0, vec![])
+    ///         .instrument(),
+    /// );
+    ///
+    /// assert_eq!(evens.len(), 50);
+    /// assert_eq!(odds.len(), 50);
+    /// assert_eq!(measurements.items(), 100);
+    /// assert!(measurements.max_latency() <= measurements.busy());
+    /// ```
+    ///
+    /// [`Measurements`]: crate::collector::Measurements
+    /// [`SystemClock`]: crate::time::SystemClock
+    /// [`Clock`]: crate::time::Clock
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn instrument(self) -> Instrument<Self>
+    where
+        Self: Sized,
+    {
+        assert_collector_base(Instrument::new(self))
+    }
+
+    // fn step_by()
+
+    /// Creates a collector that distributes items between two collectors based on a predicate.
+    ///
+    /// Items for which the predicate returns `true` are sent to the first collector,
+    /// and those for which it returns `false` go to the second collector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let collector = vec![]
+    ///     .into_collector()
+    ///     .partition(|&mut x| x % 2 == 0, vec![]);
+    /// let (evens, odds) = collector.collect_then_finish(-5..5);
+    ///
+    /// assert_eq!(evens, [-4, -2, 0, 2, 4]);
+    /// assert_eq!(odds, [-5, -3, -1, 1, 3]);
+    /// ```
+    #[inline]
+    fn partition<C, F, T>(self, pred: F, other_if_false: C) -> Partition<Self, C::IntoCollector, F>
+    where
+        Self: Collector<T> + Sized,
+        C: IntoCollector<T>,
+        F: FnMut(&mut T) -> bool,
+    {
+        assert_collector::<_, T>(Partition::new(self, other_if_false.into_collector(), pred))
+    }
+
+    /// Creates a collector that sends items to this collector and another one in turn.
+    ///
+    /// The 1st, 3rd, 5th... items are sent to this collector,
+    /// and the 2nd, 4th, 6th... items go to the other collector.
+    /// Unlike [`partition()`](CollectorBase::partition), items are routed by their position,
+    /// not by their values.
+    ///
+    /// Once one collector stops accumulating, the items in its turns are discarded,
+    /// so that the other collector still receives the items in its own turns only.
+    /// `alternate()` only stops when **both** collectors have stopped.
+    ///
+    /// The [`Output`](CollectorBase::Output) is a tuple containing the outputs of
+    /// both underlying collectors, in order.
+    /// For more than two collectors, nest this adapter,
+    /// e.g., `a.alternate(b.alternate(c))` sends every other item to `a`
+    /// while `b` and `c` alternate the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// // Keys and values, flattened.
+    /// let (keys, values) = ["a", "1", "b", "2", "c", "3"]
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().alternate(vec![]));
+    ///
+    /// assert_eq!(keys, ["a", "b", "c"]);
+    /// assert_eq!(values, ["1", "2", "3"]);
+    /// ```
+    #[inline]
+    fn alternate<C>(self, other: C) -> Alternate<Self, C::IntoCollector>
+    where
+        Self: Sized,
+        C: IntoCollectorBase,
+    {
+        assert_collector_base(Alternate::new(self, other.into_collector()))
+    }
+
+    /// Creates a collector that distributes [`Result`]s between two collectors.
+    ///
+    /// [`Ok`] values are sent to the first collector,
+    /// and [`Err`] values go to the second collector.
+    ///
+    /// `partition_result()` only stops when **both** collectors have stopped.
+    ///
+    /// The [`Output`](CollectorBase::Output) is a tuple containing the outputs of
+    /// both underlying collectors, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let collector = vec![]
+    ///     .into_collector()
+    ///     .partition_result(vec![]);
+    /// let (nums, errs) = collector.collect_then_finish(
+    ///     ["1", "two", "3"].into_iter().map(str::parse::<i32>),
+    /// );
+    ///
+    /// assert_eq!(nums, [1, 3]);
+    /// assert_eq!(errs.len(), 1);
+    /// ```
+    #[inline]
+    fn partition_result<C>(self, err_collector: C) -> PartitionResult<Self, C::IntoCollector>
+    where
+        Self: Sized,
+        C: IntoCollectorBase,
+    {
+        assert_collector_base(PartitionResult::new(self, err_collector.into_collector()))
+    }
+
+    /// Creates a collector that lets both collectors collect the same item.
+    ///
+    /// For each item collected, the first collector collects the item
+    /// mapped by a given closure before the second collector collects it.
+    /// If the second collector stops accumulating, the item will **not**
+    /// be mapped, and instead is fed directly into the first collector.
+    ///
+    /// `tee_with()` only stops when **both** collectors have stopped.
+    ///
+    /// If the item type of this adapter is `T`, the first collector must implement
+    /// [`Collector<T>`](super::Collector) and [`Collector<U>`](super::Collector),
+    /// and the second collector must implement [`Collector<T>`](super::Collector).
+    /// Since many collectors do not collect two or more types of items,
+    /// `U` is effectively also `T` in this case.
+    ///
+    /// The [`Output`](CollectorBase::Output) is a tuple containing the outputs of
+    /// both underlying collectors, in order.
+    ///
+    /// See the [module-level documentation](crate::collector) for
+    /// when this adapter is used and other variants of `tee` adapters.
+    #[inline]
+    #[cfg(feature = "unstable")]
+    fn tee_with<C, F, T, U>(self, f: F, other: C) -> TeeWith<Self, C::IntoCollector, F>
+    where
+        Self: Collector<T> + Collector<U> + Sized,
+        C: IntoCollector<T>,
+        F: FnMut(&mut T) -> U,
+    {
+        assert_collector::<_, T>(TeeWith::new(self, other.into_collector(), f))
+    }
+
+    /// Creates a collector with a custom collection logic.
+    ///
+    /// This adaptor is useful for behaviors that cannot be expressed
+    /// through existing adaptors without cloning or intermediate allocations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = Vec::<i32>::new()
+    ///     .into_collector()
+    ///     .unbatching(|v, arr: &[_]| v.collect_many(arr));
+    ///
+    /// assert!(collector.collect(&[1, 2, 3]).is_continue());
+    /// assert!(collector.collect(&[4, 5]).is_continue());
+    /// assert!(collector.collect(&[6, 7, 8, 9]).is_continue());
+    ///
+    /// assert_eq!(collector.finish(), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    fn unbatching<F, T>(self, f: F) -> Unbatching<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut Self, T) -> ControlFlow<()>,
+    {
+        assert_collector_base(Unbatching::new(self, f))
+    }
+
+    // ///
+    // #[inline]
+    // fn map_ref_ref<F, T, U>(self, f: F) -> Map<Self, F>
+    // where
+    //     Self: for<'a> Collector<&'a T> + Sized,
+    //     F: FnMut(&U) -> &T,
+    //     T: ?Sized,
+    //     U: ?Sized,
+    // {
+    //     assert_collector::<_, &U>(Map::new(self, f))
+    // }
+
+    // ///
+    // #[inline]
+    // fn map_mut_ref<F, T, U>(self, f: F) -> Map<Self, F>
+    // where
+    //     Self: for<'a> Collector<&'a T> + Sized,
+    //     F: FnMut(&mut U) -> &T,
+    //     T: ?Sized,
+    //     U: ?Sized,
+    // {
+    //     assert_collector::<_, &mut U>(Map::new(self, f))
+    // }
+
+    // ///
+    // #[inline]
+    // fn map_mut_mut<F, T, U>(self, f: F) -> Map<Self, F>
+    // where
+    //     Self: for<'a> Collector<&'a mut T> + Sized,
+    //     F: FnMut(&mut U) -> &mut T,
+    //     T: ?Sized,
+    //     U: ?Sized,
+    // {
+    //     assert_collector::<_, &mut U>(Map::new(self, f))
+    // }
+
+    /// A collector that flattens items by one level of nesting before collecting.
+    ///
+    /// Each item will be converted into an iterator, then the underlying collector
+    /// collects every element in that iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = vec![]
+    ///     .into_collector()
+    ///     .flatten();
+    ///
+    /// assert!(collector.collect([1, 2]).is_continue());
+    /// assert!(collector.collect(&[] as &[i32]).is_continue());
+    /// assert!(collector.collect(vec![3, 4, 5]).is_continue());
+    ///
+    /// assert_eq!(collector.finish(), [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+    {
+        assert_collector_base(Flatten::new(self))
+    }
+
+    /// A collector that collects elements in each iterator item provided by a closure.
+    ///
+    /// Each item will be mapped into an iterator by a closure,
+    /// then the underlying collector collects every element in that iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = String::new()
+    ///     .into_collector()
+    ///     .flat_map(str::chars);
+    ///
+    /// assert!(collector.collect("elegance ").is_continue());
+    /// assert!(collector.collect("and ").is_continue());
+    /// assert!(collector.collect("radiance").is_continue());
+    ///
+    /// assert_eq!(collector.finish(), "elegance and radiance");
+    /// ```
+    #[inline]
+    fn flat_map<F, T, I>(self, f: F) -> FlatMap<Self, F>
+    where
+        Self: Collector<I::Item> + Sized,
+        F: FnMut(T) -> I,
+        I: IntoIterator,
+    {
+        assert_collector::<_, T>(FlatMap::new(self, f))
+    }
+
+    /// Creates a "by reference" adapter for this collector.
+    ///
+    /// Used when you do not want, yet, consume the collector
+    /// and reuse it further.
+    ///
+    /// It is possible since `&mut C` implements [`Collector<T>`]
+    /// when `C` implements [`Collector<T>`].
+    ///
+    /// Due to this, function signatures and structs (using generics)
+    /// should only either expect an [`impl Collector<T>`](Collector)
+    /// or [`impl IntoCollector<T>`](super::IntoCollector)
+    /// for more flexibility, allowing callers to opt for
+    /// either ownership or borrowing.
+    ///
+    /// Also, if you do not chain adapters (before and after `by_ref()`),
+    /// consider passing a `&mut collector` instead to express the intent better.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// fn fill_one_and_two(collector: impl IntoCollector<i32>) {
+    ///     collector
+    ///         .into_collector()
+    ///         .collect_many([1, 2]);
+    /// }
+    ///
+    /// let mut collector = vec![].into_collector();
+    /// // `by_ref()` works, but this is more readable.
+    /// fill_one_and_two(&mut collector);
+    /// assert!(collector.collect(3).is_continue());
+    /// assert_eq!(collector.finish(), [1, 2, 3]);
+    ///
+    /// let mut collector = vec![].into_collector();
+    /// fill_one_and_two(collector.by_ref().filter(|&num| num % 2 == 0));
+    /// assert!(collector.collect(3).is_continue());
+    /// assert_eq!(collector.finish(), [2, 3]);
+    /// ```
+    #[inline]
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Creates a collector that "views" each item first before collecting.
+    ///
+    /// It is used when you want to debug/log what happens between transformations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = vec![]
+    ///     .into_collector()
+    ///     .inspect(|&num| println!("After the filter: {num}"))
+    ///     .filter(|&num| num % 2 != 0)
+    ///     .inspect(|&num| println!("Before the filter: {num}"));
+    ///
+    /// assert!(collector.collect(1).is_continue());
+    /// assert!(collector.collect(2).is_continue());
+    /// assert!(collector.collect(3).is_continue());
+    ///
+    /// assert_eq!(collector.finish(), [1, 3]);
+    /// ```
+    #[inline]
+    fn inspect<F, T>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Collector<T> + Sized,
+        F: FnMut(&T),
+    {
+        assert_collector::<_, T>(Inspect::new(self, f))
+    }
+
+    /// Creates a collector that calls a closure the first time this collector
+    /// returns [`Break(())`](ControlFlow::Break).
+    ///
+    /// It is used when you want to debug/log why a long chain stops early.
+    /// The closure is not called if this collector never returns `Break(())`,
+    /// even if [`break_hint()`](CollectorBase::break_hint) has been `Break(())` from the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use komadori::prelude::*;
+    ///
+    /// let stopped_at = Cell::new(None);
+    /// let mut seen = 0;
+    ///
+    /// let output = (1..=10)
+    ///     .inspect(|_| seen += 1)
+    ///     .feed_into(
+    ///         vec![]
+    ///             .into_collector()
+    ///             .take(3)
+    ///             .on_break(|| stopped_at.set(Some("take(3)"))),
+    ///     );
+    ///
+    /// assert_eq!(output, [1, 2, 3]);
+    /// assert_eq!(stopped_at.get(), Some("take(3)"));
+    /// assert_eq!(seen, 3);
+    /// ```
+    #[inline]
+    fn on_break<F>(self, f: F) -> OnBreak<Self, F>
+    where
+        Self: Sized,
+        F: FnOnce(),
+    {
+        assert_collector_base(OnBreak::new(self, f))
+    }
+
+    /// Creates a collector that calls a closure on the output on finishing,
+    /// without changing it.
+    ///
+    /// It is used when you want to debug/log the intermediate outputs in a chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, cmp::Max};
+    ///
+    /// let (max, sum) = [4, 2, 6, 3].into_iter().feed_into(
+    ///     Max::new()
+    ///         .inspect_output(|max| println!("max: {max:?}"))
+    ///         .tee(i32::adding().inspect_output(|sum| println!("sum: {sum}"))),
+    /// );
+    ///
+    /// assert_eq!(max, Some(6));
+    /// assert_eq!(sum, 15);
     /// ```
     #[inline]
-    fn flat_map<F, T, I>(self, f: F) -> FlatMap<Self, F>
+    fn inspect_output<F>(self, f: F) -> InspectOutput<Self, F>
     where
-        Self: Collector<I::Item> + Sized,
-        F: FnMut(T) -> I,
-        I: IntoIterator,
+        Self: Sized,
+        F: FnOnce(&Self::Output),
     {
-        assert_collector::<_, T>(FlatMap::new(self, f))
+        assert_collector_base(InspectOutput::new(self, f))
     }
 
-    /// Creates a "by reference" adapter for this collector.
+    /// Creates a collector that feeds a clone of itself, i.e., its state so far,
+    /// to another collector after every `n` items collected.
     ///
-    /// Used when you do not want, yet, consume the collector
-    /// and reuse it further.
+    /// This makes long-running aggregations recoverable: the sink can persist the states
+    /// (e.g., to a file, with the `serde` feature), and if the process crashes,
+    /// the latest one can be fed the remaining items to resume.
+    /// Each checkpoint clones the whole state, so prefer a large `n`
+    /// for collectors whose states grow, such as `Vec`'s.
     ///
-    /// It is possible since `&mut C` implements [`Collector<T>`]
-    /// when `C` implements [`Collector<T>`].
+    /// The sink stopping does not stop this collector; it merely receives no more checkpoints.
+    /// The [`Output`](CollectorBase::Output) is a tuple of the outputs of both collectors.
     ///
-    /// Due to this, function signatures and structs (using generics)
-    /// should only either expect an [`impl Collector<T>`](Collector)
-    /// or [`impl IntoCollector<T>`](super::IntoCollector)
-    /// for more flexibility, allowing callers to opt for
-    /// either ownership or borrowing.
+    /// # Panics
     ///
-    /// Also, if you do not chain adapters (before and after `by_ref()`),
-    /// consider passing a `&mut collector` instead to express the intent better.
+    /// Panics if `n` is 0.
     ///
     /// # Examples
     ///
     /// ```
     /// use komadori::prelude::*;
     ///
-    /// fn fill_one_and_two(collector: impl IntoCollector<i32>) {
-    ///     collector
-    ///         .into_collector()
-    ///         .collect_many([1, 2]);
-    /// }
+    /// let (sum, checkpoints) = (1..=10).feed_into(
+    ///     i32::adding().checkpoint_every(4, vec![]),
+    /// );
     ///
-    /// let mut collector = vec![].into_collector();
-    /// // `by_ref()` works, but this is more readable.
-    /// fill_one_and_two(&mut collector);
-    /// assert!(collector.collect(3).is_continue());
-    /// assert_eq!(collector.finish(), [1, 2, 3]);
+    /// assert_eq!(sum, 55);
     ///
-    /// let mut collector = vec![].into_collector();
-    /// fill_one_and_two(collector.by_ref().filter(|&num| num % 2 == 0));
-    /// assert!(collector.collect(3).is_continue());
-    /// assert_eq!(collector.finish(), [2, 3]);
+    /// // Resume from the state after the 8th item.
+    /// let checkpoint = checkpoints.last().unwrap().clone();
+    /// assert_eq!((9..=10).feed_into(checkpoint), 55);
     /// ```
     #[inline]
-    fn by_ref(&mut self) -> &mut Self
+    fn checkpoint_every<C>(self, n: usize, sink: C) -> CheckpointEvery<Self, C::IntoCollector>
     where
-        Self: Sized,
+        Self: Clone + Sized,
+        C: IntoCollector<Self>,
     {
-        self
+        assert_collector_base(CheckpointEvery::new(self, n, sink.into_collector()))
     }
 
-    /// Creates a collector that "views" each item first before collecting.
+    /// Creates a collector that tags its output with a label if it has stopped accumulating.
     ///
-    /// It is used when you want to debug/log what happens between transformations.
+    /// The [`Output`](CollectorBase::Output) is [`Ok`] with the output of this collector
+    /// if it has not stopped accumulating, or [`Err`] with a [`Stopped`]
+    /// containing both the output and the label if it has.
+    /// This is useful for effectful sinks, such as channels and writers,
+    /// which stop accumulating when they fail. When several of them are combined
+    /// (e.g., with [`tee()`](CollectorBase::tee)), the label tells which one has stopped.
+    ///
+    /// This collector has stopped accumulating if any collecting method has returned
+    /// [`Break(())`](ControlFlow::Break), or [`break_hint()`](CollectorBase::break_hint)
+    /// returns `Break(())` on finishing.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::sync::mpsc;
     /// use komadori::prelude::*;
     ///
-    /// let mut collector = vec![]
-    ///     .into_collector()
-    ///     .inspect(|&num| println!("After the filter: {num}"))
-    ///     .filter(|&num| num % 2 != 0)
-    ///     .inspect(|&num| println!("Before the filter: {num}"));
+    /// let (audit_tx, audit_rx) = mpsc::channel();
+    /// let (metrics_tx, metrics_rx) = mpsc::channel();
+    /// // The metrics receiver disconnects.
+    /// drop(metrics_rx);
     ///
-    /// assert!(collector.collect(1).is_continue());
-    /// assert!(collector.collect(2).is_continue());
-    /// assert!(collector.collect(3).is_continue());
+    /// let (audit, metrics) = [1, 2, 3].into_iter().feed_into(
+    ///     audit_tx
+    ///         .into_collector()
+    ///         .context("audit")
+    ///         .tee(metrics_tx.into_collector().context("metrics")),
+    /// );
     ///
-    /// assert_eq!(collector.finish(), [1, 3]);
+    /// assert!(audit.is_ok());
+    /// assert_eq!(audit_rx.try_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    ///
+    /// let stopped = metrics.unwrap_err();
+    /// assert_eq!(stopped.label(), &"metrics");
+    /// assert_eq!(stopped.to_string(), "`metrics` stopped accumulating");
     /// ```
+    ///
+    /// [`Stopped`]: crate::collector::Stopped
     #[inline]
-    fn inspect<F, T>(self, f: F) -> Inspect<Self, F>
+    fn context<L>(self, label: L) -> Context<Self, L>
     where
-        Self: Collector<T> + Sized,
-        F: FnMut(&T),
+        Self: Sized,
     {
-        assert_collector::<_, T>(Inspect::new(self, f))
+        assert_collector_base(Context::new(self, label))
     }
 
     /// Creates a collector that alternates the behavior of [`break_hint()`](Self::break_hint).
@@ -1087,36 +2072,6 @@ pub trait CollectorBase {
     /// to calculate the hint before collecting only.
     ///
     /// [`Break(())`]: ControlFlow::Break
-    //    ///
-    //    /// # Examples
-    //    ///
-    //    /// ```
-    //    /// use std::ops::ControlFlow;
-    //    /// use komadori::prelude::*;
-    //    ///
-    //    /// fn vec_zip(nums: impl IntoIterator<Item = i32>) -> impl Collector<i32, Output = Vec<i32>> {
-    //    ///     let mut nums = nums.into_iter();
-    //    ///     let sh = nums.size_hint();
-    //    ///
-    //    ///     vec![]
-    //    ///         .into_collector()
-    //    ///         .unbatching(move |collector, item| {
-    //    ///             if let Some(num) = nums.next() {
-    //    ///                 collector.collect(item)
-    //    ///             } else {
-    //    ///                 ControlFlow::Break(())
-    //    ///             }
-    //    ///         })
-    //    ///         .alt_break_hint(move |_| {
-    //    ///             if let (0, Some(0)) = sh {
-    //    ///                 ControlFlow::Break(())
-    //    ///             } else {
-    //    ///                 ControlFlow::Continue(())
-    //    ///             }
-    //    ///         })
-    //    /// }
-    //    /// ```
-    //    ///
     #[cfg(feature = "unstable")]
     #[inline]
     fn alt_break_hint<F>(self, f: F) -> AltBreakHint<Self, F>
@@ -1232,7 +2187,6 @@ pub trait CollectorBase {
     ///
     /// [`finish()`]: CollectorBase::finish
     /// [`collect_then_finish()`]: Collector::collect_then_finish
-    #[cfg(feature = "unstable")]
     fn nest<C>(self, inner: C) -> Nest<Self, C::IntoCollector>
     where
         Self: Collector<C::Output> + Sized,
@@ -1281,7 +2235,6 @@ pub trait CollectorBase {
     ///
     /// [`finish()`]: CollectorBase::finish
     /// [`collect_then_finish()`]: Collector::collect_then_finish
-    #[cfg(feature = "unstable")]
     fn nest_exact<C>(self, inner: C) -> NestExact<Self, C::IntoCollector>
     where
         Self: Collector<C::Output> + Sized,
@@ -1289,6 +2242,392 @@ pub trait CollectorBase {
     {
         assert_collector_base(NestExact::new(self, inner.into_collector()))
     }
+
+    /// Creates a collector that collects items in chunks of `n` items,
+    /// each into a fresh clone of an inner collector,
+    /// then collects the output of each chunk.
+    ///
+    /// This is a shorthand of [`nest()`](CollectorBase::nest) with the inner collector
+    /// adapted with [`take(n)`](CollectorBase::take).
+    /// Similar to [`[_]::chunks()`](slice::chunks), the last chunk is collected
+    /// even if it has fewer than `n` items.
+    /// A chunk may also be shorter if the inner collector stops accumulating early.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let batches = (1..=8)
+    ///     .feed_into(vec![].into_collector().chunks(3, vec![]));
+    ///
+    /// assert_eq!(batches, [vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]);
+    /// ```
+    ///
+    /// The inner collector can be any collector, e.g., summing each batch.
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let sums = [1, 2, 3, 4, 5]
+    ///     .into_iter()
+    ///     .feed_into(vec![].into_collector().chunks(2, i32::adding()));
+    ///
+    /// assert_eq!(sums, [3, 7, 5]);
+    /// ```
+    #[track_caller]
+    fn chunks<C>(self, n: usize, inner: C) -> Nest<Self, Take<C::IntoCollector>>
+    where
+        Self: Collector<C::Output> + Sized,
+        C: IntoCollectorBase<IntoCollector: Clone>,
+    {
+        assert!(n != 0, "chunk size must be non-zero");
+        self.nest(inner.into_collector().take(n))
+    }
+
+    /// Creates a collector that groups items into arrays of `N` items,
+    /// then collects the arrays.
+    ///
+    /// Unlike [`chunks()`](CollectorBase::chunks), no collector is created per chunk,
+    /// so no heap allocation happens per chunk. This matters for small chunk sizes,
+    /// where allocating a `Vec` per chunk may dominate the runtime.
+    ///
+    /// The remaining items that do not fill a whole array are discarded on finishing,
+    /// similar to [`[_]::chunks_exact()`](slice::chunks_exact).
+    /// To keep them without allocating, use [`chunks()`](CollectorBase::chunks)
+    /// with a fixed-capacity or inline-storage collection (e.g., an `ArrayVec` or a `SmallVec`)
+    /// as the inner collector instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let pairs = (1..=7).feed_into(vec![].into_collector().array_chunks::<2, _>());
+    ///
+    /// assert_eq!(pairs, [[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = vec![].into_collector().take(2).array_chunks::<3, _>();
+    ///
+    /// assert!(collector.collect_many(1..=5).is_continue());
+    /// assert!(collector.collect(6).is_break());
+    ///
+    /// assert_eq!(collector.finish(), [[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    #[track_caller]
+    fn array_chunks<const N: usize, T>(self) -> ArrayChunks<Self, T, N>
+    where
+        Self: Collector<[T; N]> + Sized,
+    {
+        assert_collector::<_, T>(ArrayChunks::new(self))
+    }
+
+    /// Creates a collector that collects each window of the last `n` items as a slice.
+    ///
+    /// Once `n` items have been collected, the window of the last `n` items is collected
+    /// after each item, so `k` items produce `k - n + 1` windows,
+    /// similar to [`[_]::windows()`](slice::windows).
+    ///
+    /// The windows are borrowed from a buffer of at most `2 * n - 1` items,
+    /// so neither the items nor the windows are cloned.
+    /// As a result, if the item type of this adapter is `T`, the underlying collector
+    /// must implement [`for<'a> Collector<&'a [T]>`](super::Collector)
+    /// (a collector that can collect a slice with any lifetime).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, clb_mut};
+    ///
+    /// let moving_averages = [2.0, 4.0, 6.0, 5.0, 1.0].into_iter().feed_into(
+    ///     vec![]
+    ///         .into_collector()
+    ///         .map(clb_mut!(|window: &[f64]| -> f64 {
+    ///             window.iter().sum::<f64>() / 3.0
+    ///         }))
+    ///         .windows(3),
+    /// );
+    ///
+    /// assert_eq!(moving_averages, [4.0, 5.0, 4.0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[track_caller]
+    fn windows<T>(self, n: usize) -> Windows<Self, T>
+    where
+        Self: for<'a> Collector<&'a [T]> + Sized,
+    {
+        assert_collector::<_, T>(Windows::new(self, n))
+    }
+
+    /// Creates a collector that splits items into groups separated by items
+    /// matching a predicate, collecting each group into a fresh clone of an inner collector,
+    /// then collects the output of each group.
+    ///
+    /// Unlike [`nest()`](CollectorBase::nest), which only starts a new group
+    /// when the inner collector stops accumulating, the groups here are delimited
+    /// by the items themselves, such as newlines in a stream of bytes.
+    /// The delimiters are not collected.
+    /// If the inner collector stops accumulating early, the rest of the group is ignored.
+    ///
+    /// Similar to [`[_]::split()`](slice::split), the last group is collected
+    /// on [`finish()`](CollectorBase::finish) even if it is empty
+    /// (e.g., when the last item is a delimiter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let lines = b"GET /\nHost: a\n\nbody"
+    ///     .iter()
+    ///     .feed_into(
+    ///         vec![]
+    ///             .into_collector()
+    ///             .split_when(|&&byte| byte == b'\n', vec![].into_collector().copying()),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     lines,
+    ///     [&b"GET /"[..], b"Host: a", b"", b"body"],
+    /// );
+    /// ```
+    ///
+    /// The outer collector can stop the whole stream.
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut records = [1, 2, 0, 3, 0, 4, 5].into_iter();
+    /// let sums = records
+    ///     .by_ref()
+    ///     .feed_into(vec![].into_collector().take(2).split_when(|&num| num == 0, i32::adding()));
+    ///
+    /// assert_eq!(sums, [3, 3]);
+    /// assert_eq!(records.next(), Some(4));
+    /// ```
+    #[inline]
+    fn split_when<C, F, T>(self, pred: F, inner: C) -> SplitWhen<Self, C::IntoCollector, F>
+    where
+        Self: Collector<C::Output> + Sized,
+        C: IntoCollector<T, IntoCollector: Clone>,
+        F: FnMut(&T) -> bool,
+    {
+        assert_collector::<_, T>(SplitWhen::new(self, inner.into_collector(), pred))
+    }
+
+    /// Creates a collector that groups consecutive items with equal keys,
+    /// collecting each group into a fresh clone of an inner collector,
+    /// then collects the key and the output of each group.
+    ///
+    /// A new group starts whenever the key of an item differs from that of the previous item,
+    /// similar to [`Itertools::chunk_by()`] and [`[_]::chunk_by()`](slice::chunk_by).
+    /// The outer collector collects `(key, output)` pairs, where the key is that of
+    /// the first item of the group. To drop the keys, [`map()`](CollectorBase::map)
+    /// the outer collector.
+    /// If the inner collector stops accumulating early, the rest of the group is ignored.
+    ///
+    /// Unlike [`nest()`](CollectorBase::nest), which only starts a new group
+    /// when the inner collector stops accumulating, the groups here are delimited by keys.
+    /// The last group is collected on [`finish()`](CollectorBase::finish).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let logs = [(1, "boot"), (1, "ready"), (2, "login"), (1, "logout")];
+    ///
+    /// let sessions = logs.into_iter().feed_into(
+    ///     vec![]
+    ///         .into_collector()
+    ///         .chunk_by(|&(user, _)| user, vec![].into_collector().map(|(_, event)| event)),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     sessions,
+    ///     [(1, vec!["boot", "ready"]), (2, vec!["login"]), (1, vec!["logout"])],
+    /// );
+    /// ```
+    ///
+    /// Without the keys:
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let run_lengths = "aaabccdd".chars().feed_into(
+    ///     vec![]
+    ///         .into_collector()
+    ///         .map(|(_, len)| len)
+    ///         .chunk_by(|&c| c, usize::adding().map(|_| 1)),
+    /// );
+    ///
+    /// assert_eq!(run_lengths, [3, 1, 2, 2]);
+    /// ```
+    ///
+    /// [`Itertools::chunk_by()`]: https://docs.rs/itertools/latest/itertools/trait.Itertools.html#method.chunk_by
+    #[inline]
+    fn chunk_by<C, F, T, K>(self, f: F, inner: C) -> ChunkBy<Self, C::IntoCollector, F, K>
+    where
+        Self: Collector<(K, C::Output)> + Sized,
+        C: IntoCollector<T, IntoCollector: Clone>,
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        assert_collector::<_, T>(ChunkBy::new(self, inner.into_collector(), f))
+    }
+
+    /// Moves this collector onto a background thread and returns a collector
+    /// that sends items to it.
+    ///
+    /// Items are sent through a channel holding up to `bound` items.
+    /// Once the channel is full, collecting blocks until the background collector
+    /// catches up.
+    /// Its [`Output`](CollectorBase::Output) is a [`JoinHandle`](std::thread::JoinHandle),
+    /// which yields the output of the background collector once joined.
+    /// If the background collector panics, [`join()`](std::thread::JoinHandle::join)
+    /// returns an error.
+    ///
+    /// This packages the "feed from this thread, aggregate on another" pattern.
+    ///
+    /// The returned collector stops accumulating once it notices that
+    /// the background collector has, which may take a few more items.
+    /// The items still in the channel by then are dropped.
+    /// Hence, up to `bound` items for which [`collect()`](Collector::collect)
+    /// returned [`Continue`](ControlFlow::Continue) may never reach the background collector,
+    /// plus the item for which it returned [`Break`](ControlFlow::Break).
+    /// With a `bound` of 0, every item is handed over directly,
+    /// so only the latter may be lost, at the cost of waiting for the background thread
+    /// on every item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut collector = vec![].into_collector().detach(16);
+    ///
+    /// assert!(collector.collect(1).is_continue());
+    /// assert!(collector.collect_many([2, 3]).is_continue());
+    ///
+    /// assert_eq!(collector.finish().join().unwrap(), [1, 2, 3]);
+    /// ```
+    ///
+    /// The background collector may stop early.
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let handle = (1..)
+    ///     .feed_into(vec![].into_collector().take(3).detach(0));
+    ///
+    /// assert_eq!(handle.join().unwrap(), [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn detach<T>(self, bound: usize) -> Detach<T, Self::Output>
+    where
+        Self: Collector<T> + Send + Sized + 'static,
+        Self::Output: Send + 'static,
+        T: Send + 'static,
+    {
+        assert_collector::<_, T>(Detach::new(self, bound))
+    }
+
+    /// Creates a collector that calls a closure with this collector
+    /// if it is dropped without being finished.
+    ///
+    /// This happens when the pipeline is abandoned before [`finish()`](CollectorBase::finish),
+    /// e.g., due to an early return with `?` or a panic. The closure can then salvage
+    /// whatever has been accumulated, such as flushing or closing effectful sinks.
+    /// If the collector is finished, the closure is not called.
+    /// Use [`FinalizeOnDrop::into_inner()`] to get the collector back without calling it.
+    ///
+    /// If the closure panics while the thread is already panicking, the process aborts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use komadori::prelude::*;
+    ///
+    /// let salvaged = RefCell::new(vec![]);
+    ///
+    /// let parse_all = |lines: &[&str]| -> Result<Vec<i32>, std::num::ParseIntError> {
+    ///     let mut collector = vec![]
+    ///         .into_collector()
+    ///         .finalize_on_drop(|collector| *salvaged.borrow_mut() = collector.finish());
+    ///
+    ///     for line in lines {
+    ///         // On error, `collector` is dropped before being finished.
+    ///         let _ = collector.collect(line.parse::<i32>()?);
+    ///     }
+    ///
+    ///     Ok(collector.finish())
+    /// };
+    ///
+    /// assert_eq!(parse_all(&["1", "2"]), Ok(vec![1, 2]));
+    /// assert!(salvaged.borrow().is_empty());
+    ///
+    /// assert!(parse_all(&["3", "4", "five", "6"]).is_err());
+    /// assert_eq!(*salvaged.borrow(), [3, 4]);
+    /// ```
+    #[inline]
+    fn finalize_on_drop<F>(self, f: F) -> FinalizeOnDrop<Self, F>
+    where
+        Self: Sized,
+        F: FnOnce(Self),
+    {
+        assert_collector_base(FinalizeOnDrop::new(self, f))
+    }
+
+    /// Erases the type of this collector into a [`BoxCollector`].
+    ///
+    /// This is useful for storing collectors of different types together,
+    /// e.g., in a registry keyed by name, since `dyn Collector<T>` cannot be finished.
+    /// See [`BoxCollector`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use komadori::{prelude::*, cmp::{Max, Min}, collector::BoxCollector};
+    ///
+    /// let mut registry: HashMap<&str, BoxCollector<'_, i32, Option<i32>>> = HashMap::new();
+    /// registry.insert("max", Max::new().boxed());
+    /// registry.insert("min", Min::new().boxed());
+    /// registry.insert("sum", i32::adding().map_output(Some).boxed());
+    ///
+    /// let collector = registry.remove("sum").unwrap();
+    /// assert_eq!([4, 2, 6, 3].into_iter().feed_into(collector), Some(15));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn boxed<'a, T>(self) -> BoxCollector<'a, T, Self::Output>
+    where
+        Self: Collector<T> + Sized + 'a,
+    {
+        BoxCollector::new(self)
+    }
 }
 
 impl<C> CollectorBase for &mut C
@@ -1302,6 +2641,10 @@ where
     fn break_hint(&self) -> ControlFlow<()> {
         C::break_hint(self)
     }
+
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        C::reserve(self, additional_min, additional_max);
+    }
 }
 
 macro_rules! dyn_impl {
@@ -1314,7 +2657,12 @@ macro_rules! dyn_impl {
 
             #[inline]
             fn break_hint(&self) -> ControlFlow<()> {
-                <dyn CollectorBase>::break_hint(self)
+                (**self).break_hint()
+            }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+                (**self).reserve(additional_min, additional_max);
             }
         }
 
@@ -1326,7 +2674,12 @@ macro_rules! dyn_impl {
 
             #[inline]
             fn break_hint(&self) -> ControlFlow<()> {
-                <dyn super::Collector<T>>::break_hint(self)
+                (**self).break_hint()
+            }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+                (**self).reserve(additional_min, additional_max);
             }
         }
     };
@@ -0,0 +1,176 @@
+use std::{any::Any, ops::ControlFlow};
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+use super::{Collector, CollectorBase};
+
+/// An object-safe companion of [`Collector`] that can be finished through a [`Box`].
+///
+/// `dyn Collector<T>` cannot be finished, since [`finish()`](CollectorBase::finish)
+/// takes `self` by value and the output type is not known.
+/// This trait erases the output type into [`Box<dyn Any>`] instead,
+/// so that collectors with different outputs can be chosen or stored together,
+/// and their outputs recovered with [`downcast()`](Box::downcast) after finishing.
+///
+/// It is implemented for every [`Collector`] whose output is `'static`.
+/// `Box<dyn DynCollector<T>>` is also a [`Collector`] itself,
+/// whose [`Output`](CollectorBase::Output) is [`Box<dyn Any>`].
+///
+/// If all collectors share the same output type, prefer [`BoxCollector`](super::BoxCollector),
+/// which needs no downcasting.
+///
+/// # Examples
+///
+/// ```
+/// use std::any::Any;
+/// use komadori::{prelude::*, cmp::Max, collector::DynCollector, iter::Count};
+///
+/// let collectors: Vec<Box<dyn DynCollector<i32>>> =
+///     vec![Box::new(Count::new()), Box::new(Max::new()), Box::new(vec![].into_collector())];
+///
+/// let outputs: Vec<Box<dyn Any>> = collectors
+///     .into_iter()
+///     .map(|collector| [4, 2, 6, 3].into_iter().feed_into(collector))
+///     .collect();
+///
+/// assert_eq!(outputs[0].downcast_ref::<usize>(), Some(&4));
+/// assert_eq!(outputs[1].downcast_ref::<Option<i32>>(), Some(&Some(6)));
+/// assert_eq!(outputs[2].downcast_ref::<Vec<i32>>(), Some(&vec![4, 2, 6, 3]));
+/// ```
+///
+/// The methods can also be called directly.
+///
+/// ```
+/// use komadori::{collector::DynCollector, ops::Adding};
+///
+/// let mut collector: Box<dyn DynCollector<i32>> = Box::new(i32::adding());
+///
+/// assert!(collector.collect_dyn(1).is_continue());
+/// assert!(collector.collect_dyn(2).is_continue());
+///
+/// assert_eq!(*collector.finish_boxed().downcast::<i32>().unwrap(), 3);
+/// ```
+pub trait DynCollector<T> {
+    /// Collects an item. See [`Collector::collect()`].
+    fn collect_dyn(&mut self, item: T) -> ControlFlow<()>;
+
+    /// Returns a hint of whether the collector has stopped accumulating.
+    /// See [`CollectorBase::break_hint()`].
+    fn break_hint_dyn(&self) -> ControlFlow<()>;
+
+    /// Hints how many more items the collector is about to receive.
+    /// See [`CollectorBase::reserve()`].
+    fn reserve_dyn(&mut self, additional_min: usize, additional_max: Option<usize>);
+
+    /// Finishes the collector and returns its output, with its type erased.
+    /// See [`CollectorBase::finish()`].
+    fn finish_boxed(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<C, T> DynCollector<T> for C
+where
+    C: Collector<T, Output: 'static>,
+{
+    #[inline]
+    fn collect_dyn(&mut self, item: T) -> ControlFlow<()> {
+        self.collect(item)
+    }
+
+    #[inline]
+    fn break_hint_dyn(&self) -> ControlFlow<()> {
+        self.break_hint()
+    }
+
+    #[inline]
+    fn reserve_dyn(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.reserve(additional_min, additional_max);
+    }
+
+    #[inline]
+    fn finish_boxed(self: Box<Self>) -> Box<dyn Any> {
+        Box::new((*self).finish())
+    }
+}
+
+macro_rules! box_dyn_impl {
+    ($($traits:ident)*) => {
+        impl<'a, T> CollectorBase for Box<dyn DynCollector<T> $(+ $traits)* + 'a> {
+            type Output = Box<dyn Any>;
+
+            #[inline]
+            fn finish(self) -> Self::Output {
+                self.finish_boxed()
+            }
+
+            #[inline]
+            fn break_hint(&self) -> ControlFlow<()> {
+                (**self).break_hint_dyn()
+            }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+                (**self).reserve_dyn(additional_min, additional_max);
+            }
+        }
+
+        impl<'a, T> Collector<T> for Box<dyn DynCollector<T> $(+ $traits)* + 'a> {
+            #[inline]
+            fn collect(&mut self, item: T) -> ControlFlow<()> {
+                (**self).collect_dyn(item)
+            }
+        }
+    };
+}
+
+box_dyn_impl!();
+box_dyn_impl!(Send);
+box_dyn_impl!(Sync);
+box_dyn_impl!(Send Sync);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..5),
+            n in ..5_usize,
+        ) {
+            all_collect_methods_impl(nums, n)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, n: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || -> Box<dyn DynCollector<i32>> {
+                Box::new(vec![].into_collector().take(n))
+            },
+            should_break_pred: |iter| iter.count() >= n,
+            pred: |mut iter, output, remaining| {
+                if output.downcast_ref::<Vec<i32>>()
+                    != Some(&iter.by_ref().take(n).collect::<Vec<_>>())
+                {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
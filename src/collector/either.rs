@@ -0,0 +1,151 @@
+use std::ops::ControlFlow;
+
+use itertools::Either;
+
+use super::{Collector, CollectorBase};
+
+/// A collector that is one of two collectors.
+///
+/// It delegates every operation to whichever collector it holds.
+/// Its [`Output`](CollectorBase::Output) is `Either<L::Output, R::Output>`.
+///
+/// This is useful for choosing a collector at runtime without boxing.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, iter::Last};
+/// use itertools::Either;
+///
+/// fn collect_nums(all: bool) -> Either<Vec<i32>, Option<i32>> {
+///     let collector = if all {
+///         Either::Left(vec![].into_collector())
+///     } else {
+///         Either::Right(Last::new())
+///     };
+///
+///     [1, 2, 3].into_iter().feed_into(collector)
+/// }
+///
+/// assert_eq!(collect_nums(true), Either::Left(vec![1, 2, 3]));
+/// assert_eq!(collect_nums(false), Either::Right(Some(3)));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "itertools")))]
+impl<L, R> CollectorBase for Either<L, R>
+where
+    L: CollectorBase,
+    R: CollectorBase,
+{
+    type Output = Either<L::Output, R::Output>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.map_either(L::finish, R::finish)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        match self {
+            Either::Left(collector) => collector.break_hint(),
+            Either::Right(collector) => collector.break_hint(),
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        match self {
+            Either::Left(collector) => collector.reserve(additional_min, additional_max),
+            Either::Right(collector) => collector.reserve(additional_min, additional_max),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "itertools")))]
+impl<L, R, T> Collector<T> for Either<L, R>
+where
+    L: Collector<T>,
+    R: Collector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match self {
+            Either::Left(collector) => collector.collect(item),
+            Either::Right(collector) => collector.collect(item),
+        }
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        match self {
+            Either::Left(collector) => collector.collect_many(items),
+            Either::Right(collector) => collector.collect_many(items),
+        }
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        match self {
+            Either::Left(collector) => Either::Left(collector.collect_then_finish(items)),
+            Either::Right(collector) => Either::Right(collector.collect_then_finish(items)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use itertools::Either;
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::{iter::Last, prelude::*};
+
+    // Precondition:
+    // - `Vec::IntoCollector`
+    // - `CollectorBase::take()`
+    // - `crate::iter::Last`
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            take_count in ..=5_usize,
+            left in any::<bool>(),
+        ) {
+            all_collect_methods_impl(nums, take_count, left)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize, left: bool) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                if left {
+                    Either::Left(vec![].into_collector().take(take_count))
+                } else {
+                    Either::Right(Last::new())
+                }
+            },
+            should_break_pred: |iter| left && iter.count() >= take_count,
+            pred: |iter, output, remaining| {
+                let (expected, consumed) = if left {
+                    (
+                        Either::Left(iter.clone().take(take_count).collect()),
+                        take_count,
+                    )
+                } else {
+                    (Either::Right(iter.clone().last()), nums.len())
+                };
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,153 @@
+use std::ops::ControlFlow;
+
+use super::{Collector, CollectorBase};
+
+/// A collector that collects items into any type implementing [`Extend`].
+/// Its [`Output`](CollectorBase::Output) is the extended value.
+///
+/// This makes types that implement [`Extend`], but not the traits of this crate
+/// (e.g., collections from other crates), usable as collectors.
+/// It is the counterpart of [`AsExtend`](super::AsExtend).
+///
+/// This collector never stops accumulating.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use komadori::{prelude::*, collector::ExtendCollector};
+///
+/// let path = ["usr", "local", "bin"]
+///     .into_iter()
+///     .feed_into(ExtendCollector::new(PathBuf::from("/")));
+///
+/// assert_eq!(path, PathBuf::from("/usr/local/bin"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ExtendCollector<E>(E);
+
+impl<E> ExtendCollector<E> {
+    /// Wraps a value implementing [`Extend`].
+    #[inline]
+    pub const fn new(extend: E) -> Self {
+        Self(extend)
+    }
+
+    /// Returns the underlying value.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+
+    /// Returns a reference to the underlying value.
+    #[inline]
+    pub const fn get_ref(&self) -> &E {
+        &self.0
+    }
+}
+
+impl<E> CollectorBase for ExtendCollector<E> {
+    type Output = E;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<E, T> Collector<T> for ExtendCollector<E>
+where
+    E: Extend<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.0.extend(Some(item));
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        self.0.extend(items);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.0.extend(items);
+        self.0
+    }
+}
+
+/// Extends any type with a method to turn it into an [`ExtendCollector`].
+///
+/// The method only makes sense for types implementing [`Extend`],
+/// but since [`Extend`] is generic over the item type, this trait is implemented
+/// for every type. It is not in the [prelude](crate::prelude) for this reason.
+///
+/// This trait is sealed and for providing methods only.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BinaryHeap;
+/// use komadori::{prelude::*, collector::FromExtend};
+///
+/// let heap = [4, 2, 6, 3].into_iter().feed_into(BinaryHeap::new().extending());
+///
+/// assert_eq!(heap.into_sorted_vec(), [2, 3, 4, 6]);
+/// ```
+#[allow(private_bounds)]
+pub trait FromExtend: Sized + FromExtendSealed {
+    /// Creates a collector that collects items into this value.
+    ///
+    /// See [`ExtendCollector`] for more.
+    #[inline]
+    fn extending(self) -> ExtendCollector<Self> {
+        ExtendCollector::new(self)
+    }
+}
+
+impl<E> FromExtend for E {}
+
+trait FromExtendSealed {}
+
+impl<E> FromExtendSealed for E {}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            starting_nums in propvec(any::<i32>(), ..3),
+            nums in propvec(any::<i32>(), ..5),
+        ) {
+            all_collect_methods_impl(starting_nums, nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(starting_nums: Vec<i32>, nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || ExtendCollector::new(starting_nums.clone()),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if output != [starting_nums.clone(), iter.collect()].concat() {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,136 @@
+use std::ops::ControlFlow;
+
+use super::{Collector, CollectorBase};
+
+/// An optional collector.
+///
+/// If it is `Some`, it delegates to the underlying collector.
+/// If it is `None`, it behaves like [`Dropping`](crate::mem::Dropping):
+/// it accepts and drops every item without ever stopping.
+/// Its [`Output`](CollectorBase::Output) is `Option<C::Output>`.
+///
+/// This is useful for conditionally-constructed collectors.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, iter::Count};
+///
+/// fn collect_nums(verbose: bool) -> (usize, Option<Vec<i32>>) {
+///     let log = verbose.then(|| vec![].into_collector());
+///     [1, 2, 3].into_iter().feed_into(Count::new().tee(log))
+/// }
+///
+/// assert_eq!(collect_nums(true), (3, Some(vec![1, 2, 3])));
+/// assert_eq!(collect_nums(false), (3, None));
+/// ```
+///
+/// Note that methods of [`CollectorBase`] taking `self` by value take precedence
+/// over inherent methods of [`Option`] taking `&mut self`.
+/// For example, `opt.take()` resolves to [`CollectorBase::take()`] when the
+/// `Option` holds a collector, so call `Option::take(&mut opt)` instead.
+impl<C> CollectorBase for Option<C>
+where
+    C: CollectorBase,
+{
+    type Output = Option<C::Output>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.map(C::finish)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        match self {
+            Some(collector) => collector.break_hint(),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        if let Some(collector) = self {
+            collector.reserve(additional_min, additional_max);
+        }
+    }
+}
+
+impl<C, T> Collector<T> for Option<C>
+where
+    C: Collector<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match self {
+            Some(collector) => collector.collect(item),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        match self {
+            Some(collector) => collector.collect_many(items),
+            None => {
+                items.into_iter().for_each(drop);
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        match self {
+            Some(collector) => Some(collector.collect_then_finish(items)),
+            None => {
+                items.into_iter().for_each(drop);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    // Precondition:
+    // - `Vec::IntoCollector`
+    // - `CollectorBase::take()`
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            take_count in proptest::option::of(..=5_usize),
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: Option<usize>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || take_count.map(|n| vec![].into_collector().take(n)),
+            should_break_pred: |iter| take_count.is_some_and(|n| iter.count() >= n),
+            pred: |iter, output, remaining| {
+                let expected = take_count.map(|n| iter.clone().take(n).collect::<Vec<_>>());
+                let consumed = take_count.map_or(nums.len(), |n| n.min(nums.len()));
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,402 @@
+//! A builder for composing adapters from the first to the last.
+//!
+//! Adapters on [`CollectorBase`] wrap a collector that already exists,
+//! so a chain of them is read from the last step back to the first.
+//! Starting with [`pipeline()`] instead, the adapters are listed in the order
+//! items go through them, and the collector at the end is given with [`sink()`](Pipeline::sink).
+//!
+//! Since the item type is known at every step, closures rarely need
+//! their parameter types annotated.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::prelude::*;
+//!
+//! let collector = pipeline::<i32>()
+//!     .filter(|&num| num % 2 == 0)
+//!     .map(|num| num * 10)
+//!     .sink(vec![]);
+//!
+//! // The same as the line below.
+//! // let collector = vec![].into_collector().map(|num: i32| num * 10).filter(|&num| num % 2 == 0);
+//!
+//! assert_eq!((1..=6).feed_into(collector), [20, 40, 60]);
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::collector::{self, CollectorBase, IntoCollector};
+
+/// Creates a [`Pipeline`] with no adapters yet, collecting items of type `T`.
+///
+/// See the [module-level documentation](self) for more.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::prelude::*;
+///
+/// let (evens, firsts) = [3, 1, 4, 1, 5, 9, 2, 6]
+///     .into_iter()
+///     .feed_into(
+///         pipeline()
+///             .tee(pipeline().filter(|&num: &i32| num % 2 == 0).sink(vec![]))
+///             .take(3)
+///             .sink(vec![]),
+///     );
+///
+/// assert_eq!(evens, [4, 2, 6]);
+/// assert_eq!(firsts, [3, 1, 4]);
+/// ```
+#[inline]
+pub const fn pipeline<T>() -> Pipeline<T, Identity> {
+    Pipeline {
+        stages: Identity,
+        _marker: PhantomData,
+    }
+}
+
+/// A builder listing adapters in the order items go through them.
+///
+/// `T` is the type of items reaching the next adapter,
+/// and `S` is the chain of adapters added so far.
+///
+/// This `struct` is created by [`pipeline()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct Pipeline<T, S> {
+    stages: S,
+    _marker: PhantomData<fn(T)>,
+}
+
+impl<T, S> Pipeline<T, S> {
+    #[inline]
+    fn then<U, S2>(self, f: impl FnOnce(S) -> S2) -> Pipeline<U, S2> {
+        Pipeline {
+            stages: f(self.stages),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Calls a closure on each item before passing it on.
+    ///
+    /// See [`CollectorBase::map()`] for more.
+    #[inline]
+    pub fn map<F, U>(self, f: F) -> Pipeline<U, Map<S, F>>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.then(|stages| Map { stages, f })
+    }
+
+    /// Passes on only the items satisfying a predicate.
+    ///
+    /// See [`CollectorBase::filter()`] for more.
+    #[inline]
+    pub fn filter<F>(self, pred: F) -> Pipeline<T, Filter<S, F>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.then(|stages| Filter { stages, pred })
+    }
+
+    /// Calls a closure on a reference of each item before passing it on.
+    ///
+    /// See [`CollectorBase::inspect()`] for more.
+    #[inline]
+    pub fn inspect<F>(self, f: F) -> Pipeline<T, Inspect<S, F>>
+    where
+        F: FnMut(&T),
+    {
+        self.then(|stages| Inspect { stages, f })
+    }
+
+    /// Stops accumulating after the first `n` items.
+    ///
+    /// See [`CollectorBase::take()`] for more.
+    #[inline]
+    pub fn take(self, n: usize) -> Pipeline<T, Take<S>> {
+        self.then(|stages| Take { stages, n })
+    }
+
+    /// Accumulates items as long as a predicate returns `true`.
+    ///
+    /// See [`CollectorBase::take_while()`] for more.
+    #[inline]
+    pub fn take_while<F>(self, pred: F) -> Pipeline<T, TakeWhile<S, F>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.then(|stages| TakeWhile { stages, pred })
+    }
+
+    /// Lets `other` collect a [`Copy`] of each item as well.
+    ///
+    /// The output of `other` comes first in the final output,
+    /// followed by the output of the rest of the pipeline.
+    ///
+    /// See [`CollectorBase::tee()`] for more.
+    #[inline]
+    pub fn tee<C>(self, other: C) -> Pipeline<T, Tee<S, C::IntoCollector>>
+    where
+        T: Copy,
+        C: IntoCollector<T>,
+    {
+        self.then(|stages| Tee {
+            stages,
+            other: other.into_collector(),
+        })
+    }
+
+    /// Ends the pipeline with a collector, producing the composed collector.
+    #[inline]
+    pub fn sink<C>(self, collector: C) -> S::Collector
+    where
+        C: IntoCollector<T>,
+        S: Stage<C::IntoCollector>,
+    {
+        self.stages.wrap(collector.into_collector())
+    }
+}
+
+impl<T, S> Debug for Pipeline<T, S>
+where
+    S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("stages", &self.stages)
+            .finish()
+    }
+}
+
+/// A chain of adapters in a [`Pipeline`], which can wrap the collector at its end.
+pub trait Stage<C> {
+    /// The composed collector.
+    type Collector: CollectorBase;
+
+    /// Wraps `collector` with every adapter in this chain.
+    fn wrap(self, collector: C) -> Self::Collector;
+}
+
+/// The empty chain of adapters.
+///
+/// This `struct` is created by [`pipeline()`]. See its documentation for more.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+
+impl<C> Stage<C> for Identity
+where
+    C: CollectorBase,
+{
+    type Collector = C;
+
+    #[inline]
+    fn wrap(self, collector: C) -> Self::Collector {
+        collector
+    }
+}
+
+/// A [`CollectorBase::map()`] in a [`Pipeline`].
+///
+/// This `struct` is created by [`Pipeline::map()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct Map<S, F> {
+    stages: S,
+    f: F,
+}
+
+impl<S, F, C> Stage<C> for Map<S, F>
+where
+    S: Stage<collector::Map<C, F>>,
+{
+    type Collector = S::Collector;
+
+    #[inline]
+    fn wrap(self, collector: C) -> Self::Collector {
+        self.stages.wrap(collector::Map::new(collector, self.f))
+    }
+}
+
+/// A [`CollectorBase::filter()`] in a [`Pipeline`].
+///
+/// This `struct` is created by [`Pipeline::filter()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct Filter<S, F> {
+    stages: S,
+    pred: F,
+}
+
+impl<S, F, C> Stage<C> for Filter<S, F>
+where
+    S: Stage<collector::Filter<C, F>>,
+{
+    type Collector = S::Collector;
+
+    #[inline]
+    fn wrap(self, collector: C) -> Self::Collector {
+        self.stages
+            .wrap(collector::Filter::new(collector, self.pred))
+    }
+}
+
+/// A [`CollectorBase::inspect()`] in a [`Pipeline`].
+///
+/// This `struct` is created by [`Pipeline::inspect()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct Inspect<S, F> {
+    stages: S,
+    f: F,
+}
+
+impl<S, F, C> Stage<C> for Inspect<S, F>
+where
+    S: Stage<collector::Inspect<C, F>>,
+{
+    type Collector = S::Collector;
+
+    #[inline]
+    fn wrap(self, collector: C) -> Self::Collector {
+        self.stages.wrap(collector::Inspect::new(collector, self.f))
+    }
+}
+
+/// A [`CollectorBase::take()`] in a [`Pipeline`].
+///
+/// This `struct` is created by [`Pipeline::take()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Take<S> {
+    stages: S,
+    n: usize,
+}
+
+impl<S, C> Stage<C> for Take<S>
+where
+    S: Stage<collector::Take<C>>,
+{
+    type Collector = S::Collector;
+
+    #[inline]
+    fn wrap(self, collector: C) -> Self::Collector {
+        self.stages.wrap(collector::Take::new(collector, self.n))
+    }
+}
+
+/// A [`CollectorBase::take_while()`] in a [`Pipeline`].
+///
+/// This `struct` is created by [`Pipeline::take_while()`]. See its documentation for more.
+#[derive(Clone)]
+pub struct TakeWhile<S, F> {
+    stages: S,
+    pred: F,
+}
+
+impl<S, F, C> Stage<C> for TakeWhile<S, F>
+where
+    S: Stage<collector::TakeWhile<C, F>>,
+{
+    type Collector = S::Collector;
+
+    #[inline]
+    fn wrap(self, collector: C) -> Self::Collector {
+        self.stages
+            .wrap(collector::TakeWhile::new(collector, self.pred))
+    }
+}
+
+/// A [`CollectorBase::tee()`] in a [`Pipeline`].
+///
+/// This `struct` is created by [`Pipeline::tee()`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Tee<S, C> {
+    stages: S,
+    other: C,
+}
+
+impl<S, C1, C2> Stage<C2> for Tee<S, C1>
+where
+    S: Stage<collector::Tee<C1, C2>>,
+    C1: CollectorBase,
+    C2: CollectorBase,
+{
+    type Collector = S::Collector;
+
+    #[inline]
+    fn wrap(self, collector: C2) -> Self::Collector {
+        self.stages.wrap(collector::Tee::new(self.other, collector))
+    }
+}
+
+macro_rules! closure_stage_debug {
+    ($($name:ident),*) => {
+        $(
+            impl<S, F> Debug for $name<S, F>
+            where
+                S: Debug,
+            {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_struct(stringify!($name))
+                        .field("stages", &self.stages)
+                        .finish_non_exhaustive()
+                }
+            }
+        )*
+    };
+}
+
+closure_stage_debug!(Map, Filter, Inspect, TakeWhile);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::filter()`]
+        /// - [`crate::collector::CollectorBase::map()`]
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=6),
+            take_count in ..=6_usize,
+        ) {
+            all_collect_methods_impl(nums, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, take_count: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                pipeline()
+                    .filter(|&num: &i32| num % 2 == 0)
+                    .map(|num| num / 2)
+                    .take(take_count)
+                    .sink(vec![])
+            },
+            should_break_pred: |iter| iter.filter(|&num| num % 2 == 0).count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let expected = iter
+                    .by_ref()
+                    .filter(|&num| num % 2 == 0)
+                    .take(take_count)
+                    .map(|num| num / 2);
+
+                if expected.ne(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
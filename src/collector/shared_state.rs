@@ -0,0 +1,97 @@
+use std::cell::RefCell;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{rc::Rc, sync::Arc};
+#[cfg(feature = "std")]
+use std::{
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
+};
+
+/// A handle to state shared between collectors, such as an `Rc<RefCell<S>>`.
+///
+/// It is taken by [`map_with()`](super::CollectorBase::map_with) and
+/// [`filter_with()`](super::CollectorBase::filter_with), which let their closures read
+/// the state while collecting an item. The state is borrowed (or locked) only for
+/// that call, so branches of a [`tee()`](super::CollectorBase::tee) or a tuple can read
+/// the same state, and it can still be modified between items.
+///
+/// It is implemented for [`RefCell`], `RwLock` and `Mutex`,
+/// and for references, `Rc`s and `Arc`s to them.
+pub trait SharedState {
+    /// The type of the shared state.
+    type State: ?Sized;
+
+    /// Calls a closure with a shared reference to the state.
+    ///
+    /// # Panics
+    ///
+    /// For a [`RefCell`], panics if the state is currently mutably borrowed.
+    fn with<R>(&self, f: impl FnOnce(&Self::State) -> R) -> R;
+}
+
+impl<S: ?Sized> SharedState for RefCell<S> {
+    type State = S;
+
+    #[inline]
+    #[track_caller]
+    fn with<R>(&self, f: impl FnOnce(&Self::State) -> R) -> R {
+        f(&self.borrow())
+    }
+}
+
+/// A poisoned lock is still read, since the state is only read.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<S: ?Sized> SharedState for RwLock<S> {
+    type State = S;
+
+    #[inline]
+    fn with<R>(&self, f: impl FnOnce(&Self::State) -> R) -> R {
+        f(&self.read().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// A poisoned lock is still read, since the state is only read.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<S: ?Sized> SharedState for Mutex<S> {
+    type State = S;
+
+    #[inline]
+    fn with<R>(&self, f: impl FnOnce(&Self::State) -> R) -> R {
+        f(&self.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+impl<H: SharedState + ?Sized> SharedState for &H {
+    type State = H::State;
+
+    #[inline]
+    #[track_caller]
+    fn with<R>(&self, f: impl FnOnce(&Self::State) -> R) -> R {
+        H::with(self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<H: SharedState + ?Sized> SharedState for Rc<H> {
+    type State = H::State;
+
+    #[inline]
+    #[track_caller]
+    fn with<R>(&self, f: impl FnOnce(&Self::State) -> R) -> R {
+        H::with(self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<H: SharedState + ?Sized> SharedState for Arc<H> {
+    type State = H::State;
+
+    #[inline]
+    #[track_caller]
+    fn with<R>(&self, f: impl FnOnce(&Self::State) -> R) -> R {
+        H::with(self, f)
+    }
+}
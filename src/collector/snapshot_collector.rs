@@ -0,0 +1,109 @@
+use super::CollectorBase;
+
+/// A collector whose output can be produced at any time without consuming it.
+///
+/// This is for collectors whose [`finish()`](CollectorBase::finish) merely returns
+/// (a copy of) what they have accumulated, such as [`Count`], `i32::adding()`, [`Max`]
+/// and `Vec`'s collector.
+/// [`peek_output()`](SnapshotCollector::peek_output) must return what
+/// [`finish()`](CollectorBase::finish) would return if called at that point.
+///
+/// Long-running collection can report partial results periodically, then keep going.
+/// Adaptors that do not change the output, such as [`map()`](CollectorBase::map),
+/// [`filter()`](CollectorBase::filter) and [`tee()`](CollectorBase::tee),
+/// implement this trait if their underlying collectors do.
+///
+/// To periodically capture the state of a collector instead of its output,
+/// see [`checkpoint_every()`](CollectorBase::checkpoint_every).
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, collector::SnapshotCollector, iter::Count};
+///
+/// let mut collector = Count::new().tee(u64::adding());
+/// let mut reports = vec![];
+///
+/// for (i, bytes) in [512_u64, 1024, 256, 2048].into_iter().enumerate() {
+///     let _ = collector.collect(bytes);
+///
+///     if i % 2 == 1 {
+///         reports.push(collector.peek_output());
+///     }
+/// }
+///
+/// assert_eq!(reports, [(2, 1536), (4, 3840)]);
+/// assert_eq!(collector.finish(), (4, 3840));
+/// ```
+///
+/// [`Count`]: crate::iter::Count
+/// [`Max`]: crate::cmp::Max
+pub trait SnapshotCollector: CollectorBase {
+    /// Returns what [`finish()`](CollectorBase::finish) would return now,
+    /// without consuming the collector.
+    fn peek_output(&self) -> Self::Output
+    where
+        Self: Sized;
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::fmt::Debug;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::{cmp::Max, collector::Collector, iter::Count, prelude::*};
+
+    use super::*;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::CollectorBase::tee()`]
+        /// - [`crate::collector::CollectorBase::take()`]
+        /// - [`crate::collector::CollectorBase::skip()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn peek_output(
+            nums in propvec(any::<i32>(), ..=8),
+            peeks in propvec(any::<bool>(), 8),
+            n in ..=8_usize,
+        ) {
+            peek_output_impl(Count::new(), &nums, &peeks)?;
+            peek_output_impl(Max::new(), &nums, &peeks)?;
+            peek_output_impl(vec![].into_collector(), &nums, &peeks)?;
+            peek_output_impl(Count::new().tee(Max::new()), &nums, &peeks)?;
+            peek_output_impl(vec![].into_collector().take(n), &nums, &peeks)?;
+            peek_output_impl(vec![].into_collector().skip(n), &nums, &peeks)?;
+        }
+    }
+
+    // Peeks before the items marked in `peeks` and after all of them.
+    fn peek_output_impl<C>(collector: C, nums: &[i32], peeks: &[bool]) -> TestCaseResult
+    where
+        C: SnapshotCollector + Collector<i32> + Clone,
+        C::Output: PartialEq + Debug,
+    {
+        let mut peeked = collector.clone();
+        let mut unpeeked = collector;
+
+        for (&num, &peek) in nums.iter().zip(peeks) {
+            if peek {
+                prop_assert_eq!(peeked.peek_output(), peeked.clone().finish());
+            }
+
+            let cf = peeked.collect(num);
+            prop_assert_eq!(cf, unpeeked.collect(num));
+            if cf.is_break() {
+                break;
+            }
+        }
+
+        prop_assert_eq!(peeked.peek_output(), peeked.clone().finish());
+        // Peeking does not affect what is collected.
+        prop_assert_eq!(peeked.finish(), unpeeked.finish());
+
+        Ok(())
+    }
+}
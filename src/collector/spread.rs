@@ -0,0 +1,305 @@
+use std::ops::ControlFlow;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use super::{Collector, CollectorBase, Fuse};
+
+/// A collector that spreads items over a runtime-sized list of collectors.
+/// Its [`Output`](CollectorBase::Output) is a [`Vec`] of outputs
+/// of the underlying collectors, in order.
+///
+/// Items are either broadcast to every collector ([`Spread::broadcast()`])
+/// or distributed in turn ([`Spread::round_robin()`]).
+/// In both cases, collectors that have stopped accumulating are skipped,
+/// and this collector only stops when **all** of them have stopped.
+/// Hence, if no collectors are given, it stops accumulating right away.
+///
+/// This is useful when the number of collectors is only known at runtime,
+/// which the [`tee()`](CollectorBase::tee) family cannot express.
+///
+/// The item type must implement [`Clone`] regardless of the mode.
+#[derive(Debug, Clone)]
+pub struct Spread<C> {
+    collectors: Vec<Fuse<C>>,
+    // Number of collectors that have not stopped.
+    active: usize,
+    mode: Mode,
+}
+
+#[derive(Debug, Clone)]
+enum Mode {
+    Broadcast,
+    RoundRobin { next: usize },
+}
+
+impl<C> Spread<C>
+where
+    C: CollectorBase,
+{
+    fn new(collectors: Vec<C>, mode: Mode) -> Self {
+        let collectors: Vec<_> = collectors.into_iter().map(Fuse::new).collect();
+        let active = collectors
+            .iter()
+            .filter(|collector| collector.break_hint().is_continue())
+            .count();
+
+        Self {
+            collectors,
+            active,
+            mode,
+        }
+    }
+
+    /// Creates a collector that feeds every item to each collector.
+    ///
+    /// Each collector but the last active one receives a clone of the item,
+    /// and the last active one takes the ownership of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, collector::Spread};
+    ///
+    /// let limits = [1, 3, 2];
+    /// let mut collector = Spread::broadcast(
+    ///     limits.map(|n| vec![].into_collector().take(n)).to_vec(),
+    /// );
+    ///
+    /// assert!(collector.collect(1).is_continue());
+    /// assert!(collector.collect(2).is_continue());
+    /// assert!(collector.collect(3).is_break());
+    ///
+    /// assert_eq!(collector.finish(), [vec![1], vec![1, 2, 3], vec![1, 2]]);
+    /// ```
+    #[inline]
+    pub fn broadcast(collectors: Vec<C>) -> Self {
+        Self::new(collectors, Mode::Broadcast)
+    }
+
+    /// Creates a collector that feeds each item to the next active collector in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, collector::Spread};
+    ///
+    /// let mut collector = Spread::round_robin(vec![
+    ///     vec![].into_collector().take(1),
+    ///     vec![].into_collector().take(3),
+    ///     vec![].into_collector().take(3),
+    /// ]);
+    ///
+    /// assert!(collector.collect_many(1..=6).is_continue());
+    ///
+    /// assert_eq!(collector.finish(), [vec![1], vec![2, 4, 6], vec![3, 5]]);
+    /// ```
+    #[inline]
+    pub fn round_robin(collectors: Vec<C>) -> Self {
+        Self::new(collectors, Mode::RoundRobin { next: 0 })
+    }
+
+    /// Returns the number of collectors that have not stopped accumulating.
+    #[inline]
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    #[inline]
+    fn collect_at<T>(&mut self, idx: usize, item: T)
+    where
+        C: Collector<T>,
+    {
+        if self.collectors[idx].collect(item).is_break() {
+            self.active -= 1;
+        }
+    }
+}
+
+impl<C> CollectorBase for Spread<C>
+where
+    C: CollectorBase,
+{
+    type Output = Vec<C::Output>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collectors.into_iter().map(Fuse::finish).collect()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.active == 0 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        match self.mode {
+            Mode::Broadcast => {
+                for collector in &mut self.collectors {
+                    collector.reserve(additional_min, additional_max);
+                }
+            }
+            Mode::RoundRobin { .. } => {
+                // Any collector may end up receiving every item if the others stop.
+                for collector in &mut self.collectors {
+                    collector.reserve(0, additional_max);
+                }
+            }
+        }
+    }
+}
+
+impl<C, T> Collector<T> for Spread<C>
+where
+    C: Collector<T>,
+    T: Clone,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match self.mode {
+            Mode::Broadcast => {
+                let Some(last) = self
+                    .collectors
+                    .iter()
+                    .rposition(|collector| collector.break_hint().is_continue())
+                else {
+                    return ControlFlow::Break(());
+                };
+
+                for idx in 0..last {
+                    if self.collectors[idx].break_hint().is_continue() {
+                        self.collect_at(idx, item.clone());
+                    }
+                }
+
+                self.collect_at(last, item);
+            }
+            Mode::RoundRobin { next } => {
+                let len = self.collectors.len();
+                let Some(idx) = (next..len)
+                    .chain(0..next)
+                    .find(|&idx| self.collectors[idx].break_hint().is_continue())
+                else {
+                    return ControlFlow::Break(());
+                };
+
+                self.collect_at(idx, item);
+                self.mode = Mode::RoundRobin {
+                    next: (idx + 1) % len,
+                };
+            }
+        }
+
+        self.break_hint()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use super::Spread;
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods_broadcast(
+            nums in propvec(any::<i32>(), ..=5),
+            counts in propvec(..=5_usize, ..=3),
+        ) {
+            all_collect_methods_broadcast_impl(nums, counts)?;
+        }
+
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods_round_robin(
+            nums in propvec(any::<i32>(), ..=8),
+            counts in propvec(..=3_usize, ..=3),
+        ) {
+            all_collect_methods_round_robin_impl(nums, counts)?;
+        }
+    }
+
+    fn all_collect_methods_broadcast_impl(nums: Vec<i32>, counts: Vec<usize>) -> TestCaseResult {
+        let max_count = counts.iter().copied().max().unwrap_or(0);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                Spread::broadcast(
+                    counts
+                        .iter()
+                        .map(|&count| vec![].into_collector().take(count))
+                        .collect(),
+                )
+            },
+            should_break_pred: |iter| iter.count() >= max_count,
+            pred: |iter, output, remaining| {
+                let expected: Vec<Vec<_>> = counts
+                    .iter()
+                    .map(|&count| iter.clone().take(count).collect())
+                    .collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(max_count).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn all_collect_methods_round_robin_impl(nums: Vec<i32>, counts: Vec<usize>) -> TestCaseResult {
+        let total: usize = counts.iter().sum();
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                Spread::round_robin(
+                    counts
+                        .iter()
+                        .map(|&count| vec![].into_collector().take(count))
+                        .collect(),
+                )
+            },
+            should_break_pred: |iter| iter.count() >= total,
+            pred: |mut iter, output, remaining| {
+                let mut expected = vec![vec![]; counts.len()];
+                let mut next = 0;
+
+                for num in iter.by_ref().take(total) {
+                    // Find the next collector with room left.
+                    while expected[next].len() >= counts[next] {
+                        next = (next + 1) % counts.len();
+                    }
+
+                    expected[next].push(num);
+                    next = (next + 1) % counts.len();
+                }
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,208 @@
+//! [`Collector`]s that log items with [`defmt`].
+//!
+//! They give embedded pipelines an observable sink, e.g., alongside
+//! the collectors of `heapless` collections via [`tee()`].
+//!
+//! As with any use of [`defmt`], a global logger must be linked into the final binary,
+//! and the `DEFMT_LOG` environment variable filters out levels at compile time.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! [`Collector`]: crate::collector::Collector
+//! [`tee()`]: crate::collector::CollectorBase::tee
+
+use std::ops::ControlFlow;
+
+use defmt::Format;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// The level that [`Log`] logs items at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Format)]
+pub enum Level {
+    /// [`defmt::trace!`].
+    Trace,
+    /// [`defmt::debug!`].
+    Debug,
+    /// [`defmt::info!`].
+    Info,
+    /// [`defmt::warn!`].
+    Warn,
+    /// [`defmt::error!`].
+    Error,
+}
+
+/// A collector that logs each collected item with [`defmt`].
+/// Its [`Output`](CollectorBase::Output) is [`LogSummary`].
+///
+/// Items must implement [`defmt::Format`].
+/// To avoid flooding a slow transport, only one item out of every
+/// [`every()`](Log::every) items is logged, starting from the first one.
+/// All items are counted regardless.
+///
+/// This collector never stops accumulating.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, defmt::{Level, Log, LogSummary}, iter::Last};
+///
+/// # #[defmt::global_logger]
+/// # struct Logger;
+/// # unsafe impl defmt::Logger for Logger {
+/// #     fn acquire() {}
+/// #     unsafe fn flush() {}
+/// #     unsafe fn release() {}
+/// #     unsafe fn write(_: &[u8]) {}
+/// # }
+/// # defmt::timestamp!("");
+/// let readings = [20_u16, 21, 21, 23, 22];
+///
+/// let (last, summary) = readings
+///     .into_iter()
+///     .feed_into(Last::new().tee(Log::new(Level::Info).every(2)));
+///
+/// assert_eq!(last, Some(22));
+/// // 20, 21 and 22 are logged.
+/// assert_eq!(summary, LogSummary { collected: 5, logged: 3 });
+/// ```
+#[derive(Debug, Clone)]
+pub struct Log {
+    level: Level,
+    every: usize,
+    summary: LogSummary,
+}
+
+/// The [`Output`](CollectorBase::Output) of [`Log`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Format)]
+pub struct LogSummary {
+    /// The number of items collected.
+    pub collected: usize,
+    /// The number of items logged.
+    pub logged: usize,
+}
+
+impl Log {
+    /// Creates a new instance of this collector logging every item at the given level.
+    #[inline]
+    pub const fn new(level: Level) -> Self {
+        Self {
+            level,
+            every: 1,
+            summary: LogSummary {
+                collected: 0,
+                logged: 0,
+            },
+        }
+    }
+
+    /// Logs only one item out of every `n` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    #[inline]
+    pub const fn every(mut self, n: usize) -> Self {
+        assert!(n != 0, "`n` must not be 0");
+        self.every = n;
+        self
+    }
+
+    /// Returns the counters so far.
+    #[inline]
+    pub const fn summary(&self) -> LogSummary {
+        self.summary
+    }
+}
+
+impl CollectorBase for Log {
+    type Output = LogSummary;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.summary
+    }
+}
+
+impl<T> Collector<T> for Log
+where
+    T: Format,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.summary.collected.is_multiple_of(self.every) {
+            match self.level {
+                Level::Trace => defmt::trace!("{}", item),
+                Level::Debug => defmt::debug!("{}", item),
+                Level::Info => defmt::info!("{}", item),
+                Level::Warn => defmt::warn!("{}", item),
+                Level::Error => defmt::error!("{}", item),
+            }
+            self.summary.logged += 1;
+        }
+
+        self.summary.collected += 1;
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    // Discards everything, so that the tests link.
+    #[defmt::global_logger]
+    struct Logger;
+
+    unsafe impl defmt::Logger for Logger {
+        fn acquire() {}
+
+        unsafe fn flush() {}
+
+        unsafe fn release() {}
+
+        unsafe fn write(_bytes: &[u8]) {}
+    }
+
+    defmt::timestamp!("");
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..9),
+            every in 1..4_usize,
+        ) {
+            all_collect_methods_impl(nums, every)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, every: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || Log::new(Level::Warn).every(every),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let collected = iter.count();
+                let expected = LogSummary {
+                    collected,
+                    logged: collected.div_ceil(every),
+                };
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,191 @@
+//! [`Collector`]s that hash bytes with [`digest`] hash functions.
+//!
+//! Any hash function implementing [`Digest`] (e.g., SHA-256 from `sha2`,
+//! BLAKE2 from `blake2`) can be turned into a collector with [`DigestExt::into_collector()`].
+//! Combined with [`tee()`], bytes can be hashed while being processed in the same pass.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::{prelude::*, digest::DigestExt};
+//! use sha2::{Digest, Sha256};
+//!
+//! let chunks: [&[u8]; 3] = [b"the ", b"noble ", b"and"];
+//!
+//! let (hash, len) = chunks
+//!     .into_iter()
+//!     .feed_into(Sha256::new().into_collector().tee(usize::adding().map(<[u8]>::len)));
+//!
+//! assert_eq!(hash, Sha256::digest(b"the noble and"));
+//! assert_eq!(len, 13);
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+//! [`tee()`]: crate::collector::CollectorBase::tee
+
+use std::ops::ControlFlow;
+
+use digest::{Digest, Output};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that feeds the collected bytes into a hash function.
+/// Its [`Output`](CollectorBase::Output) is the hash.
+///
+/// It accepts `u8`, `&u8`, `&[u8]` and `&[u8; N]`.
+///
+/// This collector never stops accumulating.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, digest::Hasher};
+/// use sha2::{Digest, Sha256};
+///
+/// let hash = "abc".bytes().feed_into(Hasher::<Sha256>::new());
+///
+/// assert_eq!(hash, Sha256::digest(b"abc"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Hasher<D> {
+    digest: D,
+}
+
+impl<D> Hasher<D>
+where
+    D: Digest,
+{
+    /// Creates a new instance of this collector with a fresh hash function.
+    #[inline]
+    pub fn new() -> Self {
+        Self { digest: D::new() }
+    }
+
+    /// Creates a new instance of this collector continuing from the given hash function.
+    #[inline]
+    pub const fn with_digest(digest: D) -> Self {
+        Self { digest }
+    }
+
+    /// Returns a reference to the underlying hash function.
+    #[inline]
+    pub const fn get_ref(&self) -> &D {
+        &self.digest
+    }
+}
+
+impl<D> CollectorBase for Hasher<D>
+where
+    D: Digest,
+{
+    type Output = Output<D>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.digest.finalize()
+    }
+}
+
+impl<D> Collector<u8> for Hasher<D>
+where
+    D: Digest,
+{
+    #[inline]
+    fn collect(&mut self, byte: u8) -> ControlFlow<()> {
+        self.digest.update([byte]);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<D> Collector<&u8> for Hasher<D>
+where
+    D: Digest,
+{
+    #[inline]
+    fn collect(&mut self, &byte: &u8) -> ControlFlow<()> {
+        self.collect(byte)
+    }
+}
+
+impl<D> Collector<&[u8]> for Hasher<D>
+where
+    D: Digest,
+{
+    #[inline]
+    fn collect(&mut self, bytes: &[u8]) -> ControlFlow<()> {
+        self.digest.update(bytes);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<D, const N: usize> Collector<&[u8; N]> for Hasher<D>
+where
+    D: Digest,
+{
+    #[inline]
+    fn collect(&mut self, bytes: &[u8; N]) -> ControlFlow<()> {
+        self.collect(bytes.as_slice())
+    }
+}
+
+/// Extends [`Digest`] with a method to turn a hash function into a collector.
+///
+/// This trait is sealed and for providing methods only.
+#[allow(private_bounds)]
+pub trait DigestExt: Digest + Sized + DigestExtSealed {
+    /// Creates a collector that feeds the collected bytes into this hash function.
+    /// The [`Output`](CollectorBase::Output) is the hash.
+    ///
+    /// See [`Hasher`] for more.
+    #[inline]
+    fn into_collector(self) -> Hasher<Self> {
+        Hasher::with_digest(self)
+    }
+}
+
+impl<D: Digest> DigestExt for D {}
+
+trait DigestExtSealed {}
+
+impl<D: Digest> DigestExtSealed for D {}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+    use sha2::Sha256;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            chunks in propvec(propvec(any::<u8>(), ..8), ..5),
+        ) {
+            all_collect_methods_impl(chunks)?;
+        }
+    }
+
+    fn all_collect_methods_impl(chunks: Vec<Vec<u8>>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chunks.iter().map(Vec::as_slice),
+            collector_factory: Hasher::<Sha256>::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if output != Sha256::digest(chunks.concat()) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
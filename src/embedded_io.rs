@@ -0,0 +1,202 @@
+//! [`Collector`]s for [`embedded_io`] byte sinks.
+//!
+//! They mirror the ones in the `io` module (with the `std` feature)
+//! for targets where [`std::io`] is unavailable.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! [`Collector`]: crate::collector::Collector
+//! [`std::io`]: https://doc.rust-lang.org/std/io/index.html
+
+use std::ops::ControlFlow;
+
+use embedded_io::Write;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that writes collected bytes to an [`embedded_io::Write`]r.
+/// Its [`Output`](CollectorBase::Output) is `Result<W, W::Error>`.
+///
+/// It accepts any item implementing [`AsRef<[u8]>`](AsRef), such as `&[u8]` and `&str`.
+/// Each item is written entirely with [`write_all()`](Write::write_all).
+///
+/// If writing fails, this collector stops accumulating
+/// and the error is returned on finishing.
+/// Otherwise, the writer is flushed and returned.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, embedded_io::Writer};
+///
+/// let mut buf = [0; 16];
+/// let rest = ["the ", "noble ", "and"]
+///     .into_iter()
+///     .feed_into(Writer::new(&mut buf[..]))
+///     .unwrap();
+///
+/// assert_eq!(rest.len(), 3);
+/// assert_eq!(&buf[..13], b"the noble and");
+/// ```
+///
+/// Writing stops once the sink is full.
+///
+/// ```
+/// use komadori::{prelude::*, embedded_io::Writer};
+///
+/// let mut buf = [0; 8];
+/// let mut words = ["the ", "noble ", "and"].into_iter();
+///
+/// assert!(words.by_ref().feed_into(Writer::new(&mut buf[..])).is_err());
+/// assert_eq!(words.next(), Some("and"));
+/// ```
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    error: Option<W::Error>,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a new instance of this collector writing to the given writer.
+    #[inline]
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+}
+
+impl<W> CollectorBase for Writer<W>
+where
+    W: Write,
+{
+    type Output = Result<W, W::Error>;
+
+    fn finish(mut self) -> Self::Output {
+        match self.error {
+            Some(e) => Err(e),
+            None => self.writer.flush().map(|_| self.writer),
+        }
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.error.is_some() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<W, T> Collector<T> for Writer<W>
+where
+    W: Write,
+    T: AsRef<[u8]>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match self.writer.write_all(item.as_ref()) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => {
+                self.error = Some(e);
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use embedded_io::{ErrorKind, ErrorType};
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    const CAP: usize = 8;
+
+    // Fails on writes exceeding the capacity.
+    #[derive(Debug, Default)]
+    struct Bounded(Vec<u8>);
+
+    impl ErrorType for Bounded {
+        type Error = ErrorKind;
+    }
+
+    impl Write for Bounded {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            if self.0.len() + buf.len() > CAP {
+                return Err(ErrorKind::OutOfMemory);
+            }
+
+            self.0.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            chunks in propvec(propvec(any::<u8>(), ..4), ..5),
+        ) {
+            all_collect_methods_impl(chunks)?;
+        }
+    }
+
+    // The number of chunks written until and including the failing one, if any.
+    fn failing_at<'a>(chunks: impl Iterator<Item = &'a Vec<u8>>) -> Option<usize> {
+        let mut len = 0;
+        chunks
+            .map(|chunk| {
+                len += chunk.len();
+                len
+            })
+            .position(|len| len > CAP)
+            .map(|i| i + 1)
+    }
+
+    fn all_collect_methods_impl(chunks: Vec<Vec<u8>>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chunks.iter(),
+            collector_factory: || Writer::new(Bounded::default()),
+            should_break_pred: |iter| failing_at(iter).is_some(),
+            pred: |iter, output, remaining| {
+                let failing_at = failing_at(iter.clone());
+                let expected: Vec<_> = iter.clone().flatten().copied().collect();
+
+                let correct = match (output, failing_at) {
+                    (Ok(Bounded(buf)), None) => buf == expected,
+                    (Err(_), Some(_)) => true,
+                    _ => false,
+                };
+
+                if !correct {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(failing_at.unwrap_or(usize::MAX)).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,159 @@
+//! Formatting of the outputs of collectors.
+//!
+//! Outputs of combined collectors (e.g., with [`tee()`] or tuples) are nested tuples,
+//! which are tedious to destructure just to print them.
+//! Naming the output of each collector with [`named_output()`] makes them [`Fields`],
+//! which can be printed in one go with [`summary()`](Fields::summary),
+//! e.g., `sum=10, max=Some(4), count=4`.
+//!
+//! This module corresponds to [`std::fmt`].
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::{prelude::*, cmp::Max, fmt::Fields, iter::Count};
+//!
+//! let output = [4, 2, 1, 3].into_iter().feed_into(
+//!     i32::adding()
+//!         .named_output("sum")
+//!         .tee(Max::new().named_output("max"))
+//!         .tee(Count::new().named_output("count")),
+//! );
+//!
+//! assert_eq!(output.summary().to_string(), "sum=10, max=Some(4), count=4");
+//! ```
+//!
+//! [`tee()`]: crate::collector::CollectorBase::tee
+//! [`named_output()`]: crate::collector::CollectorBase::named_output
+
+use std::fmt::{Debug, Display, Formatter, Result};
+
+/// A value with a name.
+///
+/// Its [`Display`] implementation prints `name=value`,
+/// where the value is printed with its [`Debug`] implementation.
+///
+/// This `struct` is produced by [`CollectorBase::named_output()`].
+/// See its documentation for more.
+///
+/// [`CollectorBase::named_output()`]: crate::collector::CollectorBase::named_output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Named<T> {
+    name: &'static str,
+    value: T,
+}
+
+impl<T> Named<T> {
+    /// Names a value.
+    #[inline]
+    pub const fn new(name: &'static str, value: T) -> Self {
+        Self { name, value }
+    }
+
+    /// Returns the name.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns a reference to the value.
+    #[inline]
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value, discarding the name.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Debug> Display for Named<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}={:?}", self.name, self.value)
+    }
+}
+
+/// A value made of named fields, such as a [`Named`] or a (nested) tuple of them.
+pub trait Fields {
+    /// Calls a closure on each field with its name and value, in order,
+    /// stopping at the first error.
+    fn try_for_each_field(&self, f: &mut dyn FnMut(&str, &dyn Debug) -> Result) -> Result;
+
+    /// Returns a value that prints all fields as `name=value`, separated by `, `.
+    #[inline]
+    fn summary(&self) -> Summary<'_, Self> {
+        Summary(self)
+    }
+}
+
+impl<T: Debug> Fields for Named<T> {
+    #[inline]
+    fn try_for_each_field(&self, f: &mut dyn FnMut(&str, &dyn Debug) -> Result) -> Result {
+        f(self.name, &self.value)
+    }
+}
+
+impl<T: Fields + ?Sized> Fields for &T {
+    #[inline]
+    fn try_for_each_field(&self, f: &mut dyn FnMut(&str, &dyn Debug) -> Result) -> Result {
+        T::try_for_each_field(self, f)
+    }
+}
+
+impl Fields for () {
+    #[inline]
+    fn try_for_each_field(&self, _f: &mut dyn FnMut(&str, &dyn Debug) -> Result) -> Result {
+        Ok(())
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($idx:tt $ts:ident),*) => {
+        impl<$($ts: Fields),*> Fields for ($($ts,)*) {
+            #[inline]
+            fn try_for_each_field(
+                &self,
+                f: &mut dyn FnMut(&str, &dyn Debug) -> Result,
+            ) -> Result {
+                $(self.$idx.try_for_each_field(f)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+tuple_impl!(0 T0);
+tuple_impl!(0 T0, 1 T1);
+tuple_impl!(0 T0, 1 T1, 2 T2);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11);
+
+/// A value that prints all fields of a [`Fields`] as `name=value`, separated by `, `.
+///
+/// This `struct` is created by [`Fields::summary()`]. See its documentation for more.
+#[derive(Debug, Clone, Copy)]
+pub struct Summary<'a, T: ?Sized>(&'a T);
+
+impl<T: Fields + ?Sized> Display for Summary<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut first = true;
+
+        self.0.try_for_each_field(&mut |name, value| {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+
+            write!(f, "{name}={value:?}")
+        })
+    }
+}
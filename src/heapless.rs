@@ -0,0 +1,27 @@
+//! [`Collector`]s for fixed-capacity collections in the [`heapless`] crate.
+//!
+//! These collectors stop accumulating once the collection is full,
+//! making it possible to collect without any allocator.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::prelude::*;
+//!
+//! let mut nums = 1..;
+//! let vec = nums
+//!     .by_ref()
+//!     .feed_into(heapless::Vec::<i32, 3>::new());
+//!
+//! assert_eq!(vec, [1, 2, 3]);
+//! // Nothing more is consumed once the vector is full.
+//! assert_eq!(nums.next(), Some(4));
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+
+pub mod index_map;
+pub mod string;
+pub mod vec;
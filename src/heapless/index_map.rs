@@ -0,0 +1,215 @@
+//! [`Collector`]s for [`heapless::IndexMap`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::{
+    hash::{BuildHasher, Hash},
+    ops::ControlFlow,
+};
+
+use heapless::IndexMap;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that inserts collected items into a [`heapless::IndexMap`].
+/// Its [`Output`] is [`heapless::IndexMap`].
+///
+/// It stops accumulating once the map is full,
+/// even though existing keys could still be updated.
+///
+/// This struct is created by `heapless::IndexMap::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct IntoCollector<K, V, S, const N: usize>(IndexMap<K, V, S, N>);
+
+/// A collector that inserts collected items into a [`&mut heapless::IndexMap`](heapless::IndexMap).
+/// Its [`Output`] is [`&mut heapless::IndexMap`](heapless::IndexMap).
+///
+/// It stops accumulating once the map is full,
+/// even though existing keys could still be updated.
+///
+/// This struct is created by `heapless::IndexMap::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorMut<'a, K, V, S, const N: usize>(&'a mut IndexMap<K, V, S, N>);
+
+impl<K, V, S, const N: usize> crate::collector::IntoCollectorBase for IndexMap<K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<K, V, S, N>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, K, V, S, const N: usize> crate::collector::IntoCollectorBase
+    for &'a mut IndexMap<K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, K, V, S, N>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+#[inline]
+fn insert<K, V, S, const N: usize>(
+    map: &mut IndexMap<K, V, S, N>,
+    (key, value): (K, V),
+) -> ControlFlow<()>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    if map.insert(key, value).is_err() || map.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+#[inline]
+fn break_hint<K, V, S, const N: usize>(map: &IndexMap<K, V, S, N>) -> ControlFlow<()>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    if map.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<K, V, S, const N: usize> CollectorBase for IntoCollector<K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = IndexMap<K, V, S, N>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(&self.0)
+    }
+}
+
+impl<K, V, S, const N: usize> Collector<(K, V)> for IntoCollector<K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn collect(&mut self, item: (K, V)) -> ControlFlow<()> {
+        insert(&mut self.0, item)
+    }
+}
+
+impl<'a, K, V, S, const N: usize> CollectorBase for CollectorMut<'a, K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = &'a mut IndexMap<K, V, S, N>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(self.0)
+    }
+}
+
+impl<K, V, S, const N: usize> Collector<(K, V)> for CollectorMut<'_, K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn collect(&mut self, item: (K, V)) -> ControlFlow<()> {
+        insert(self.0, item)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use heapless::index_map::FnvIndexMap;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    const CAP: usize = 4;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            pairs in propvec((..6_u8, any::<i32>()), ..=8),
+        ) {
+            all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    // Also returns the number of pairs consumed if it stops.
+    fn iter_output(iter: impl IntoIterator<Item = (u8, i32)>) -> (Vec<(u8, i32)>, Option<usize>) {
+        let mut entries: Vec<(u8, i32)> = vec![];
+
+        for (idx, (key, value)) in iter.into_iter().enumerate() {
+            match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, v)) => *v = value,
+                None => entries.push((key, value)),
+            }
+
+            if entries.len() == CAP {
+                return (entries, Some(idx + 1));
+            }
+        }
+
+        (entries, None)
+    }
+
+    fn all_collect_methods_impl(pairs: Vec<(u8, i32)>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || pairs.iter().copied(),
+            collector_factory: || FnvIndexMap::<u8, i32, CAP>::new().into_collector(),
+            should_break_pred: |iter| iter_output(iter).1.is_some(),
+            pred: |iter, output, remaining| {
+                let (expected, consumed) = iter_output(iter.clone());
+
+                if !output.iter().map(|(&k, &v)| (k, v)).eq(expected) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed.unwrap_or(pairs.len())).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,206 @@
+//! [`Collector`]s for [`heapless::String`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::ops::ControlFlow;
+
+use heapless::{LenType, String};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pushes `char`s into a [`heapless::String`].
+/// Its [`Output`] is [`heapless::String`].
+///
+/// It stops accumulating once the string is full, or a `char` does not fit
+/// in the remaining capacity. Such a `char` is not pushed.
+///
+/// This struct is created by `heapless::String::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct IntoCollector<const N: usize, LenT: LenType = usize>(String<N, LenT>);
+
+/// A collector that pushes `char`s into a [`&mut heapless::String`](heapless::String).
+/// Its [`Output`] is [`&mut heapless::String`](heapless::String).
+///
+/// It stops accumulating once the string is full, or a `char` does not fit
+/// in the remaining capacity. Such a `char` is not pushed.
+///
+/// This struct is created by `heapless::String::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorMut<'a, const N: usize, LenT: LenType = usize>(&'a mut String<N, LenT>);
+
+impl<const N: usize, LenT: LenType> crate::collector::IntoCollectorBase for String<N, LenT> {
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<N, LenT>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, const N: usize, LenT: LenType> crate::collector::IntoCollectorBase
+    for &'a mut String<N, LenT>
+{
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, N, LenT>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+#[inline]
+fn push<const N: usize, LenT: LenType>(s: &mut String<N, LenT>, ch: char) -> ControlFlow<()> {
+    if s.push(ch).is_err() || s.len() == s.capacity() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+#[inline]
+fn break_hint<const N: usize, LenT: LenType>(s: &String<N, LenT>) -> ControlFlow<()> {
+    if s.len() == s.capacity() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<const N: usize, LenT: LenType> CollectorBase for IntoCollector<N, LenT> {
+    type Output = String<N, LenT>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(&self.0)
+    }
+}
+
+impl<const N: usize, LenT: LenType> Collector<char> for IntoCollector<N, LenT> {
+    #[inline]
+    fn collect(&mut self, ch: char) -> ControlFlow<()> {
+        push(&mut self.0, ch)
+    }
+}
+
+impl<const N: usize, LenT: LenType> Collector<&char> for IntoCollector<N, LenT> {
+    #[inline]
+    fn collect(&mut self, &ch: &char) -> ControlFlow<()> {
+        push(&mut self.0, ch)
+    }
+}
+
+impl<const N: usize, LenT: LenType> Collector<&mut char> for IntoCollector<N, LenT> {
+    #[inline]
+    fn collect(&mut self, &mut ch: &mut char) -> ControlFlow<()> {
+        push(&mut self.0, ch)
+    }
+}
+
+impl<'a, const N: usize, LenT: LenType> CollectorBase for CollectorMut<'a, N, LenT> {
+    type Output = &'a mut String<N, LenT>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(self.0)
+    }
+}
+
+impl<const N: usize, LenT: LenType> Collector<char> for CollectorMut<'_, N, LenT> {
+    #[inline]
+    fn collect(&mut self, ch: char) -> ControlFlow<()> {
+        push(self.0, ch)
+    }
+}
+
+impl<const N: usize, LenT: LenType> Collector<&char> for CollectorMut<'_, N, LenT> {
+    #[inline]
+    fn collect(&mut self, &ch: &char) -> ControlFlow<()> {
+        push(self.0, ch)
+    }
+}
+
+impl<const N: usize, LenT: LenType> Collector<&mut char> for CollectorMut<'_, N, LenT> {
+    #[inline]
+    fn collect(&mut self, &mut ch: &mut char) -> ControlFlow<()> {
+        push(self.0, ch)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    const CAP: usize = 6;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            chars in propvec(prop::sample::select(&['a', 'ß', '€', '🦀'][..]), ..=6),
+        ) {
+            all_collect_methods_impl(chars)?;
+        }
+    }
+
+    // Also returns the number of chars consumed if it stops.
+    fn iter_output(iter: impl IntoIterator<Item = char>) -> (std::string::String, Option<usize>) {
+        let mut s = std::string::String::new();
+
+        for (idx, ch) in iter.into_iter().enumerate() {
+            if s.len() + ch.len_utf8() > CAP {
+                return (s, Some(idx + 1));
+            }
+
+            s.push(ch);
+            if s.len() == CAP {
+                return (s, Some(idx + 1));
+            }
+        }
+
+        (s, None)
+    }
+
+    fn all_collect_methods_impl(chars: Vec<char>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chars.iter().copied(),
+            collector_factory: || String::<CAP>::new().into_collector(),
+            should_break_pred: |iter| iter_output(iter).1.is_some(),
+            pred: |iter, output, remaining| {
+                let (expected, consumed) = iter_output(iter.clone());
+
+                if output != *expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed.unwrap_or(chars.len())).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
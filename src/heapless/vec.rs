@@ -0,0 +1,211 @@
+//! [`Collector`]s for [`heapless::Vec`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::ops::ControlFlow;
+
+use heapless::{LenType, Vec};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pushes collected items into a [`heapless::Vec`].
+/// Its [`Output`] is [`heapless::Vec`].
+///
+/// It stops accumulating once the vector is full.
+///
+/// This struct is created by `heapless::Vec::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct IntoCollector<T, const N: usize, LenT: LenType = usize>(Vec<T, N, LenT>);
+
+/// A collector that pushes collected items into a [`&mut heapless::Vec`](heapless::Vec).
+/// Its [`Output`] is [`&mut heapless::Vec`](heapless::Vec).
+///
+/// It stops accumulating once the vector is full.
+///
+/// This struct is created by `heapless::Vec::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorMut<'a, T, const N: usize, LenT: LenType = usize>(&'a mut Vec<T, N, LenT>);
+
+impl<T, const N: usize, LenT: LenType> crate::collector::IntoCollectorBase for Vec<T, N, LenT> {
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<T, N, LenT>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, T, const N: usize, LenT: LenType> crate::collector::IntoCollectorBase
+    for &'a mut Vec<T, N, LenT>
+{
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, T, N, LenT>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+#[inline]
+fn push<T, const N: usize, LenT: LenType>(vec: &mut Vec<T, N, LenT>, item: T) -> ControlFlow<()> {
+    // The item is dropped if it doesn't fit, which only happens
+    // if the caller ignores the previous `Break`.
+    if vec.push(item).is_err() || vec.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+#[inline]
+fn break_hint<T, const N: usize, LenT: LenType>(vec: &Vec<T, N, LenT>) -> ControlFlow<()> {
+    if vec.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T, const N: usize, LenT: LenType> CollectorBase for IntoCollector<T, N, LenT> {
+    type Output = Vec<T, N, LenT>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(&self.0)
+    }
+}
+
+impl<T, const N: usize, LenT: LenType> Collector<T> for IntoCollector<T, N, LenT> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        push(&mut self.0, item)
+    }
+}
+
+impl<'i, T, const N: usize, LenT: LenType> Collector<&'i T> for IntoCollector<T, N, LenT>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &item: &'i T) -> ControlFlow<()> {
+        push(&mut self.0, item)
+    }
+}
+
+impl<'i, T, const N: usize, LenT: LenType> Collector<&'i mut T> for IntoCollector<T, N, LenT>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &mut item: &'i mut T) -> ControlFlow<()> {
+        push(&mut self.0, item)
+    }
+}
+
+impl<'a, T, const N: usize, LenT: LenType> CollectorBase for CollectorMut<'a, T, N, LenT> {
+    type Output = &'a mut Vec<T, N, LenT>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(self.0)
+    }
+}
+
+impl<T, const N: usize, LenT: LenType> Collector<T> for CollectorMut<'_, T, N, LenT> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        push(self.0, item)
+    }
+}
+
+impl<'i, T, const N: usize, LenT: LenType> Collector<&'i T> for CollectorMut<'_, T, N, LenT>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &item: &'i T) -> ControlFlow<()> {
+        push(self.0, item)
+    }
+}
+
+impl<'i, T, const N: usize, LenT: LenType> Collector<&'i mut T> for CollectorMut<'_, T, N, LenT>
+where
+    T: Copy,
+{
+    #[inline]
+    fn collect(&mut self, &mut item: &'i mut T) -> ControlFlow<()> {
+        push(self.0, item)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    const CAP: usize = 4;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            starting_nums in propvec(any::<i32>(), ..=CAP),
+            nums in propvec(any::<i32>(), ..=6),
+        ) {
+            all_collect_methods_impl(starting_nums, nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        starting_nums: std::vec::Vec<i32>,
+        nums: std::vec::Vec<i32>,
+    ) -> TestCaseResult {
+        let starting_vec = || Vec::<i32, CAP>::from_slice(&starting_nums).unwrap();
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || starting_vec().into_collector(),
+            should_break_pred: |iter| starting_nums.len() + iter.count() >= CAP,
+            pred: |mut iter, output, remaining| {
+                let free = CAP - starting_nums.len();
+                let expected: std::vec::Vec<_> = starting_nums
+                    .iter()
+                    .copied()
+                    .chain(iter.by_ref().take(free))
+                    .collect();
+
+                if output[..] != expected[..] {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
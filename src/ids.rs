@@ -0,0 +1,55 @@
+//! [`Collector`]s for 128-bit ids, such as UUIDs and ULIDs.
+//!
+//! These collectors accept `u128` and `&u128`, plus [`Uuid`] and `&Uuid`
+//! with the `uuid` feature enabled. Other ids (e.g., ULIDs) can be collected
+//! after converting them into `u128`.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! [`Collector`]: crate::collector::Collector
+//! [`Uuid`]: https://docs.rs/uuid/latest/uuid/struct.Uuid.html
+
+mod id_set;
+mod probably_seen;
+
+pub use id_set::*;
+pub use probably_seen::*;
+
+/// Implements `Collector` for every id type, in terms of a `collect_id(&mut self, u128)` method.
+macro_rules! id_collector_impl {
+    ($ty:ty) => {
+        impl $crate::collector::Collector<u128> for $ty {
+            #[inline]
+            fn collect(&mut self, id: u128) -> ::core::ops::ControlFlow<()> {
+                self.collect_id(id)
+            }
+        }
+
+        impl $crate::collector::Collector<&u128> for $ty {
+            #[inline]
+            fn collect(&mut self, &id: &u128) -> ::core::ops::ControlFlow<()> {
+                self.collect_id(id)
+            }
+        }
+
+        #[cfg(feature = "uuid")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+        impl $crate::collector::Collector<::uuid::Uuid> for $ty {
+            #[inline]
+            fn collect(&mut self, id: ::uuid::Uuid) -> ::core::ops::ControlFlow<()> {
+                self.collect_id(id.as_u128())
+            }
+        }
+
+        #[cfg(feature = "uuid")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+        impl $crate::collector::Collector<&::uuid::Uuid> for $ty {
+            #[inline]
+            fn collect(&mut self, id: &::uuid::Uuid) -> ::core::ops::ControlFlow<()> {
+                self.collect_id(id.as_u128())
+            }
+        }
+    };
+}
+
+use id_collector_impl;
@@ -0,0 +1,219 @@
+use std::ops::ControlFlow;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+use crate::collector::CollectorBase;
+
+use super::id_collector_impl;
+
+/// A collector that records 128-bit ids in a Bloom filter.
+/// Its [`Output`](CollectorBase::Output) is itself, to be queried later.
+///
+/// It answers whether an id has *probably* been seen, using a fixed amount of memory
+/// decided on construction: about 1.2 bytes per expected id for a 1% false positive rate.
+/// It never reports a collected id as unseen, but may report an uncollected one as seen.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, ids::ProbablySeen};
+///
+/// let mut seen = ProbablySeen::new(1000, 0.01);
+///
+/// // Only forward the events not seen before.
+/// let mut fresh = vec![];
+/// for id in [5_u128, 8, 5, 13, 8] {
+///     if !seen.contains(id) {
+///         fresh.push(id);
+///     }
+///     let _ = seen.collect(id);
+/// }
+///
+/// assert_eq!(fresh, [5, 8, 13]);
+/// assert_eq!(seen.distinct_count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProbablySeen {
+    bits: Vec<u64>,
+    // The number of bits, i.e., `bits.len() * 64`.
+    num_bits: u64,
+    num_hashes: u32,
+    distinct_count: usize,
+}
+
+impl ProbablySeen {
+    /// Creates a new instance of this collector, sized for `expected_items` ids
+    /// at the given false positive rate.
+    ///
+    /// The false positive rate grows beyond `false_positive_rate`
+    /// if more than `expected_items` distinct ids are collected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` is not strictly between 0 and 1.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "the false positive rate must be strictly between 0 and 1",
+        );
+
+        // The optimal number of bits per item is `-ln(p) / ln(2)^2`,
+        // and the optimal number of hash functions is `bits per item * ln(2)`.
+        // `ln(2)` and `log2(1/p)` are computed without `std` floating point functions.
+        const LN_2: f64 = std::f64::consts::LN_2;
+        let log2_inv_p = log2(1.0 / false_positive_rate);
+        let bits_per_item = log2_inv_p / LN_2;
+
+        let num_bits = (expected_items.max(1) as f64 * bits_per_item) as u64;
+        let num_words = num_bits.div_ceil(64).max(1) as usize;
+        let num_hashes = ((bits_per_item * LN_2) as u32).clamp(1, 32);
+
+        Self {
+            bits: vec![0; num_words],
+            num_bits: num_words as u64 * 64,
+            num_hashes,
+            distinct_count: 0,
+        }
+    }
+
+    /// Returns `true` if the id has probably been collected,
+    /// and `false` if it definitely has not.
+    #[inline]
+    pub fn contains(&self, id: u128) -> bool {
+        self.bit_indices(id)
+            .all(|idx| self.bits[(idx / 64) as usize] & (1 << (idx % 64)) != 0)
+    }
+
+    /// Returns the number of collected ids that were definitely not seen before.
+    ///
+    /// It is a lower bound of the number of distinct ids collected,
+    /// since some new ids may be mistaken as seen.
+    #[inline]
+    pub fn distinct_count(&self) -> usize {
+        self.distinct_count
+    }
+
+    // Double hashing: the `i`-th index is `h1 + i * h2`, modulo the number of bits.
+    #[inline]
+    fn bit_indices(&self, id: u128) -> impl Iterator<Item = u64> + use<> {
+        let num_bits = self.num_bits;
+        let h1 = mix(id as u64 ^ mix((id >> 64) as u64));
+        // Odd, while the number of bits is a multiple of 64,
+        // so their greatest common divisor divides `num_bits / 64`.
+        // Thus, the first 64 indices are distinct, more than `num_hashes` can be.
+        let h2 = (mix(h1) % num_bits) | 1;
+        let h1 = h1 % num_bits;
+
+        (0..u64::from(self.num_hashes)).map(move |i| {
+            // In `u128` so that the arithmetic is exact.
+            ((u128::from(h1) + u128::from(i) * u128::from(h2)) % u128::from(num_bits)) as u64
+        })
+    }
+
+    #[inline]
+    fn collect_id(&mut self, id: u128) -> ControlFlow<()> {
+        let mut new = false;
+
+        for idx in self.bit_indices(id) {
+            let (word, mask) = (&mut self.bits[(idx / 64) as usize], 1 << (idx % 64));
+            new |= *word & mask == 0;
+            *word |= mask;
+        }
+
+        self.distinct_count += usize::from(new);
+        ControlFlow::Continue(())
+    }
+}
+
+// The finalizer of SplitMix64.
+#[inline]
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+// `f64::log2` is not available in `core`. Precise enough for sizing.
+fn log2(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as f64 - 1023.0;
+    // The mantissa is in [1, 2). Approximate `log2` of it by a quadratic.
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    exponent + (-0.344_845 * mantissa + 2.024_658) * mantissa - 1.674_873
+}
+
+impl CollectorBase for ProbablySeen {
+    type Output = Self;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self
+    }
+}
+
+id_collector_impl!(ProbablySeen);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::collections::HashSet;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            ids in propvec(..100_u128, ..50),
+        ) {
+            all_collect_methods_impl(ids)?;
+        }
+
+        #[test]
+        fn distinct_bit_indices(
+            id in any::<u128>(),
+            expected_items in 1..10_000_usize,
+            false_positive_rate in 1e-9..0.5_f64,
+        ) {
+            let seen = ProbablySeen::new(expected_items, false_positive_rate);
+            let indices: Vec<_> = seen.bit_indices(id).collect();
+            let distinct: HashSet<_> = indices.iter().collect();
+
+            prop_assert_eq!(distinct.len(), indices.len());
+            prop_assert!(indices.iter().all(|&idx| idx < seen.num_bits));
+        }
+
+        #[test]
+        fn log2_approx(x in 1.0..1e12_f64) {
+            prop_assert!((log2(x) - x.log2()).abs() < 0.01);
+        }
+    }
+
+    fn all_collect_methods_impl(ids: Vec<u128>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || ids.iter().copied(),
+            collector_factory: || ProbablySeen::new(50, 0.01),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let distinct: HashSet<_> = iter.collect();
+
+                // No false negatives, and the count never overestimates.
+                if !distinct.iter().all(|&id| output.contains(id))
+                    || output.distinct_count() > distinct.len()
+                {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,21 @@
+//! [`Collector`]s for persistent collections in the [`im`] crate.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::prelude::*;
+//!
+//! let base = im::vector![1, 2];
+//! // Collecting into a clone leaves `base` untouched, while sharing its structure.
+//! let extended = [3, 4].into_iter().feed_into(base.clone());
+//!
+//! assert_eq!(base, im::vector![1, 2]);
+//! assert_eq!(extended, im::vector![1, 2, 3, 4]);
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+
+pub mod hash_map;
+pub mod vector;
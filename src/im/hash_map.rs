@@ -0,0 +1,204 @@
+//! [`Collector`]s for [`im::HashMap`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+    ops::ControlFlow,
+};
+
+use im::HashMap;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that inserts collected items into an [`im::HashMap`].
+/// Its [`Output`] is [`im::HashMap`].
+///
+/// This struct is created by `im::HashMap::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Clone)]
+pub struct IntoCollector<K, V, S>(HashMap<K, V, S>);
+
+/// A collector that inserts collected items into an [`&mut im::HashMap`](im::HashMap).
+/// Its [`Output`] is [`&mut im::HashMap`](im::HashMap).
+///
+/// This struct is created by `im::HashMap::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+pub struct CollectorMut<'a, K, V, S>(&'a mut HashMap<K, V, S>);
+
+impl<K, V, S> crate::collector::IntoCollectorBase for HashMap<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: BuildHasher,
+{
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<K, V, S>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, K, V, S> crate::collector::IntoCollectorBase for &'a mut HashMap<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: BuildHasher,
+{
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, K, V, S>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+impl<K, V, S> CollectorBase for IntoCollector<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: BuildHasher,
+{
+    type Output = HashMap<K, V, S>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<K, V, S> Collector<(K, V)> for IntoCollector<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn collect(&mut self, (key, value): (K, V)) -> ControlFlow<()> {
+        self.0.insert(key, value);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = (K, V)>) -> ControlFlow<()> {
+        self.0.extend(items);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = (K, V)>) -> Self::Output {
+        self.0.extend(items);
+        self.0
+    }
+}
+
+impl<'a, K, V, S> CollectorBase for CollectorMut<'a, K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: BuildHasher,
+{
+    type Output = &'a mut HashMap<K, V, S>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<K, V, S> Collector<(K, V)> for CollectorMut<'_, K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: BuildHasher,
+{
+    #[inline]
+    fn collect(&mut self, (key, value): (K, V)) -> ControlFlow<()> {
+        self.0.insert(key, value);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = (K, V)>) -> ControlFlow<()> {
+        self.0.extend(items);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = (K, V)>) -> Self::Output {
+        self.0.extend(items);
+        self.0
+    }
+}
+
+// `im::HashMap` only implements `Debug` with more bounds.
+impl<K, V, S> Debug for IntoCollector<K, V, S>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IntoCollector").field(&self.0).finish()
+    }
+}
+
+impl<K, V, S> Debug for CollectorMut<'_, K, V, S>
+where
+    K: Hash + Eq + Debug,
+    V: Debug,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CollectorMut").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            pairs in propvec((..6_u8, any::<i32>()), ..=8),
+        ) {
+            all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    fn all_collect_methods_impl(pairs: Vec<(u8, i32)>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || pairs.iter().copied(),
+            collector_factory: || HashMap::new().into_collector(),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let expected: std::collections::HashMap<_, _> = iter.collect();
+
+                if output != HashMap::from(expected) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
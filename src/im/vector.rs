@@ -0,0 +1,149 @@
+//! [`Collector`]s for [`im::Vector`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::ops::ControlFlow;
+
+use im::Vector;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pushes collected items to the back of an [`im::Vector`].
+/// Its [`Output`] is [`im::Vector`].
+///
+/// This struct is created by `im::Vector::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct IntoCollector<A: Clone>(Vector<A>);
+
+/// A collector that pushes collected items to the back of an [`&mut im::Vector`](im::Vector).
+/// Its [`Output`] is [`&mut im::Vector`](im::Vector).
+///
+/// This struct is created by `im::Vector::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorMut<'a, A: Clone>(&'a mut Vector<A>);
+
+impl<A: Clone> crate::collector::IntoCollectorBase for Vector<A> {
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<A>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, A: Clone> crate::collector::IntoCollectorBase for &'a mut Vector<A> {
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, A>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+impl<A: Clone> CollectorBase for IntoCollector<A> {
+    type Output = Vector<A>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<A: Clone> Collector<A> for IntoCollector<A> {
+    #[inline]
+    fn collect(&mut self, item: A) -> ControlFlow<()> {
+        self.0.push_back(item);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = A>) -> ControlFlow<()> {
+        self.0.extend(items);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = A>) -> Self::Output {
+        self.0.extend(items);
+        self.0
+    }
+}
+
+impl<'a, A: Clone> CollectorBase for CollectorMut<'a, A> {
+    type Output = &'a mut Vector<A>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<A: Clone> Collector<A> for CollectorMut<'_, A> {
+    #[inline]
+    fn collect(&mut self, item: A) -> ControlFlow<()> {
+        self.0.push_back(item);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = A>) -> ControlFlow<()> {
+        self.0.extend(items);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = A>) -> Self::Output {
+        self.0.extend(items);
+        self.0
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            starting_nums in propvec(any::<i32>(), ..5),
+            nums in propvec(any::<i32>(), ..5),
+        ) {
+            all_collect_methods_impl(starting_nums, nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(starting_nums: Vec<i32>, nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || Vector::from(&starting_nums).into_collector(),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let expected: Vector<_> = starting_nums.iter().copied().chain(iter).collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
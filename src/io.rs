@@ -0,0 +1,13 @@
+//! [`Collector`]s that write to I/O sinks.
+//!
+//! This module corresponds to [`std::io`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+mod rotating_file_writer;
+mod write_lines;
+mod writer;
+
+pub use rotating_file_writer::*;
+pub use write_lines::*;
+pub use writer::*;
@@ -0,0 +1,361 @@
+use std::{
+    fmt::Debug,
+    fs::File,
+    io::{self, BufWriter, Write},
+    ops::ControlFlow,
+    path::PathBuf,
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that writes collected bytes to files, switching to a new file
+/// whenever the current one reaches a limit.
+/// Its [`Output`](CollectorBase::Output) is [`io::Result<Vec<PathBuf>>`],
+/// containing the paths of all files written, in order.
+///
+/// It accepts any item implementing [`AsRef<[u8]>`](AsRef), such as `&[u8]`,
+/// `&str`, [`String`] and [`Vec<u8>`].
+/// An item is never split across files.
+///
+/// Files are created (or truncated) lazily, so no file is created if no items are collected.
+/// The path of each file is produced by a closure receiving the index of the file,
+/// starting from 0.
+/// Writes are buffered, and each file is flushed before moving on to the next one.
+///
+/// If creating or writing a file fails, this collector stops accumulating
+/// and the error is returned on finishing.
+///
+/// # Examples
+///
+/// ```
+/// use std::fs;
+/// use komadori::{prelude::*, io::{RotatingFileWriter, Rotation}};
+///
+/// let dir = std::env::temp_dir().join("komadori-rotating-file-writer-doc");
+/// fs::create_dir_all(&dir).unwrap();
+///
+/// let paths = ["a\n", "b\n", "c\n", "d\n", "e\n"]
+///     .into_iter()
+///     .feed_into(RotatingFileWriter::new(
+///         |idx| dir.join(format!("part-{idx}.txt")),
+///         Rotation::Items(2),
+///     ))
+///     .unwrap();
+///
+/// assert_eq!(paths.len(), 3);
+/// assert_eq!(fs::read_to_string(&paths[0]).unwrap(), "a\nb\n");
+/// assert_eq!(fs::read_to_string(&paths[1]).unwrap(), "c\nd\n");
+/// assert_eq!(fs::read_to_string(&paths[2]).unwrap(), "e\n");
+///
+/// fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub struct RotatingFileWriter<F> {
+    path_fn: F,
+    rotation: Rotation,
+    file: Option<BufWriter<File>>,
+    // Bytes or items written to the current file, depending on `rotation`.
+    written: u64,
+    paths: Vec<PathBuf>,
+    error: Option<io::Error>,
+}
+
+/// The limit at which [`RotatingFileWriter`] switches to a new file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// Switches to a new file when writing the next item would make the current file
+    /// exceed this many bytes.
+    ///
+    /// An item larger than the limit is written to its own file.
+    Bytes(u64),
+    /// Switches to a new file after this many items have been written to the current one.
+    ///
+    /// A limit of 0 is treated as 1.
+    Items(u64),
+}
+
+impl<F> RotatingFileWriter<F>
+where
+    F: FnMut(usize) -> PathBuf,
+{
+    /// Creates a new instance of this collector.
+    ///
+    /// `path_fn` produces the path of the file at the given index.
+    #[inline]
+    pub const fn new(path_fn: F, rotation: Rotation) -> Self {
+        Self {
+            path_fn,
+            rotation,
+            file: None,
+            written: 0,
+            paths: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Returns the paths of the files created so far.
+    #[inline]
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    fn should_rotate(&self, len: u64) -> bool {
+        self.file.is_none()
+            || match self.rotation {
+                Rotation::Bytes(max) => self.written > 0 && self.written + len > max,
+                Rotation::Items(max) => self.written >= max.max(1),
+            }
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+        }
+
+        let path = (self.path_fn)(self.paths.len());
+        let file = File::create(&path)?;
+        self.paths.push(path);
+        self.file = Some(BufWriter::new(file));
+        self.written = 0;
+
+        Ok(())
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<()> {
+        let len = buf.len() as u64;
+
+        if self.should_rotate(len) {
+            self.rotate()?;
+        }
+
+        if let Some(file) = &mut self.file {
+            file.write_all(buf)?;
+        }
+
+        self.written += match self.rotation {
+            Rotation::Bytes(_) => len,
+            Rotation::Items(_) => 1,
+        };
+
+        Ok(())
+    }
+}
+
+impl<F> CollectorBase for RotatingFileWriter<F>
+where
+    F: FnMut(usize) -> PathBuf,
+{
+    type Output = io::Result<Vec<PathBuf>>;
+
+    fn finish(self) -> Self::Output {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        if let Some(mut file) = self.file {
+            file.flush()?;
+        }
+
+        Ok(self.paths)
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.error.is_some() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<F, T> Collector<T> for RotatingFileWriter<F>
+where
+    F: FnMut(usize) -> PathBuf,
+    T: AsRef<[u8]>,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match self.write(item.as_ref()) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => {
+                self.error = Some(e);
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+impl<F> Debug for RotatingFileWriter<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RotatingFileWriter")
+            .field("rotation", &self.rotation)
+            .field("file", &self.file)
+            .field("written", &self.written)
+            .field("paths", &self.paths)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::{fs, path::Path};
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn by_bytes(
+            chunks in propvec(propvec(any::<u8>(), ..20), ..10),
+            max in ..40_u64,
+        ) {
+            all_collect_methods_impl(chunks, Rotation::Bytes(max))?;
+        }
+
+        #[test]
+        fn by_items(
+            chunks in propvec(propvec(any::<u8>(), ..5), ..10),
+            max in ..5_u64,
+        ) {
+            all_collect_methods_impl(chunks, Rotation::Items(max))?;
+        }
+    }
+
+    fn all_collect_methods_impl(chunks: Vec<Vec<u8>>, rotation: Rotation) -> TestCaseResult {
+        let root = TempDir::new("proptest");
+        let mut run = 0;
+
+        BasicCollectorTester {
+            iter_factory: || chunks.iter(),
+            collector_factory: || {
+                // Each run writes to its own directory.
+                let dir = root.path().join(run.to_string());
+                run += 1;
+                fs::create_dir(&dir).unwrap();
+                RotatingFileWriter::new(move |idx| dir.join(idx.to_string()), rotation)
+            },
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let contents: Vec<_> = output
+                    .unwrap()
+                    .iter()
+                    .map(|path| fs::read(path).unwrap())
+                    .collect();
+
+                if contents != expected_files(iter, rotation) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The contents of each file, rotated the naive way.
+    fn expected_files<'a>(
+        chunks: impl Iterator<Item = &'a Vec<u8>>,
+        rotation: Rotation,
+    ) -> Vec<Vec<u8>> {
+        let mut files: Vec<Vec<u8>> = vec![];
+        let mut items = 0;
+
+        for chunk in chunks {
+            let rotate = files.last().is_none_or(|file| match rotation {
+                Rotation::Bytes(max) => !file.is_empty() && (file.len() + chunk.len()) as u64 > max,
+                Rotation::Items(max) => items >= max.max(1),
+            });
+
+            if rotate {
+                files.push(vec![]);
+                items = 0;
+            }
+
+            files.last_mut().unwrap().extend(chunk);
+            items += 1;
+        }
+
+        files
+    }
+
+    #[test]
+    fn item_larger_than_limit() {
+        let dir = TempDir::new("large");
+        let contents = feed(&dir, ["ab", "cdefg", "h", "ijklmn"], Rotation::Bytes(3));
+
+        assert_eq!(contents, ["ab", "cdefg", "h", "ijklmn"]);
+    }
+
+    #[test]
+    fn items_zero_as_one() {
+        let dir = TempDir::new("items-zero");
+        let contents = feed(&dir, ["a", "b", "c"], Rotation::Items(0));
+
+        assert_eq!(contents, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn no_file_for_empty_stream() {
+        for rotation in [Rotation::Bytes(10), Rotation::Items(10)] {
+            let dir = TempDir::new("empty");
+            let contents = feed(&dir, [], rotation);
+
+            assert!(contents.is_empty());
+            assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+        }
+    }
+
+    // Returns the contents of the files written, checking that they are in `dir`.
+    fn feed<const N: usize>(dir: &TempDir, items: [&str; N], rotation: Rotation) -> Vec<String> {
+        let paths = items
+            .into_iter()
+            .feed_into(RotatingFileWriter::new(
+                |idx| dir.path().join(format!("part-{idx}")),
+                rotation,
+            ))
+            .unwrap();
+
+        paths
+            .iter()
+            .enumerate()
+            .map(|(idx, path)| {
+                assert_eq!(*path, dir.path().join(format!("part-{idx}")));
+                fs::read_to_string(path).unwrap()
+            })
+            .collect()
+    }
+
+    // A directory removed on drop, unique to the process and the name.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "komadori-rotating-file-writer-{}-{name}-{:?}",
+                std::process::id(),
+                std::thread::current().id(),
+            ));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    fmt::Display,
+    io::{self, StderrLock, StdoutLock, Write},
+    ops::ControlFlow,
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that writes each collected item as a line to a [`Write`]r.
+/// Its [`Output`](CollectorBase::Output) is [`io::Result<W>`].
+///
+/// It accepts any item implementing [`Display`].
+/// Each item is written followed by `\n`.
+///
+/// If writing fails, this collector stops accumulating
+/// and the error is returned on finishing.
+/// Otherwise, the writer is flushed and returned.
+///
+/// To write to the standard output or error, see [`stdout_lines()`] and [`stderr_lines()`].
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, io::WriteLines};
+///
+/// let buf = [1, 2, 3]
+///     .into_iter()
+///     .feed_into(WriteLines::new(vec![]))
+///     .unwrap();
+///
+/// assert_eq!(buf, b"1\n2\n3\n");
+/// ```
+#[derive(Debug)]
+pub struct WriteLines<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W> WriteLines<W>
+where
+    W: Write,
+{
+    /// Creates a new instance of this collector writing to the given writer.
+    #[inline]
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+}
+
+/// Creates a collector that writes each collected item as a line to the standard output.
+///
+/// The standard output is locked once for the whole lifetime of the collector,
+/// instead of once per item as [`println!`] does.
+/// It is flushed on finishing.
+///
+/// See [`WriteLines`] for more.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, io::stdout_lines};
+///
+/// ["the", "noble", "and", "the", "singer"]
+///     .into_iter()
+///     .feed_into(stdout_lines())
+///     .unwrap();
+/// ```
+#[inline]
+pub fn stdout_lines() -> WriteLines<StdoutLock<'static>> {
+    WriteLines::new(io::stdout().lock())
+}
+
+/// Creates a collector that writes each collected item as a line to the standard error.
+///
+/// The standard error is locked once for the whole lifetime of the collector,
+/// instead of once per item as [`eprintln!`] does.
+///
+/// See [`WriteLines`] for more.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, io::stderr_lines};
+///
+/// [1, 2, 3].into_iter().feed_into(stderr_lines()).unwrap();
+/// ```
+#[inline]
+pub fn stderr_lines() -> WriteLines<StderrLock<'static>> {
+    WriteLines::new(io::stderr().lock())
+}
+
+impl<W> CollectorBase for WriteLines<W>
+where
+    W: Write,
+{
+    type Output = io::Result<W>;
+
+    fn finish(mut self) -> Self::Output {
+        match self.error {
+            Some(e) => Err(e),
+            None => self.writer.flush().map(|_| self.writer),
+        }
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.error.is_some() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<W, T> Collector<T> for WriteLines<W>
+where
+    W: Write,
+    T: Display,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match writeln!(self.writer, "{item}") {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => {
+                self.error = Some(e);
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || WriteLines::new(vec![]),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let expected: String = iter.map(|num| format!("{num}\n")).collect();
+
+                if output.ok().as_deref() != Some(expected.as_bytes()) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
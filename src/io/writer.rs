@@ -0,0 +1,172 @@
+use std::{
+    io::{self, Write},
+    ops::ControlFlow,
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that writes collected bytes to a [`Write`]r.
+/// Its [`Output`](CollectorBase::Output) is [`io::Result<W>`].
+///
+/// It accepts any item implementing [`AsRef<[u8]>`](AsRef), such as `&[u8]`,
+/// `&str`, [`String`] and [`Vec<u8>`].
+/// Each item is written entirely with [`write_all()`](Write::write_all).
+///
+/// If writing fails, this collector stops accumulating
+/// and the error is returned on finishing.
+/// Otherwise, the writer is flushed and returned.
+///
+/// No buffering is done. Wrap the writer in a [`BufWriter`](std::io::BufWriter)
+/// if needed.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, io::Writer};
+///
+/// let buf = ["the ", "noble ", "and ", "the ", "singer"]
+///     .into_iter()
+///     .feed_into(Writer::new(vec![]))
+///     .unwrap();
+///
+/// assert_eq!(buf, b"the noble and the singer");
+/// ```
+#[derive(Debug)]
+pub struct Writer<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a new instance of this collector writing to the given writer.
+    #[inline]
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+}
+
+impl<W> CollectorBase for Writer<W>
+where
+    W: Write,
+{
+    type Output = io::Result<W>;
+
+    fn finish(mut self) -> Self::Output {
+        match self.error {
+            Some(e) => Err(e),
+            None => self.writer.flush().map(|_| self.writer),
+        }
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.error.is_some() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<W, T> Collector<T> for Writer<W>
+where
+    W: Write,
+    T: AsRef<[u8]>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        match self.writer.write_all(item.as_ref()) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => {
+                self.error = Some(e);
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            chunks in propvec(propvec(any::<u8>(), ..5), ..5),
+        ) {
+            all_collect_methods_impl(chunks)?;
+        }
+
+        #[test]
+        fn write_error(
+            chunks in propvec(propvec(any::<u8>(), ..5), ..5),
+            cap in ..20_usize,
+        ) {
+            write_error_impl(chunks, cap)?;
+        }
+    }
+
+    fn all_collect_methods_impl(chunks: Vec<Vec<u8>>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chunks.iter(),
+            collector_factory: || Writer::new(vec![]),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if output.ok() != Some(iter.flatten().copied().collect()) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // Writing into a fixed-size buffer fails once it is full.
+    fn write_error_impl(chunks: Vec<Vec<u8>>, cap: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chunks.iter(),
+            collector_factory: || Writer::new(io::Cursor::new(vec![0; cap].into_boxed_slice())),
+            should_break_pred: |iter| iter.flatten().count() > cap,
+            pred: |mut iter, output, remaining| {
+                let mut written = 0;
+                let fits = iter
+                    .by_ref()
+                    .take_while(|chunk| {
+                        written += chunk.len();
+                        written <= cap
+                    })
+                    .count();
+
+                if output.is_ok() != (fits == chunks.len()) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.ne(iter) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
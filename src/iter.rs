@@ -1,8 +1,8 @@
 //! Extension for the [`Iterator`] trait and
 //! [`Collector`]s for common operations in that trait.
 //!
-//! This module also includes most "terminal" operations such as [`fold()`], [`any()`]
-//! and [`find()`], except some like [`min()`], [`max()`] and [`sum()`]
+//! This module also includes most "terminal" operations such as [`fold()`], [`any()`],
+//! [`find()`] and [`position()`], except some like [`min()`], [`max()`] and [`sum()`]
 //! which are in more appropriate modules.
 //!
 //! This module corresponds to [`std::iter`].
@@ -11,28 +11,53 @@
 //! [`fold()`]: Iterator::fold
 //! [`any()`]: Iterator::any
 //! [`find()`]: Iterator::find
+//! [`position()`]: Iterator::position
 //! [`min()`]: Iterator::min
 //! [`max()`]: Iterator::max
 //! [`sum()`]: Iterator::sum
 
 mod all_any;
+#[cfg(feature = "std")]
+mod all_unique;
+mod collected;
 mod count;
+#[cfg(feature = "std")]
+mod count_by;
+mod drain_on_shutdown;
 #[cfg(feature = "unstable")]
 mod driver;
 mod find;
+mod first;
 mod fold;
+mod for_each;
 mod iterator_ext;
 mod last;
+mod longest_run;
+mod longest_run_by;
+mod position;
 mod reduce;
 mod try_fold;
+mod try_for_each;
 
 pub use all_any::*;
+#[cfg(feature = "std")]
+pub use all_unique::*;
+pub use collected::*;
 pub use count::*;
+#[cfg(feature = "std")]
+pub use count_by::*;
+pub use drain_on_shutdown::*;
 #[cfg(feature = "unstable")]
 pub use driver::*;
 pub use find::*;
+pub use first::*;
 pub use fold::*;
+pub use for_each::*;
 pub use iterator_ext::*;
 pub use last::*;
+pub use longest_run::*;
+pub use longest_run_by::*;
+pub use position::*;
 pub use reduce::*;
 pub use try_fold::*;
+pub use try_for_each::*;
@@ -41,6 +41,21 @@ use super::raw_all_any::RawAllAny;
 /// assert!(!collector.finish());
 /// ```
 ///
+/// Stopping early does not stop the collectors it is [`tee`](CollectorBase::tee)d with,
+/// so the data can be validated while it is being collected.
+///
+/// ```
+/// use komadori::{prelude::*, iter::{All, Any}};
+///
+/// let ((all_valid, any_empty), ages) = [31, 0, 45]
+///     .into_iter()
+///     .feed_into(All::new(|age| age < 150).tee(Any::new(|age| age == 0)).tee(vec![]));
+///
+/// assert!(all_valid);
+/// assert!(any_empty);
+/// assert_eq!(ages, [31, 0, 45]);
+/// ```
+///
 /// [`Break`]: std::ops::ControlFlow::Break
 /// [`Output`]: CollectorBase::Output
 #[derive(Clone)]
@@ -114,7 +129,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
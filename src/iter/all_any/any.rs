@@ -41,6 +41,8 @@ use super::raw_all_any::RawAllAny;
 /// assert!(collector.finish());
 /// ```
 ///
+/// See [`All`](super::All) for an example of combining it with other collectors.
+///
 /// [`Break`]: std::ops::ControlFlow::Break
 /// [`Output`]: CollectorBase::Output
 #[derive(Clone)]
@@ -114,7 +116,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
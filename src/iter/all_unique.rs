@@ -0,0 +1,168 @@
+use std::{collections::HashSet, hash::Hash, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that determines whether all collected items are distinct from each other.
+///
+/// The [`Output`](CollectorBase::Output) is `true` if no items were collected.
+///
+/// Items are remembered in a [`HashSet`],
+/// and it stops accumulating at the first item equal to an earlier one.
+///
+/// This corresponds to [`Itertools::all_unique()`].
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, iter::AllUnique};
+///
+/// let mut collector = AllUnique::new();
+///
+/// assert!(collector.collect(1).is_continue());
+/// assert!(collector.collect(2).is_continue());
+///
+/// // Seen before.
+/// assert!(collector.collect(1).is_break());
+///
+/// assert!(!collector.finish());
+/// ```
+///
+/// Checking that the IDs of an import are unique, while collecting the records:
+///
+/// ```
+/// use komadori::{prelude::*, iter::AllUnique};
+///
+/// let records = [(1, "Alice"), (2, "Bob"), (3, "Alice")];
+///
+/// let (ids_unique, records) = records
+///     .into_iter()
+///     .feed_into(AllUnique::new().map(|(id, _)| id).tee(vec![]));
+///
+/// assert!(ids_unique);
+/// assert_eq!(records.len(), 3);
+/// ```
+///
+/// [`Itertools::all_unique()`]: https://docs.rs/itertools/latest/itertools/trait.Itertools.html#method.all_unique
+#[derive(Debug, Clone)]
+pub struct AllUnique<T> {
+    seen: HashSet<T>,
+    unique: bool,
+}
+
+impl<T> AllUnique<T>
+where
+    T: Eq + Hash,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            seen: HashSet::new(),
+            unique: true,
+        }
+    }
+}
+
+impl<T> Default for AllUnique<T>
+where
+    T: Eq + Hash,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for AllUnique<T>
+where
+    T: Eq + Hash,
+{
+    type Output = bool;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.unique
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.unique {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        self.seen.reserve(additional_min);
+    }
+}
+
+impl<T> Collector<T> for AllUnique<T>
+where
+    T: Eq + Hash,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.unique && !self.seen.insert(item) {
+            self.unique = false;
+        }
+
+        self.break_hint()
+    }
+
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let Self { mut seen, unique } = self;
+        unique && items.into_iter().all(move |item| seen.insert(item))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::collections::HashSet;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..6, ..=5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: AllUnique::new,
+            should_break_pred: |iter| duplicate_at(iter).is_some(),
+            pred: |iter, output, remaining| {
+                let duplicate_at = duplicate_at(iter.clone());
+                let consumed = duplicate_at.unwrap_or(nums.len());
+
+                if output != duplicate_at.is_none() {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The number of items up to and including the first duplicate, if any.
+    fn duplicate_at(mut iter: impl Iterator<Item = i32>) -> Option<usize> {
+        let mut seen = HashSet::new();
+        iter.position(|num| !seen.insert(num)).map(|idx| idx + 1)
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use crate::collector::{IntoCollector, IntoCollectorBase};
+
+/// The output of a collector `C`, obtained through [`FromIterator`].
+///
+/// This lets [`collect()`](Iterator::collect), and any API that only accepts
+/// [`FromIterator`] targets, drive a collector:
+/// `iter.collect::<Collected<C>>()` is equivalent to
+/// [`iter.feed_into(C::default())`](super::IteratorExt::feed_into).
+/// Hence, `C` must implement [`Default`], which rules out collectors holding closures.
+///
+/// The output is accessed through [`Deref`] or [`into_inner()`](Collected::into_inner).
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, cmp::Max, iter::{Collected, Count}};
+///
+/// // Some generic code that cannot be changed to take a collector.
+/// fn parse_all<B: FromIterator<i32>>(s: &str) -> B {
+///     s.split(',').map(|num| num.parse().unwrap()).collect()
+/// }
+///
+/// let stats: Collected<(Count, Max<i32>)> = parse_all("4,2,6,3");
+///
+/// assert_eq!(*stats, (4, Some(6)));
+/// assert_eq!(stats.into_inner(), (4, Some(6)));
+/// ```
+pub struct Collected<C: IntoCollectorBase>(C::Output);
+
+impl<C: IntoCollectorBase> Collected<C> {
+    /// Returns the output of the collector.
+    #[inline]
+    pub fn into_inner(self) -> C::Output {
+        self.0
+    }
+}
+
+impl<C, T> FromIterator<T> for Collected<C>
+where
+    C: IntoCollector<T> + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        use super::IteratorExt;
+
+        Self(iter.into_iter().feed_into(C::default()))
+    }
+}
+
+impl<C: IntoCollectorBase> Deref for Collected<C> {
+    type Target = C::Output;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<C: IntoCollectorBase> DerefMut for Collected<C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+// Derives would bound `C` instead of `C::Output`.
+impl<C> Debug for Collected<C>
+where
+    C: IntoCollectorBase<Output: Debug>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Collected").field(&self.0).finish()
+    }
+}
+
+impl<C> Clone for Collected<C>
+where
+    C: IntoCollectorBase<Output: Clone>,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<C> PartialEq for Collected<C>
+where
+    C: IntoCollectorBase<Output: PartialEq>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C> Eq for Collected<C> where C: IntoCollectorBase<Output: Eq> {}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+
+    use crate::{cmp::Max, iter::Count};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn same_as_feed_into(
+            nums in propvec(any::<i32>(), ..5),
+        ) {
+            use crate::iter::IteratorExt;
+
+            let collected: Collected<(Count, Max<i32>, Vec<i32>)> = nums.iter().copied().collect();
+            let fed = nums.iter().copied().feed_into((Count::new(), Max::new(), vec![]));
+
+            prop_assert_eq!(collected.into_inner(), fed);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::{fmt::Debug, ops::ControlFlow};
 
-use crate::collector::{Collector, CollectorBase, assert_collector_base};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector, assert_collector_base};
 
 /// A collector that counts the number of items it collects.
 ///
@@ -27,7 +27,21 @@ use crate::collector::{Collector, CollectorBase, assert_collector_base};
 ///
 /// assert_eq!(collector.finish(), 4);
 /// ```
+///
+/// Any item type is accepted, including references to unsized types.
+///
+/// ```
+/// use komadori::{prelude::*, iter::Count};
+///
+/// let mut collector = Count::new();
+///
+/// assert!(collector.collect("hello").is_continue());
+/// assert!(collector.collect_many(b"hi world".split(|&b| b == b' ')).is_continue());
+///
+/// assert_eq!(collector.finish(), 3);
+/// ```
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Count {
     count: usize,
 }
@@ -62,6 +76,13 @@ impl CollectorBase for Count {
     }
 }
 
+impl SnapshotCollector for Count {
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.count
+    }
+}
+
 impl<T> Collector<T> for Count {
     #[inline]
     fn collect(&mut self, _: T) -> ControlFlow<()> {
@@ -86,7 +107,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
@@ -0,0 +1,244 @@
+use std::{borrow::Borrow, collections::HashMap, fmt::Debug, hash::Hash, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that counts the collected items per key computed by a function.
+/// Its [`Output`](CollectorBase::Output) is a [`HashMap`] from keys to counts.
+///
+/// The function only borrows each item, so items can be references
+/// into data that is still used elsewhere, or be moved on afterwards
+/// by adapters such as [`tee()`](CollectorBase::tee).
+/// If the key can be borrowed from the item, [`CountByBorrowed`] avoids creating
+/// an owned key for every item.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use komadori::{prelude::*, iter::CountBy};
+///
+/// let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+///
+/// let (by_initial, words) = words
+///     .into_iter()
+///     .feed_into(CountBy::new(|word: &&str| word.chars().next()).tee(vec![]));
+///
+/// assert_eq!(
+///     by_initial,
+///     HashMap::from([(Some('a'), 2), (Some('b'), 2), (Some('c'), 1)]),
+/// );
+/// assert_eq!(words.len(), 5);
+/// ```
+#[derive(Clone)]
+pub struct CountBy<K, F> {
+    counts: HashMap<K, usize>,
+    f: F,
+}
+
+impl<K, F> CountBy<K, F>
+where
+    K: Hash + Eq,
+{
+    /// Creates a new instance of this collector with a key-extraction function.
+    #[inline]
+    pub fn new<T>(f: F) -> Self
+    where
+        F: FnMut(&T) -> K,
+    {
+        assert_collector::<_, T>(Self {
+            counts: HashMap::new(),
+            f,
+        })
+    }
+}
+
+impl<K, F> CollectorBase for CountBy<K, F> {
+    type Output = HashMap<K, usize>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.counts
+    }
+}
+
+impl<K, T, F> Collector<T> for CountBy<K, F>
+where
+    K: Hash + Eq,
+    F: FnMut(&T) -> K,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        *self.counts.entry((self.f)(&item)).or_insert(0) += 1;
+        ControlFlow::Continue(())
+    }
+}
+
+impl<K: Debug, F> Debug for CountBy<K, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountBy")
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+/// A collector that counts the collected items per key borrowed from each item.
+/// Its [`Output`](CollectorBase::Output) is a [`HashMap`] from owned keys to counts.
+///
+/// This is [`CountBy`] for keys borrowed from the items (e.g., a `&str` in a log record).
+/// The key is only converted into its owned form ([`ToOwned`]) the first time it is seen,
+/// so repeated keys do not allocate.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use komadori::{prelude::*, iter::CountByBorrowed};
+///
+/// let lines = ["GET /", "POST /login", "GET /about", "GET /"].map(String::from);
+///
+/// let by_method = lines.into_iter().feed_into(CountByBorrowed::new(|line: &String| {
+///     line.split(' ').next().unwrap_or_default()
+/// }));
+///
+/// assert_eq!(
+///     by_method,
+///     HashMap::from([("GET".to_owned(), 3), ("POST".to_owned(), 1)]),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct CountByBorrowed<K, F> {
+    counts: HashMap<K, usize>,
+    f: F,
+}
+
+impl<K, F> CountByBorrowed<K, F>
+where
+    K: Hash + Eq,
+{
+    /// Creates a new instance of this collector with a key-borrowing function.
+    #[inline]
+    pub fn new<T, Q>(f: F) -> Self
+    where
+        K: Borrow<Q>,
+        F: FnMut(&T) -> &Q,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        assert_collector::<_, T>(Self {
+            counts: HashMap::new(),
+            f,
+        })
+    }
+}
+
+impl<K, F> CollectorBase for CountByBorrowed<K, F> {
+    type Output = HashMap<K, usize>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.counts
+    }
+}
+
+impl<K, T, F, Q> Collector<T> for CountByBorrowed<K, F>
+where
+    K: Hash + Eq + Borrow<Q>,
+    F: FnMut(&T) -> &Q,
+    Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let key = (self.f)(&item);
+
+        match self.counts.get_mut(key) {
+            Some(count) => *count += 1,
+            None => {
+                self.counts.insert(key.to_owned(), 1);
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<K: Debug, F> Debug for CountByBorrowed<K, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountByBorrowed")
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..9),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+
+        #[test]
+        fn borrowed_all_collect_methods(
+            words in propvec("[a-c]{0,2}", ..9),
+        ) {
+            borrowed_all_collect_methods_impl(words)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter(),
+            collector_factory: || CountBy::new(|&&num: &&i32| num.rem_euclid(3)),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut expected = HashMap::new();
+                for num in iter {
+                    *expected.entry(num.rem_euclid(3)).or_insert(0) += 1;
+                }
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn borrowed_all_collect_methods_impl(words: Vec<String>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || words.iter().cloned(),
+            collector_factory: || CountByBorrowed::new(|word: &String| word.get(..1).unwrap_or("")),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut expected = HashMap::new();
+                for word in iter {
+                    *expected
+                        .entry(word.get(..1).unwrap_or("").to_owned())
+                        .or_insert(0) += 1;
+                }
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
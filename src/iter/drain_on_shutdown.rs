@@ -0,0 +1,85 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// An [`Iterator`] that stops pulling items from the underlying iterator
+/// once a shutdown flag is set.
+///
+/// This `struct` is created by [`IteratorExt::drain_on_shutdown()`].
+/// See its documentation for more.
+///
+/// [`IteratorExt::drain_on_shutdown()`]: crate::iter::IteratorExt::drain_on_shutdown
+#[derive(Debug, Clone)]
+pub struct DrainOnShutdown<'a, I> {
+    iter: I,
+    shutdown: &'a AtomicBool,
+}
+
+impl<'a, I> DrainOnShutdown<'a, I> {
+    pub(in crate::iter) fn new(iter: I, shutdown: &'a AtomicBool) -> Self {
+        Self { iter, shutdown }
+    }
+
+    /// Returns the underlying iterator, with the items not yet pulled.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I> Iterator for DrainOnShutdown<'_, I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Checked before pulling, so that no item is pulled only to be dropped.
+        if self.shutdown.load(Ordering::Acquire) {
+            None
+        } else {
+            self.iter.next()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+
+    use crate::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn stops_before_pulling(
+            nums in propvec(any::<i32>(), ..=5),
+            shutdown_after in ..=5_usize,
+        ) {
+            let shutdown = AtomicBool::new(shutdown_after == 0);
+            let mut pulled = 0;
+            let mut iter = nums.iter().copied();
+
+            let collected = iter
+                .by_ref()
+                .inspect(|_| {
+                    pulled += 1;
+                    if pulled == shutdown_after {
+                        shutdown.store(true, Ordering::Release);
+                    }
+                })
+                .drain_on_shutdown(&shutdown)
+                .feed_into(vec![]);
+
+            let n = shutdown_after.min(nums.len());
+            prop_assert_eq!(&collected[..], &nums[..n]);
+            prop_assert!(iter.eq(nums[n..].iter().copied()));
+        }
+    }
+}
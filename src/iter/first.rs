@@ -0,0 +1,141 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that stores the first item it collects.
+///
+/// If no items have been collected, its [`Output`] is `None`;
+/// otherwise, it is `Some` containing the first collected item.
+/// It stops accumulating right after collecting that item.
+///
+/// This collector corresponds to [`Iterator::next()`], or [`Iterator::nth()`] with `0`,
+/// and complements [`Last`](super::Last).
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, iter::First};
+///
+/// let mut collector = First::new();
+///
+/// assert!(collector.break_hint().is_continue());
+/// assert!(collector.collect(1).is_break());
+///
+/// assert_eq!(collector.finish(), Some(1));
+/// ```
+///
+/// Together with [`Last`](super::Last) and [`Count`](super::Count),
+/// the first item seen, the last one, and how many there are can be found in one pass.
+///
+/// ```
+/// use komadori::{prelude::*, iter::{Count, First, Last}};
+///
+/// let ((first, last), count) = [3, 1, 4, 1, 5]
+///     .into_iter()
+///     .feed_into(First::new().tee(Last::new()).tee(Count::new()));
+///
+/// assert_eq!(first, Some(3));
+/// assert_eq!(last, Some(5));
+/// assert_eq!(count, 5);
+/// ```
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct First<T> {
+    value: Option<T>,
+}
+
+impl<T> First<T> {
+    /// Creates an instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        assert_collector::<_, T>(First { value: None })
+    }
+}
+
+impl<T> CollectorBase for First<T> {
+    type Output = Option<T>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.value
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.value.is_some() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<T> Collector<T> for First<T> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.value.is_none() {
+            self.value = Some(item);
+        }
+
+        ControlFlow::Break(())
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        if self.value.is_none() {
+            self.value = items.into_iter().next();
+        }
+
+        self.break_hint()
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        self.value.or_else(|| items.into_iter().next())
+    }
+}
+
+impl<T> Default for First<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=9),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: First::new,
+            should_break_pred: |mut iter| iter.next().is_some(),
+            pred: |mut iter, output, remaining| {
+                if iter.next() != output {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
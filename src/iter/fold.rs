@@ -7,6 +7,19 @@ use crate::collector::{Collector, CollectorBase, assert_collector};
 /// This collector corresponds to [`Iterator::fold()`], except that
 /// the accumulated value is mutated in place.
 ///
+/// # Performance
+///
+/// [`collect_many()`](Collector::collect_many) and
+/// [`collect_then_finish()`](Collector::collect_then_finish) drive the iterator with
+/// [`for_each()`](Iterator::for_each), which is built on [`Iterator::fold()`].
+/// Hence, `iter.feed_into(Fold::new(init, f))` iterates the same way as the equivalent
+/// `iter.fold(init, f)`, including for iterators overriding `fold()`
+/// (e.g., [`Chain`](std::iter::Chain)), and is expected to perform on par with it.
+/// This makes it a supported fallback when profiling shows that a chain of adaptors
+/// is slower than a raw fold.
+///
+/// The `fold` benchmark compares the two.
+///
 /// # Examples
 ///
 /// ```
@@ -86,7 +99,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
@@ -0,0 +1,123 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that calls a closure on each item.
+///
+/// This collector corresponds to [`Iterator::for_each()`].
+/// It is useful for side-effecting consumers (e.g., printing, sending metrics)
+/// at the end of a chain, without defining a dedicated collector.
+///
+/// This collector never stops accumulating. Use [`TryForEach`](super::TryForEach)
+/// if the closure should be able to stop it.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, iter::ForEach};
+///
+/// let mut log = vec![];
+///
+/// let sum = [1, 2, 3]
+///     .into_iter()
+///     .feed_into(i32::adding().tee(ForEach::new(|num| log.push(format!("saw {num}")))))
+///     .0;
+///
+/// assert_eq!(sum, 6);
+/// assert_eq!(log, ["saw 1", "saw 2", "saw 3"]);
+/// ```
+#[derive(Clone)]
+pub struct ForEach<F> {
+    f: F,
+}
+
+impl<F> ForEach<F> {
+    /// Creates a new instance of this collector with a closure.
+    #[inline]
+    pub const fn new<T>(f: F) -> Self
+    where
+        F: FnMut(T),
+    {
+        assert_collector::<_, T>(Self { f })
+    }
+}
+
+impl<F> CollectorBase for ForEach<F> {
+    type Output = ();
+
+    #[inline]
+    fn finish(self) -> Self::Output {}
+}
+
+impl<T, F> Collector<T> for ForEach<F>
+where
+    F: FnMut(T),
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        (self.f)(item);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        items.into_iter().for_each(&mut self.f);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        items.into_iter().for_each(self.f);
+    }
+}
+
+impl<F> Debug for ForEach<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ForEach").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cell::RefCell;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        let seen = RefCell::new(vec![]);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                seen.borrow_mut().clear();
+                ForEach::new(|num| seen.borrow_mut().push(num))
+            },
+            should_break_pred: |_| false,
+            pred: |iter, (), remaining| {
+                if iter.ne(seen.borrow().iter().copied()) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,9 +1,12 @@
+use std::sync::atomic::AtomicBool;
+
+use super::DrainOnShutdown;
 #[cfg(feature = "unstable")]
 use super::Driver;
 
-use crate::collector::{Collector, IntoCollector};
 #[cfg(feature = "unstable")]
-use crate::{assert_iterator, collector::CollectorBase};
+use crate::assert_iterator;
+use crate::collector::{Collector, CollectorBase, IntoCollector};
 
 /// Extends [`Iterator`] with various methods to work with [`Collector`]s.
 ///
@@ -47,7 +50,145 @@ pub trait IteratorExt: Iterator {
         Self: Sized,
         C: IntoCollector<Self::Item>,
     {
-        collector.into_collector().collect_then_finish(self)
+        let mut collector = collector.into_collector();
+        let (lower, upper) = self.size_hint();
+        collector.reserve(lower, upper);
+        collector.collect_then_finish(self)
+    }
+
+    /// An alias of [`feed_into()`](IteratorExt::feed_into).
+    ///
+    /// To use this method, import the [`IteratorExt`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, cmp::Max};
+    ///
+    /// let (max, nums) = [4, 2, 6, 3]
+    ///     .into_iter()
+    ///     .collect_with(Max::new().tee(vec![]));
+    ///
+    /// assert_eq!(max, Some(6));
+    /// assert_eq!(nums, [4, 2, 6, 3]);
+    /// ```
+    #[inline]
+    fn collect_with<C>(self, collector: C) -> C::Output
+    where
+        Self: Sized,
+        C: IntoCollector<Self::Item>,
+    {
+        self.feed_into(collector)
+    }
+
+    /// Feeds the [`Ok`] values from this iterator of [`Result`]s into the provided collector
+    /// till the collector stops accumulating, the iterator is exhausted,
+    /// or an [`Err`] is encountered.
+    ///
+    /// If an [`Err`] is encountered, it is returned and the collector is dropped
+    /// without being finished.
+    /// Otherwise, the collector’s output is returned in [`Ok`].
+    /// This is similar to how [`Result`] implements [`FromIterator`].
+    ///
+    /// Errors after the point where the collector stops accumulating are not observed,
+    /// since the iterator is not advanced further.
+    ///
+    /// To use this method, import the [`IteratorExt`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use komadori::{prelude::*, cmp::Max};
+    ///
+    /// let nums = ["4", "2", "6", "3"].into_iter().map(str::parse::<i32>);
+    /// assert_eq!(nums.feed_ok_into(Max::new()), Ok(Some(6)));
+    ///
+    /// let nums = ["4", "two", "6", "3"].into_iter().map(str::parse::<i32>);
+    /// assert!(nums.feed_ok_into(Max::new()).is_err());
+    /// ```
+    ///
+    /// Items after the first error are not consumed.
+    ///
+    /// ```
+    /// use komadori::prelude::*;
+    ///
+    /// let mut results = [Ok(1), Err("oops"), Ok(3)].into_iter();
+    ///
+    /// assert_eq!(results.by_ref().feed_ok_into(vec![]), Err("oops"));
+    /// assert_eq!(results.next(), Some(Ok(3)));
+    /// ```
+    fn feed_ok_into<C, T, E>(self, collector: C) -> Result<C::Output, E>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        C: IntoCollector<T>,
+    {
+        let mut collector = collector.into_collector();
+        let mut error = None;
+
+        let _ = collector.collect_many(self.map_while(|item| match item {
+            Ok(item) => Some(item),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }));
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(collector.finish()),
+        }
+    }
+
+    /// Creates an iterator that stops pulling items from this iterator
+    /// once `shutdown` is set to `true`.
+    ///
+    /// The flag is checked before pulling each item, so no item is pulled only to be lost,
+    /// unlike with [`take_while()`](Iterator::take_while).
+    /// Since the iterator simply ends, the collector fed from it is finished as usual,
+    /// and buffering adaptors such as [`chunks()`] and [`split_when()`]
+    /// flush their pending items downstream instead of dropping them.
+    ///
+    /// This is meant for pipelines fed from a channel, which would otherwise only end
+    /// when every sender has been dropped. Note that the flag is not observed
+    /// while the underlying iterator is blocked, e.g., waiting for the next message.
+    /// Use [`Receiver::recv_timeout()`] to wake up periodically if needed.
+    ///
+    /// To use this method, import the [`IteratorExt`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{atomic::{AtomicBool, Ordering}, mpsc};
+    /// use komadori::prelude::*;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let shutdown = AtomicBool::new(false);
+    ///
+    /// for num in 1..=5 {
+    ///     tx.send(num).unwrap();
+    /// }
+    ///
+    /// let batches = rx
+    ///     .iter()
+    ///     // Simulates a shutdown signal arriving after the 5th message.
+    ///     .inspect(|&num| if num == 5 { shutdown.store(true, Ordering::Release) })
+    ///     // Without this, `rx.iter()` would block forever, since `tx` is alive.
+    ///     .drain_on_shutdown(&shutdown)
+    ///     .feed_into(vec![].into_collector().chunks(2, vec![]));
+    ///
+    /// // The last, incomplete batch is not lost.
+    /// assert_eq!(batches, [vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    ///
+    /// [`chunks()`]: CollectorBase::chunks
+    /// [`split_when()`]: CollectorBase::split_when
+    /// [`Receiver::recv_timeout()`]: https://doc.rust-lang.org/std/sync/mpsc/struct.Receiver.html#method.recv_timeout
+    #[inline]
+    fn drain_on_shutdown(self, shutdown: &AtomicBool) -> DrainOnShutdown<'_, Self>
+    where
+        Self: Sized,
+    {
+        DrainOnShutdown::new(self, shutdown)
     }
 
     /// Extracts items from this iterator into the provided collector as far as the
@@ -29,6 +29,16 @@ use crate::collector::{Collector, CollectorBase, assert_collector};
 /// assert_eq!(Last::<i32>::new().finish(), None);
 /// ```
 ///
+/// Borrowed unsized items, such as `&str` or `&[u8]`, can be stored directly.
+///
+/// ```
+/// use komadori::{prelude::*, iter::Last};
+///
+/// let line = String::from("foo bar baz");
+///
+/// assert_eq!(line.split(' ').feed_into(Last::new()), Some("baz"));
+/// ```
+///
 /// [`Output`]: CollectorBase::Output
 #[derive(Debug, Clone)]
 pub struct Last<T> {
@@ -90,7 +100,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
@@ -111,7 +121,7 @@ mod proptests {
             pred: |iter, output, remaining| {
                 if iter.last() != output {
                     Err(PredError::IncorrectOutput)
-                } else if remaining.ne([]) {
+                } else if remaining.count() != 0 {
                     Err(PredError::IncorrectIterConsumption)
                 } else {
                     Ok(())
@@ -0,0 +1,213 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A run of consecutive items, produced by [`LongestRun`] and [`LongestRunBy`].
+///
+/// [`LongestRunBy`]: super::LongestRunBy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Run<T> {
+    /// The index of the first item of the run, counting from 0.
+    pub start: usize,
+    /// The number of items in the run. It is never 0.
+    pub len: usize,
+    /// The first item of the run.
+    pub value: T,
+}
+
+/// A collector that finds the longest run of consecutive equal items.
+///
+/// Its [`Output`](CollectorBase::Output) is the longest [`Run`],
+/// or `None` if no items were collected.
+/// If several runs are the longest, the first one is output.
+///
+/// See [`LongestRunBy`](super::LongestRunBy) for runs of items satisfying a predicate instead.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, iter::{LongestRun, Run}};
+///
+/// let run = "aabbbcbb".chars().feed_into(LongestRun::new());
+///
+/// assert_eq!(run, Some(Run { start: 2, len: 3, value: 'b' }));
+/// ```
+///
+/// ```
+/// use komadori::{prelude::*, iter::LongestRun};
+///
+/// assert_eq!(LongestRun::<i32>::new().finish(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LongestRun<T> {
+    runs: Runs<T>,
+}
+
+impl<T> LongestRun<T>
+where
+    T: PartialEq,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { runs: Runs::new() }
+    }
+}
+
+impl<T> Default for LongestRun<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for LongestRun<T> {
+    type Output = Option<Run<T>>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.runs.finish()
+    }
+}
+
+impl<T> Collector<T> for LongestRun<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if self.runs.current().is_some_and(|run| run.value == item) {
+            self.runs.extend();
+        } else {
+            self.runs.start(item);
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+/// Tracks the current and the longest runs.
+#[derive(Debug, Clone)]
+pub(super) struct Runs<T> {
+    longest: Option<Run<T>>,
+    current: Option<Run<T>>,
+    // The index of the next item.
+    idx: usize,
+}
+
+impl<T> Runs<T> {
+    #[inline]
+    pub(super) const fn new() -> Self {
+        Self {
+            longest: None,
+            current: None,
+            idx: 0,
+        }
+    }
+
+    #[inline]
+    pub(super) const fn current(&self) -> Option<&Run<T>> {
+        self.current.as_ref()
+    }
+
+    /// Adds the next item to the current run, which must exist.
+    #[inline]
+    pub(super) fn extend(&mut self) {
+        if let Some(run) = &mut self.current {
+            run.len += 1;
+        }
+        self.idx += 1;
+    }
+
+    /// Ends the current run, if any, and starts a new one with the next item.
+    #[inline]
+    pub(super) fn start(&mut self, value: T) {
+        self.end();
+        self.current = Some(Run {
+            start: self.idx,
+            len: 1,
+            value,
+        });
+        self.idx += 1;
+    }
+
+    /// Ends the current run, if any, with the next item not belonging to any run.
+    #[inline]
+    pub(super) fn skip(&mut self) {
+        self.end();
+        self.idx += 1;
+    }
+
+    #[inline]
+    pub(super) fn finish(mut self) -> Option<Run<T>> {
+        self.end();
+        self.longest
+    }
+
+    fn end(&mut self) {
+        if let Some(run) = self.current.take()
+            && self
+                .longest
+                .as_ref()
+                .is_none_or(|longest| run.len > longest.len)
+        {
+            self.longest = Some(run);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..3, ..8),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: LongestRun::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let nums: Vec<_> = iter.collect();
+                let mut expected: Option<Run<i32>> = None;
+                let mut start = 0;
+
+                for chunk in nums.chunk_by(|a, b| a == b) {
+                    if expected.is_none_or(|run| chunk.len() > run.len) {
+                        expected = Some(Run {
+                            start,
+                            len: chunk.len(),
+                            value: chunk[0],
+                        });
+                    }
+                    start += chunk.len();
+                }
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
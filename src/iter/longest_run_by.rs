@@ -0,0 +1,146 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+use super::{Run, longest_run::Runs};
+
+/// A collector that finds the longest run of consecutive items satisfying a predicate.
+///
+/// Its [`Output`](CollectorBase::Output) is the longest [`Run`],
+/// or `None` if no collected items satisfy the predicate.
+/// If several runs are the longest, the first one is output.
+///
+/// See [`LongestRun`](super::LongestRun) for runs of equal items instead.
+///
+/// # Examples
+///
+/// Finding the longest error streak:
+///
+/// ```
+/// use komadori::{prelude::*, iter::{LongestRunBy, Run}};
+///
+/// let statuses = [200, 500, 503, 200, 500, 502, 504, 200];
+///
+/// let streak = statuses
+///     .into_iter()
+///     .feed_into(LongestRunBy::new(|&status: &i32| status >= 500));
+///
+/// assert_eq!(streak, Some(Run { start: 4, len: 3, value: 500 }));
+/// ```
+pub struct LongestRunBy<T, F> {
+    runs: Runs<T>,
+    pred: F,
+}
+
+impl<T, F> LongestRunBy<T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    /// Creates a new instance of this collector with a given predicate.
+    #[inline]
+    pub const fn new(pred: F) -> Self {
+        Self {
+            runs: Runs::new(),
+            pred,
+        }
+    }
+}
+
+impl<T, F> CollectorBase for LongestRunBy<T, F> {
+    type Output = Option<Run<T>>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.runs.finish()
+    }
+}
+
+impl<T, F> Collector<T> for LongestRunBy<T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if !(self.pred)(&item) {
+            self.runs.skip();
+        } else if self.runs.current().is_some() {
+            self.runs.extend();
+        } else {
+            self.runs.start(item);
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T: Clone, F: Clone> Clone for LongestRunBy<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            runs: self.runs.clone(),
+            pred: self.pred.clone(),
+        }
+    }
+}
+
+impl<T: Debug, F> Debug for LongestRunBy<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LongestRunBy")
+            .field("runs", &self.runs)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4, ..8),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        let pred = |&num: &i32| num % 2 == 0;
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || LongestRunBy::new(pred),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let nums: Vec<_> = iter.collect();
+                let mut expected: Option<Run<i32>> = None;
+                let mut start = 0;
+
+                for chunk in nums.chunk_by(|&a, &b| pred(&a) == pred(&b)) {
+                    if pred(&chunk[0]) && expected.is_none_or(|run| chunk.len() > run.len) {
+                        expected = Some(Run {
+                            start,
+                            len: chunk.len(),
+                            value: chunk[0],
+                        });
+                    }
+                    start += chunk.len();
+                }
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,184 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that searches for the index of the first item satisfying a predicate.
+///
+/// If no matching item has been found, its [`Output`] is `None`.
+/// When the collector encounters an item that makes the predicate return `true`,
+/// it returns [`Break`], and the [`Output`] becomes `Some` containing
+/// the number of items collected before that item.
+///
+/// This collector corresponds to [`Iterator::position()`].
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, iter::Position};
+///
+/// let mut collector = Position::new(|x| x < 0);
+///
+/// assert!(collector.collect(1).is_continue());
+/// assert!(collector.collect(2).is_continue());
+///
+/// // Found!
+/// assert!(collector.collect(-1).is_break());
+///
+/// assert_eq!(collector.finish(), Some(2));
+/// ```
+///
+/// Teed with a collection, the stream is still archived
+/// while the offset of the first matching record is found.
+///
+/// ```
+/// use komadori::{prelude::*, iter::Position};
+///
+/// let (offset, records) = ["ok", "ok", "error", "ok"]
+///     .into_iter()
+///     .feed_into(Position::new(|record| record == "error").tee(vec![]));
+///
+/// assert_eq!(offset, Some(2));
+/// assert_eq!(records, ["ok", "ok", "error", "ok"]);
+/// ```
+///
+/// [`Break`]: std::ops::ControlFlow::Break
+/// [`Output`]: CollectorBase::Output
+#[derive(Clone)]
+pub struct Position<F> {
+    // `None` once found.
+    pred: Option<F>,
+    index: usize,
+}
+
+impl<F> Position<F> {
+    /// Creates a new instance of this collector with a given predicate.
+    #[inline]
+    pub const fn new<T>(pred: F) -> Self
+    where
+        F: FnMut(T) -> bool,
+    {
+        assert_collector::<_, T>(Self {
+            pred: Some(pred),
+            index: 0,
+        })
+    }
+
+    /// Returns the index of the first matching item, if it has been found.
+    #[inline]
+    pub const fn get(&self) -> Option<usize> {
+        if self.pred.is_none() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+}
+
+impl<F> CollectorBase for Position<F> {
+    type Output = Option<usize>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.get()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.pred.is_some() {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+}
+
+impl<T, F> Collector<T> for Position<F>
+where
+    F: FnMut(T) -> bool,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let Some(ref mut pred) = self.pred else {
+            return ControlFlow::Break(());
+        };
+
+        if pred(item) {
+            self.pred = None;
+            ControlFlow::Break(())
+        } else {
+            self.index += 1;
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        let Some(ref mut pred) = self.pred else {
+            return ControlFlow::Break(());
+        };
+
+        let index = &mut self.index;
+        let cf = items.into_iter().try_for_each(|item| {
+            if pred(item) {
+                ControlFlow::Break(())
+            } else {
+                *index += 1;
+                ControlFlow::Continue(())
+            }
+        });
+
+        if cf.is_break() {
+            self.pred = None;
+        }
+        cf
+    }
+
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let _ = self.collect_many(items);
+        self.get()
+    }
+}
+
+impl<F> Debug for Position<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Position")
+            .field("position", &self.get())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || Position::new(|num| num > 0),
+            should_break_pred: |mut iter| iter.any(|num| num > 0),
+            pred: |mut iter, output, remaining| {
+                if iter.position(|num| num > 0) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -101,7 +101,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
@@ -122,7 +122,7 @@ mod proptests {
             pred: |iter, output, remaining| {
                 if iter.reduce(|a, b| a ^ b) != output {
                     Err(PredError::IncorrectOutput)
-                } else if remaining.ne([]) {
+                } else if remaining.count() != 0 {
                     Err(PredError::IncorrectIterConsumption)
                 } else {
                     Ok(())
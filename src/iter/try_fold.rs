@@ -12,6 +12,17 @@ use crate::collector::{Collector, CollectorBase, assert_collector};
 /// Currently, it only supports [`ControlFlow`] as the function’s return type.
 /// More types may be supported once the [`Try`](std::ops::Try) trait is stabilized.
 ///
+/// # Performance
+///
+/// [`collect_many()`](Collector::collect_many) and
+/// [`collect_then_finish()`](Collector::collect_then_finish) drive the iterator with
+/// [`try_for_each()`](Iterator::try_for_each), which is built on [`Iterator::try_fold()`].
+/// Hence, feeding an iterator into this collector iterates the same way
+/// as the equivalent `try_fold()`, stopping at the same item,
+/// and is expected to perform on par with it.
+///
+/// The `fold` benchmark compares the two.
+///
 /// # Examples
 ///
 /// ```
@@ -111,7 +122,7 @@ mod proptests {
     use proptest::prelude::*;
     use proptest::test_runner::TestCaseResult;
 
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     use super::*;
 
@@ -0,0 +1,142 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that calls a closure on each item
+/// as long as the closure returns [`Continue(())`](ControlFlow::Continue).
+///
+/// This collector corresponds to [`Iterator::try_for_each()`].
+/// It is useful for side-effecting consumers that may fail
+/// (e.g., sending to a closed connection) at the end of a chain,
+/// without defining a dedicated collector.
+///
+/// Currently, it only supports [`ControlFlow`] as the closure’s return type.
+/// More types may be supported once the [`Try`](std::ops::Try) trait is stabilized.
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use komadori::{prelude::*, iter::TryForEach};
+///
+/// let mut budget = 10;
+/// let mut nums = [4, 3, 5, 1].into_iter();
+///
+/// nums.by_ref().feed_into(TryForEach::new(|num| {
+///     budget -= num;
+///     if budget <= 0 {
+///         ControlFlow::Break(())
+///     } else {
+///         ControlFlow::Continue(())
+///     }
+/// }));
+///
+/// assert_eq!(budget, -2);
+/// assert_eq!(nums.next(), Some(1));
+/// ```
+#[derive(Clone)]
+pub struct TryForEach<F> {
+    f: F,
+}
+
+impl<F> TryForEach<F> {
+    /// Creates a new instance of this collector with a closure.
+    #[inline]
+    pub const fn new<T>(f: F) -> Self
+    where
+        F: FnMut(T) -> ControlFlow<()>,
+    {
+        assert_collector::<_, T>(Self { f })
+    }
+}
+
+impl<F> CollectorBase for TryForEach<F> {
+    type Output = ();
+
+    #[inline]
+    fn finish(self) -> Self::Output {}
+}
+
+impl<T, F> Collector<T> for TryForEach<F>
+where
+    F: FnMut(T) -> ControlFlow<()>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        (self.f)(item)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        items.into_iter().try_for_each(&mut self.f)
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = T>) -> Self::Output {
+        let _ = items.into_iter().try_for_each(self.f);
+    }
+}
+
+impl<F> Debug for TryForEach<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TryForEach").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::cell::RefCell;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..4_i32, ..=5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    // Stops after seeing a 0.
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        let seen = RefCell::new(vec![]);
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                seen.borrow_mut().clear();
+                TryForEach::new(|num| {
+                    seen.borrow_mut().push(num);
+                    if num == 0 {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                })
+            },
+            should_break_pred: |mut iter| iter.any(|num| num == 0),
+            pred: |iter, (), remaining| {
+                let stop = nums
+                    .iter()
+                    .position(|&num| num == 0)
+                    .map_or(nums.len(), |i| i + 1);
+
+                if iter.clone().take(stop).ne(seen.borrow().iter().copied()) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(stop).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
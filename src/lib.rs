@@ -192,6 +192,37 @@
 //! It is recommended to read the documentation of `collector` next
 //! if you want to delve into how collectors work.
 //!
+//! # Allocation
+//!
+//! Without the `alloc` feature, this crate does not link the [`alloc`] crate at all.
+//! Hence, every item available then never allocates by itself.
+//! This covers all adaptors in `collector` except `boxed()` and `windows()`,
+//! which need `alloc`, and `detach()`, `instrument()`, `take_until()`, `unique()`
+//! and `unique_by()`, which need `std`.
+//! It also covers the collectors in `array`, `cell`, `cmp`, `iter`, `machine`, `mem`, `num`,
+//! `ops`, `slice`, `sync`, `tuple` and `unit`, as well as those for the fixed-capacity
+//! collections of [`heapless`], [`arrayvec`] and [`tinyvec`].
+//! Items that need to allocate, such as `Spread` and `BoxCollector` in `collector`,
+//! or `iter::CountBy`, require `alloc` or `std`.
+//!
+//! Such a pipeline can run on targets without an allocator, with a slice as the buffer:
+//!
+//! ```
+//! use komadori::{prelude::*, cmp::{Max, Min}, slice::Fill};
+//!
+//! let mut buf = [0; 3];
+//! let ((filled, sum), (min, max)) = [2, 7, 1, 8]
+//!     .into_iter()
+//!     .feed_into(
+//!         Fill::new(&mut buf)
+//!             .tee(i32::adding())
+//!             .tee(Min::new().tee(Max::new())),
+//!     );
+//!
+//! assert_eq!(filled, [2, 7, 1]);
+//! assert_eq!((sum, min, max), (18, Some(1), Some(8)));
+//! ```
+//!
 //! # Features
 //!
 //! - **`alloc`** — Enables collectors and implementations for types in the
@@ -204,6 +235,49 @@
 //! - **`itertools`** — Enables collectors and adapters that resemble those
 //!   in the `itertools` crate.
 //!
+//! - **`heapless`** — Enables collectors for fixed-capacity collections in the
+//!   [`heapless`] crate, which work without an allocator.
+//!
+//! - **`im`** — Enables collectors for persistent collections in the
+//!   [`im`] crate. Implies `std`.
+//!
+//! - **`ropey`** — Enables collectors for [`Rope`]s in the
+//!   [`ropey`] crate. Implies `std`.
+//!
+//! - **`arrayvec`**, **`smallvec`**, **`tinyvec`** — Enable collectors for the vectors
+//!   in the [`arrayvec`], [`smallvec`] and [`tinyvec`] crates, respectively.
+//!   `smallvec` implies `alloc`.
+//!
+//! - **`embedded-io`** — Enables collectors for the byte sinks of the
+//!   [`embedded-io`] crate, which work without `std`.
+//!
+//! - **`defmt`** — Enables a collector that logs items with the
+//!   [`defmt`] framework for embedded targets.
+//!
+//! - **`digest`** — Enables collectors that hash bytes with the hash functions
+//!   implementing the traits of the [`digest`] crate (e.g., SHA-256).
+//!
+//! - **`crc32fast`** — Enables the `checksum` module, with collectors computing
+//!   CRC-32 (with the [`crc32fast`] crate) and Adler-32 checksums.
+//!
+//! - **`pyo3`** — Enables exposing collectors to Python with [`pyo3`]. Implies `std`.
+//!
+//! - **`wasm`** — Enables bridging JS iterables and collectors for WebAssembly with
+//!   [`wasm-bindgen`]. Implies `std`.
+//!
+//! - **`uuid`** — Enables collecting [`Uuid`]s into the collectors in `ids`.
+//!
+//! - **`serde`** — Implements `Serialize` and `Deserialize` of the [`serde`] crate
+//!   for stateful collectors such as [`Count`](iter::Count), `i32::adding()`,
+//!   [`Max`](cmp::Max), the collectors of collections, and adaptors that hold no closures,
+//!   so that the state of a pipeline can be checkpointed and resumed later.
+//!
+//! - **`derive`** — Enables the `Collector` derive macro, which turns a struct
+//!   of collectors into a collector producing a struct of outputs.
+//!
+//! - **`testkit`** — Enables the `testkit` module, a [`proptest`]-based harness
+//!   for checking custom [`Collector`] implementations. Implies `std`.
+//!
 //! - **`unstable`** — Enables experimental and unstable features.
 //!   Items gated behind this feature do **not** follow normal semver guarantees
 //!   and may change or be removed at any time.
@@ -221,14 +295,31 @@
 //! [`ControlFlow`]: core::ops::ControlFlow
 //! [`VecDeque`]: std::collections::VecDeque
 //! [`BTreeSet`]: std::collections::BTreeSet
+//! [`proptest`]: https://docs.rs/proptest
+//! [`heapless`]: https://docs.rs/heapless
+//! [`im`]: https://docs.rs/im
+//! [`ropey`]: https://docs.rs/ropey
+//! [`Rope`]: https://docs.rs/ropey/latest/ropey/struct.Rope.html
+//! [`Uuid`]: https://docs.rs/uuid/latest/uuid/struct.Uuid.html
+//! [`arrayvec`]: https://docs.rs/arrayvec
+//! [`smallvec`]: https://docs.rs/smallvec
+//! [`tinyvec`]: https://docs.rs/tinyvec
+//! [`embedded-io`]: https://docs.rs/embedded-io
+//! [`defmt`]: https://docs.rs/defmt
+//! [`digest`]: https://docs.rs/digest
+//! [`wasm-bindgen`]: https://docs.rs/wasm-bindgen
+//! [`pyo3`]: https://docs.rs/pyo3
+//! [`crc32fast`]: https://docs.rs/crc32fast
+//! [`serde`]: https://docs.rs/serde
 
 #![forbid(missing_docs)]
 #![cfg_attr(test, deny(deprecated))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(not(feature = "std"), no_std)]
+// Not `forbid`, since serde's derive macros allow it in the code they generate.
 #![cfg_attr(
     not(any(doc, all(feature = "alloc", not(feature = "std")))),
-    forbid(unused_extern_crates)
+    deny(unused_extern_crates)
 )]
 // To make doc examples in sync (prevent accidental deprecated items usage in doc).
 #![doc(test(attr(deny(deprecated))))]
@@ -241,26 +332,81 @@ extern crate core as std;
 
 // #[cfg(feature = "unstable")]
 // pub mod aggregate;
+pub mod array;
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+pub mod arrayvec;
+pub mod cell;
+#[cfg(feature = "crc32fast")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crc32fast")))]
+pub mod checksum;
 pub mod cmp;
 #[cfg(feature = "alloc")]
 pub mod collections;
 pub mod collector;
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+pub mod defmt;
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub mod digest;
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-io")))]
+pub mod embedded_io;
+pub mod fmt;
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub mod heapless;
+#[cfg(feature = "alloc")]
+pub mod ids;
+#[cfg(feature = "im")]
+#[cfg_attr(docsrs, doc(cfg(feature = "im")))]
+pub mod im;
+#[cfg(feature = "std")]
+pub mod io;
 pub mod iter;
+pub mod machine;
 pub mod mem;
+pub mod merge;
 pub mod num;
 pub mod ops;
 pub mod prelude;
+#[cfg(feature = "std")]
+pub mod process;
+#[cfg(feature = "pyo3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pyo3")))]
+pub mod pyo3;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "ropey")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ropey")))]
+pub mod ropey;
 pub mod slice;
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+pub mod smallvec;
+pub mod stats;
 #[cfg(feature = "alloc")]
 pub mod string;
-#[cfg(feature = "std")]
 pub mod sync;
+// The crate's own tests use it regardless of the feature.
+#[cfg(any(feature = "testkit", all(test, feature = "std")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testkit")))]
+pub mod testkit;
+#[cfg(feature = "alloc")]
+pub mod text;
+#[cfg(feature = "std")]
+pub mod time;
+#[cfg(feature = "tinyvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tinyvec")))]
+pub mod tinyvec;
+pub mod tuple;
 pub mod unit;
 #[cfg(feature = "alloc")]
 pub mod vec;
-
-#[cfg(all(test, feature = "std"))]
-mod test_utils;
+#[cfg(feature = "wasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+pub mod wasm;
 
 /// Introduces the [`#!\[feature = closure_lifetime_binder\]`] to help dealing with
 /// poor lifetime inference issues of the compiler while using collectors.
@@ -454,6 +600,93 @@ macro_rules! clb {
     };
 }
 
+/// Creates a collector that lets all the given collectors collect the same item,
+/// with a flat tuple as its [`Output`](crate::collector::CollectorBase::Output).
+///
+/// `tee_funnel!(c1, c2, ..., cn)` expands to
+/// `c1.tee_mut(c2)...tee_mut(cn-1).tee_funnel(cn)`, followed by a
+/// [`map_output()`](crate::collector::CollectorBase::map_output) that flattens
+/// the nested tuple `(((o1, o2), ...), on)` into `(o1, o2, ..., on)`.
+/// Hence, every collector but the last collects the mutable reference of the item,
+/// and the last one takes the ownership of it. This works even if the item
+/// does not implement [`Clone`].
+///
+/// The first argument must implement [`IntoCollectorBase`](crate::collector::IntoCollectorBase).
+/// Up to 12 collectors are supported.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{cmp::Max, prelude::*, clb_mut, tee_funnel};
+///
+/// let mut collector = tee_funnel!(
+///     String::new()
+///         .into_concat()
+///         .map(clb_mut!(|s: &mut String| -> &str { &s[..] })),
+///     Max::new().map(clb_mut!(|s: &mut String| -> usize { s.len() })),
+///     vec![],
+/// );
+///
+/// let strings = ["noble", "and", "singer"].map(String::from);
+/// assert!(collector.collect_many(strings).is_continue());
+///
+/// let (concat, max_len, string_vec) = collector.finish();
+///
+/// assert_eq!(concat, "nobleandsinger");
+/// assert_eq!(max_len, Some(6));
+/// assert_eq!(string_vec, ["noble", "and", "singer"]);
+/// ```
+///
+/// With two collectors, it is equivalent to [`tee_funnel()`](crate::collector::CollectorBase::tee_funnel).
+///
+/// ```
+/// use komadori::{iter::Count, prelude::*, tee_funnel};
+///
+/// let (count, nums) = [1, 2, 3]
+///     .into_iter()
+///     .feed_into(tee_funnel!(Count::new(), vec![]));
+///
+/// assert_eq!(count, 3);
+/// assert_eq!(nums, [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! tee_funnel {
+    (
+        @build [$acc:expr] [$pat:pat] [$($outs:ident)*] [$out:ident $($names:ident)*]
+        $last:expr $(,)?
+    ) => {
+        $crate::collector::CollectorBase::map_output(
+            $crate::collector::CollectorBase::tee_funnel($acc, $last),
+            |($pat, $out)| ($($outs,)* $out),
+        )
+    };
+
+    (
+        @build [$acc:expr] [$pat:pat] [$($outs:ident)*] [$out:ident $($names:ident)*]
+        $next:expr, $($rest:expr),+ $(,)?
+    ) => {
+        $crate::tee_funnel!(
+            @build
+            [$crate::collector::CollectorBase::tee_mut($acc, $next)]
+            [($pat, $out)]
+            [$($outs)* $out]
+            [$($names)*]
+            $($rest),+
+        )
+    };
+
+    ($first:expr, $($rest:expr),+ $(,)?) => {
+        $crate::tee_funnel!(
+            @build
+            [$crate::collector::IntoCollectorBase::into_collector($first)]
+            [__out0]
+            [__out0]
+            [__out1 __out2 __out3 __out4 __out5 __out6 __out7 __out8 __out9 __out10 __out11]
+            $($rest),+
+        )
+    };
+}
+
 #[cfg(feature = "unstable")]
 #[inline(always)]
 const fn assert_iterator<I: Iterator>(iterator: I) -> I {
@@ -0,0 +1,9 @@
+//! [`Collector`]s that drive state machines.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! [`Collector`]: crate::collector::Collector
+
+mod dfa;
+
+pub use dfa::*;
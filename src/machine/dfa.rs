@@ -0,0 +1,195 @@
+use std::{fmt::Debug, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase, assert_collector};
+
+/// A collector that drives a state machine with every collected item.
+/// Its [`Output`](CollectorBase::Output) is the final state
+/// and the number of items consumed.
+///
+/// The transition function receives the current state and an item, and returns the next state.
+/// Returning [`Continue(next)`](ControlFlow::Continue) moves to `next` and keeps accumulating.
+/// Returning [`Break(terminal)`](ControlFlow::Break) moves to `terminal`
+/// (such as an accepting or rejecting state) and stops accumulating.
+///
+/// # Examples
+///
+/// Validating the order of a handshake:
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use komadori::{prelude::*, machine::Dfa};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Handshake {
+///     Start,
+///     SynReceived,
+///     SynAckSent,
+///     Established,
+///     Rejected,
+/// }
+///
+/// fn transition(state: &Handshake, msg: &str) -> ControlFlow<Handshake, Handshake> {
+///     use Handshake::*;
+///
+///     match (state, msg) {
+///         (Start, "SYN") => ControlFlow::Continue(SynReceived),
+///         (SynReceived, "SYN-ACK") => ControlFlow::Continue(SynAckSent),
+///         (SynAckSent, "ACK") => ControlFlow::Break(Established),
+///         _ => ControlFlow::Break(Rejected),
+///     }
+/// }
+///
+/// let mut messages = ["SYN", "SYN-ACK", "ACK", "DATA"].into_iter();
+/// let (state, steps) = messages
+///     .by_ref()
+///     .feed_into(Dfa::new(Handshake::Start, transition));
+///
+/// assert_eq!(state, Handshake::Established);
+/// assert_eq!(steps, 3);
+/// // The rest is left untouched.
+/// assert_eq!(messages.next(), Some("DATA"));
+///
+/// let (state, steps) = ["SYN", "ACK"]
+///     .into_iter()
+///     .feed_into(Dfa::new(Handshake::Start, transition));
+///
+/// assert_eq!(state, Handshake::Rejected);
+/// assert_eq!(steps, 2);
+/// ```
+#[derive(Clone)]
+pub struct Dfa<S, F> {
+    state: S,
+    steps: usize,
+    f: F,
+}
+
+impl<S, F> Dfa<S, F> {
+    /// Creates a new instance of this collector with an initial state and a transition function.
+    #[inline]
+    pub const fn new<T>(initial: S, transition: F) -> Self
+    where
+        F: FnMut(&S, T) -> ControlFlow<S, S>,
+    {
+        assert_collector::<_, T>(Self {
+            state: initial,
+            steps: 0,
+            f: transition,
+        })
+    }
+
+    /// Returns the current state.
+    #[inline]
+    pub const fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns the number of items consumed so far.
+    #[inline]
+    pub const fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+impl<S, F> CollectorBase for Dfa<S, F> {
+    type Output = (S, usize);
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        (self.state, self.steps)
+    }
+}
+
+impl<S, T, F> Collector<T> for Dfa<S, F>
+where
+    F: FnMut(&S, T) -> ControlFlow<S, S>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.steps += 1;
+
+        match (self.f)(&self.state, item) {
+            ControlFlow::Continue(next) => {
+                self.state = next;
+                ControlFlow::Continue(())
+            }
+            ControlFlow::Break(terminal) => {
+                self.state = terminal;
+                ControlFlow::Break(())
+            }
+        }
+    }
+
+    // The default implementations for `collect_many` and `collect_then_finish` are sufficient.
+}
+
+impl<S: Debug, F> Debug for Dfa<S, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dfa")
+            .field("state", &self.state)
+            .field("steps", &self.steps)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<u8>(), ..=9),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    // Sums the items, and stops at the first item making the sum overflow.
+    fn transition(sum: &Option<u8>, num: u8) -> ControlFlow<Option<u8>, Option<u8>> {
+        let curr = sum.expect("the correct usage is not to collect again");
+
+        match curr.checked_add(num) {
+            Some(next) => ControlFlow::Continue(Some(next)),
+            None => ControlFlow::Break(None),
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<u8>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || Dfa::new(Some(0_u8), transition),
+            should_break_pred: |iter| iter_output(iter).0.is_none(),
+            pred: |mut iter, output, remaining| {
+                if iter_output(&mut iter) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn iter_output(iter: impl IntoIterator<Item = u8>) -> (Option<u8>, usize) {
+        let mut sum = Some(0_u8);
+        let mut steps = 0;
+
+        for num in iter {
+            steps += 1;
+            sum = sum.and_then(|sum| sum.checked_add(num));
+            if sum.is_none() {
+                break;
+            }
+        }
+
+        (sum, steps)
+    }
+}
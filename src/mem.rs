@@ -6,6 +6,12 @@
 
 mod dropping;
 mod forgetting;
+#[cfg(feature = "std")]
+mod variant_counts;
 
 pub use dropping::*;
 pub use forgetting::*;
+#[cfg(feature = "std")]
+// So that doc.rs doesn't put both "std" and "alloc" in feature flag.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use variant_counts::*;
@@ -60,7 +60,7 @@ impl<T> Collector<T> for Dropping {
 //     use proptest::prelude::*;
 //     use proptest::test_runner::TestCaseResult;
 
-//     use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+//     use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
 //     use super::*;
 
@@ -0,0 +1,154 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt::Debug,
+    mem::{Discriminant, discriminant},
+    ops::ControlFlow,
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that counts how many collected items there are for each enum variant.
+/// Its [`Output`](CollectorBase::Output) is [`HashMap<Discriminant<T>, usize>`],
+/// which only contains variants that have been seen.
+///
+/// Variants are told apart by [`discriminant()`], so fields are ignored.
+/// It accepts `T`, `&T` and `&mut T`.
+///
+/// # Examples
+///
+/// ```
+/// use std::mem::discriminant;
+/// use komadori::{prelude::*, mem::VariantCounts};
+///
+/// enum Message {
+///     Ping,
+///     Text(String),
+///     Quit { code: i32 },
+/// }
+///
+/// let messages = [
+///     Message::Text("hi".to_owned()),
+///     Message::Ping,
+///     Message::Text("bye".to_owned()),
+///     Message::Quit { code: 0 },
+/// ];
+///
+/// let counts = messages.iter().feed_into(VariantCounts::new());
+///
+/// assert_eq!(counts.len(), 3);
+/// assert_eq!(counts[&discriminant(&Message::Ping)], 1);
+/// assert_eq!(counts[&discriminant(&Message::Text(String::new()))], 2);
+/// assert_eq!(counts[&discriminant(&Message::Quit { code: 1 })], 1);
+/// ```
+pub struct VariantCounts<T> {
+    counts: HashMap<Discriminant<T>, usize>,
+}
+
+impl<T> VariantCounts<T> {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T> CollectorBase for VariantCounts<T> {
+    type Output = HashMap<Discriminant<T>, usize>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.counts
+    }
+}
+
+impl<T, U> Collector<U> for VariantCounts<T>
+where
+    U: Borrow<T>,
+{
+    #[inline]
+    fn collect(&mut self, item: U) -> ControlFlow<()> {
+        *self.counts.entry(discriminant(item.borrow())).or_insert(0) += 1;
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T> Default for VariantCounts<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for VariantCounts<T> {
+    fn clone(&self) -> Self {
+        Self {
+            counts: self.counts.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.counts.clone_from(&source.counts);
+    }
+}
+
+impl<T> Debug for VariantCounts<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VariantCounts")
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::mem::discriminant;
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use super::VariantCounts;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<Option<i32>>(), ..=5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<Option<i32>>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter(),
+            collector_factory: VariantCounts::<Option<i32>>::new,
+            should_break_pred: |_| false,
+            pred: |mut iter, output, remaining| {
+                let (some, none) = iter.by_ref().fold((0, 0), |(some, none), num| match num {
+                    Some(_) => (some + 1, none),
+                    None => (some, none + 1),
+                });
+
+                let expected = [(Some(0), some), (None, none)]
+                    .into_iter()
+                    .filter(|&(_, count)| count > 0)
+                    .map(|(variant, count)| (discriminant(&variant), count))
+                    .collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
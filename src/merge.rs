@@ -0,0 +1,333 @@
+//! Merging of partial outputs.
+//!
+//! When items are split into shards, e.g., one per thread, each shard is fed into
+//! its own collector and produces a partial output. [`Merge`] combines those
+//! partial outputs into the output for all items, and [`MergeOutputs`] does so
+//! for any number of them.
+//!
+//! Outputs of combined collectors are merged structurally: tuples element by element,
+//! and maps value by value for each key.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::thread;
+//! use komadori::{prelude::*, iter::Count, merge::MergeOutputs};
+//!
+//! let shards = [vec![1, 2, 3], vec![4, 5], vec![6]];
+//!
+//! let (count, (sum, evens)) = thread::scope(|s| {
+//!     let handles: Vec<_> = shards
+//!         .iter()
+//!         .map(|shard| {
+//!             s.spawn(|| {
+//!                 shard.iter().copied().feed_into(Count::new().tee(
+//!                     i32::adding().tee(vec![].into_collector().filter(|&num| num % 2 == 0)),
+//!                 ))
+//!             })
+//!         })
+//!         .collect();
+//!
+//!     handles
+//!         .into_iter()
+//!         .map(|handle| handle.join().unwrap())
+//!         .feed_into(MergeOutputs::new())
+//!         .unwrap()
+//! });
+//!
+//! assert_eq!(count, 6);
+//! assert_eq!(sum, 21);
+//! assert_eq!(evens, [2, 4, 6]);
+//! ```
+
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+#[cfg(feature = "std")]
+use std::{
+    collections::{
+        BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque, btree_map, hash_map,
+    },
+    hash::{BuildHasher, Hash},
+};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+// Hashtables are not in `alloc`.
+use alloc::{
+    collections::{BTreeMap, BTreeSet, LinkedList, VecDeque, btree_map},
+    string::String,
+    vec::Vec,
+};
+
+/// A partial output that can absorb another one.
+///
+/// Merging the partial outputs of two shards must give the same output
+/// as feeding both shards, in order, into one collector.
+///
+/// Numbers are merged by addition, which suits outputs such as counts and sums.
+/// Outputs merged differently, such as that of [`Max`](crate::cmp::Max),
+/// need to be merged by hand or wrapped in a type implementing this trait.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::merge::Merge;
+///
+/// let mut output = (3, vec!['a', 'b']);
+/// output.merge((2, vec!['c']));
+///
+/// assert_eq!(output, (5, vec!['a', 'b', 'c']));
+/// ```
+pub trait Merge {
+    /// Merges the partial output that comes after this one.
+    fn merge(&mut self, other: Self);
+}
+
+macro_rules! add_impl {
+    ($($ty:ty)*) => {
+        $(
+            impl Merge for $ty {
+                #[inline]
+                fn merge(&mut self, other: Self) {
+                    *self += other;
+                }
+            }
+        )*
+    };
+}
+
+add_impl!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl Merge for () {
+    #[inline]
+    fn merge(&mut self, _other: Self) {}
+}
+
+/// `None` is the output of an empty shard.
+impl<T: Merge> Merge for Option<T> {
+    #[inline]
+    fn merge(&mut self, other: Self) {
+        match (self.as_mut(), other) {
+            (Some(this), Some(other)) => this.merge(other),
+            (None, other @ Some(_)) => *self = other,
+            (_, None) => {}
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Merge for Vec<T> {
+    #[inline]
+    fn merge(&mut self, mut other: Self) {
+        self.append(&mut other);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Merge for VecDeque<T> {
+    #[inline]
+    fn merge(&mut self, mut other: Self) {
+        self.append(&mut other);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Merge for LinkedList<T> {
+    #[inline]
+    fn merge(&mut self, mut other: Self) {
+        self.append(&mut other);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Merge for String {
+    #[inline]
+    fn merge(&mut self, other: Self) {
+        self.push_str(&other);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Ord> Merge for BTreeSet<T> {
+    #[inline]
+    fn merge(&mut self, mut other: Self) {
+        self.append(&mut other);
+    }
+}
+
+/// Values with the same key are merged.
+#[cfg(feature = "alloc")]
+impl<K: Ord, V: Merge> Merge for BTreeMap<K, V> {
+    fn merge(&mut self, other: Self) {
+        for (key, value) in other {
+            match self.entry(key) {
+                btree_map::Entry::Occupied(mut entry) => entry.get_mut().merge(value),
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Eq + Hash, S: BuildHasher> Merge for HashSet<T, S> {
+    #[inline]
+    fn merge(&mut self, other: Self) {
+        self.extend(other);
+    }
+}
+
+/// Values with the same key are merged.
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V: Merge, S: BuildHasher> Merge for HashMap<K, V, S> {
+    fn merge(&mut self, other: Self) {
+        for (key, value) in other {
+            match self.entry(key) {
+                hash_map::Entry::Occupied(mut entry) => entry.get_mut().merge(value),
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($idx:tt $ts:ident),*) => {
+        impl<$($ts: Merge),*> Merge for ($($ts,)*) {
+            #[inline]
+            fn merge(&mut self, other: Self) {
+                $(self.$idx.merge(other.$idx);)*
+            }
+        }
+    };
+}
+
+tuple_impl!(0 T0);
+tuple_impl!(0 T0, 1 T1);
+tuple_impl!(0 T0, 1 T1, 2 T2);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10);
+tuple_impl!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11);
+
+/// A collector that merges partial outputs, in order, with [`Merge`].
+///
+/// Its [`Output`](CollectorBase::Output) is the merged output,
+/// or `None` if no partial outputs were collected.
+///
+/// See the [module-level documentation](self) for more.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, merge::MergeOutputs};
+///
+/// let merged = [(1, "ab".to_owned()), (2, "c".to_owned())]
+///     .into_iter()
+///     .feed_into(MergeOutputs::new());
+///
+/// assert_eq!(merged, Some((3, "abc".to_owned())));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MergeOutputs<T> {
+    merged: Option<T>,
+}
+
+impl<T> MergeOutputs<T>
+where
+    T: Merge,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { merged: None }
+    }
+}
+
+impl<T> Default for MergeOutputs<T>
+where
+    T: Merge,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for MergeOutputs<T> {
+    type Output = Option<T>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.merged
+    }
+}
+
+impl<T> Collector<T> for MergeOutputs<T>
+where
+    T: Merge,
+{
+    #[inline]
+    fn collect(&mut self, output: T) -> ControlFlow<()> {
+        match &mut self.merged {
+            Some(merged) => merged.merge(output),
+            None => self.merged = Some(output),
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            shards in propvec(propvec(any::<i8>(), ..=3), ..=4),
+        ) {
+            all_collect_methods_impl(shards)?;
+        }
+    }
+
+    fn all_collect_methods_impl(shards: Vec<Vec<i8>>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || {
+                shards
+                    .iter()
+                    .map(|shard| (shard.len(), (shard.clone(), Some(shard.len()))))
+            },
+            collector_factory: MergeOutputs::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                let nums: Vec<_> = shards.iter().flatten().copied().collect();
+                let expected =
+                    (!shards.is_empty()).then(|| (nums.len(), (nums.clone(), Some(nums.len()))));
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,13 +1,38 @@
 //! Numeric-related collectors.
 //!
 //! This module provides [`Adding`](crate::ops::Adding) and [`Muling`](crate::ops::Muling)
-//! collectors for numeric types in the standard library.
+//! collectors for numeric types in the standard library,
+//! as well as other collectors for numbers such as [`WeightedSum`].
 //!
 //! This module corresponds to [`std::num`].
 
+mod and_all;
+mod checked_sum;
+mod gcd;
+mod kahan_sum;
+mod lcm;
+mod neumaier_sum;
+mod or_all;
+mod saturating_sum;
+mod weighted_sum;
+mod wrapping_sum;
+mod xor_all;
+
+pub use and_all::*;
+pub use checked_sum::*;
+pub use gcd::*;
+pub use kahan_sum::*;
+pub use lcm::*;
+pub use neumaier_sum::*;
+pub use or_all::*;
+pub use saturating_sum::*;
+pub use weighted_sum::*;
+pub use wrapping_sum::*;
+pub use xor_all::*;
+
 use std::{num::Wrapping, ops::ControlFlow};
 
-use crate::collector::{Collector, CollectorBase, assert_collector};
+use crate::collector::{Collector, CollectorBase, SnapshotCollector, assert_collector};
 
 /// A collector that adds every collected number.
 /// Its [`Output`](CollectorBase::Output) is the type
@@ -30,7 +55,23 @@ use crate::collector::{Collector, CollectorBase, assert_collector};
 ///
 /// assert_eq!(sum.finish(), 6);
 /// ```
+///
+/// Slices are accepted too, each added up at once.
+/// For integers, this lets the addition be vectorized.
+///
+/// ```
+/// use komadori::prelude::*;
+///
+/// let batches: [&[i32]; 3] = [&[1, 2, 3], &[], &[4, 5]];
+///
+/// assert_eq!(batches.into_iter().feed_into(i32::adding()), 15);
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Adding<Num>(Num);
 
 /// A collector that adds every collected number.
@@ -54,7 +95,22 @@ pub struct Adding<Num>(Num);
 ///
 /// assert_eq!(product.finish(), -6);
 /// ```
+///
+/// Slices are accepted too, each multiplied at once.
+///
+/// ```
+/// use komadori::prelude::*;
+///
+/// let batches: [&[i32]; 3] = [&[1, 2, 3], &[], &[4, 5]];
+///
+/// assert_eq!(batches.into_iter().feed_into(i32::muling()), 120);
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Muling<Num>(Num);
 
 macro_rules! prim_adding_impl {
@@ -86,6 +142,13 @@ macro_rules! prim_adding_impl {
             }
         }
 
+        impl SnapshotCollector for Adding<$pri_ty> {
+            #[inline]
+            fn peek_output(&self) -> Self::Output {
+                self.0
+            }
+        }
+
         impl Collector<$pri_ty> for Adding<$pri_ty> {
             #[inline]
             fn collect(&mut self, item: $pri_ty) -> ControlFlow<()> {
@@ -163,6 +226,70 @@ macro_rules! prim_adding_impl {
                 self.0
             }
         }
+
+        impl<'a> Collector<&'a [$pri_ty]> for Adding<$pri_ty> {
+            #[inline]
+            fn collect(&mut self, nums: &'a [$pri_ty]) -> ControlFlow<()> {
+                self.0 += nums.iter().sum::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                slices: impl IntoIterator<Item = &'a [$pri_ty]>,
+            ) -> ControlFlow<()> {
+                self.0 += slices
+                    .into_iter()
+                    .map(|nums| nums.iter().sum::<$pri_ty>())
+                    .sum::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_then_finish(
+                mut self,
+                slices: impl IntoIterator<Item = &'a [$pri_ty]>,
+            ) -> Self::Output {
+                self.0 += slices
+                    .into_iter()
+                    .map(|nums| nums.iter().sum::<$pri_ty>())
+                    .sum::<$pri_ty>();
+                self.0
+            }
+        }
+
+        impl<'a> Collector<&'a mut [$pri_ty]> for Adding<$pri_ty> {
+            #[inline]
+            fn collect(&mut self, nums: &'a mut [$pri_ty]) -> ControlFlow<()> {
+                self.0 += nums.iter().sum::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                slices: impl IntoIterator<Item = &'a mut [$pri_ty]>,
+            ) -> ControlFlow<()> {
+                self.0 += slices
+                    .into_iter()
+                    .map(|nums| nums.iter().sum::<$pri_ty>())
+                    .sum::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_then_finish(
+                mut self,
+                slices: impl IntoIterator<Item = &'a mut [$pri_ty]>,
+            ) -> Self::Output {
+                self.0 += slices
+                    .into_iter()
+                    .map(|nums| nums.iter().sum::<$pri_ty>())
+                    .sum::<$pri_ty>();
+                self.0
+            }
+        }
     };
 }
 
@@ -195,6 +322,13 @@ macro_rules! prim_muling_impl {
             }
         }
 
+        impl SnapshotCollector for Muling<$pri_ty> {
+            #[inline]
+            fn peek_output(&self) -> Self::Output {
+                self.0
+            }
+        }
+
         impl Collector<$pri_ty> for Muling<$pri_ty> {
             #[inline]
             fn collect(&mut self, item: $pri_ty) -> ControlFlow<()> {
@@ -272,6 +406,70 @@ macro_rules! prim_muling_impl {
                 self.0
             }
         }
+
+        impl<'a> Collector<&'a [$pri_ty]> for Muling<$pri_ty> {
+            #[inline]
+            fn collect(&mut self, nums: &'a [$pri_ty]) -> ControlFlow<()> {
+                self.0 *= nums.iter().product::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                slices: impl IntoIterator<Item = &'a [$pri_ty]>,
+            ) -> ControlFlow<()> {
+                self.0 *= slices
+                    .into_iter()
+                    .map(|nums| nums.iter().product::<$pri_ty>())
+                    .product::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_then_finish(
+                mut self,
+                slices: impl IntoIterator<Item = &'a [$pri_ty]>,
+            ) -> Self::Output {
+                self.0 *= slices
+                    .into_iter()
+                    .map(|nums| nums.iter().product::<$pri_ty>())
+                    .product::<$pri_ty>();
+                self.0
+            }
+        }
+
+        impl<'a> Collector<&'a mut [$pri_ty]> for Muling<$pri_ty> {
+            #[inline]
+            fn collect(&mut self, nums: &'a mut [$pri_ty]) -> ControlFlow<()> {
+                self.0 *= nums.iter().product::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                slices: impl IntoIterator<Item = &'a mut [$pri_ty]>,
+            ) -> ControlFlow<()> {
+                self.0 *= slices
+                    .into_iter()
+                    .map(|nums| nums.iter().product::<$pri_ty>())
+                    .product::<$pri_ty>();
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_then_finish(
+                mut self,
+                slices: impl IntoIterator<Item = &'a mut [$pri_ty]>,
+            ) -> Self::Output {
+                self.0 *= slices
+                    .into_iter()
+                    .map(|nums| nums.iter().product::<$pri_ty>())
+                    .product::<$pri_ty>();
+                self.0
+            }
+        }
     };
 }
 
@@ -305,7 +503,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{BasicCollectorTester, CollectorTesterExt, PredError};
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
 
     proptest! {
         #[test]
@@ -314,6 +512,14 @@ mod proptests {
         ) {
             all_collect_methods_adding_int_impl(nums)?;
         }
+
+        #[test]
+        fn all_collect_methods_adding_int_slices(
+            nums in propvec(any::<i16>().prop_map_into::<i32>(), ..8),
+            chunk_size in 1..=3_usize,
+        ) {
+            all_collect_methods_adding_int_slices_impl(nums, chunk_size)?;
+        }
     }
 
     fn all_collect_methods_adding_int_impl(nums: Vec<i32>) -> TestCaseResult {
@@ -334,6 +540,35 @@ mod proptests {
         .test_collector()
     }
 
+    fn all_collect_methods_adding_int_slices_impl(
+        mut nums: Vec<i32>,
+        chunk_size: usize,
+    ) -> TestCaseResult {
+        let sum: i32 = nums.iter().sum();
+
+        BasicCollectorTester {
+            iter_factory: || nums.chunks(chunk_size),
+            collector_factory: || i32::adding(),
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if output != sum {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()?;
+
+        // By mutable reference.
+        let chunks: Vec<_> = nums.chunks_mut(chunk_size).collect();
+        prop_assert_eq!(chunks.into_iter().feed_into(i32::adding()), sum);
+
+        Ok(())
+    }
+
     proptest! {
         #[test]
         fn all_collect_methods_muling_int(
@@ -341,6 +576,14 @@ mod proptests {
         ) {
             all_collect_methods_muling_int_impl(nums)?;
         }
+
+        #[test]
+        fn all_collect_methods_muling_int_slices(
+            nums in propvec(any::<i8>().prop_map_into::<i64>(), ..8),
+            chunk_size in 1..=3_usize,
+        ) {
+            all_collect_methods_muling_int_slices_impl(nums, chunk_size)?;
+        }
     }
 
     fn all_collect_methods_muling_int_impl(nums: Vec<i64>) -> TestCaseResult {
@@ -360,4 +603,33 @@ mod proptests {
         }
         .test_collector()
     }
+
+    fn all_collect_methods_muling_int_slices_impl(
+        mut nums: Vec<i64>,
+        chunk_size: usize,
+    ) -> TestCaseResult {
+        let product: i64 = nums.iter().product();
+
+        BasicCollectorTester {
+            iter_factory: || nums.chunks(chunk_size),
+            collector_factory: || i64::muling(),
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if output != product {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()?;
+
+        // By mutable reference.
+        let chunks: Vec<_> = nums.chunks_mut(chunk_size).collect();
+        prop_assert_eq!(chunks.into_iter().feed_into(i64::muling()), product);
+
+        Ok(())
+    }
 }
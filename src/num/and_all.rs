@@ -0,0 +1,163 @@
+use std::ops::{BitAndAssign, ControlFlow, Not};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that computes the bitwise AND of every collected number.
+/// Its [`Output`](CollectorBase::Output) is the result,
+/// which has all bits set if it has not collected any numbers.
+///
+/// It stops once the result reaches 0, since it can no longer change.
+///
+/// This collector corresponds to `iter.fold(!0, |acc, num| acc & num)`.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::AndAll};
+///
+/// // The permissions granted by every role.
+/// let roles: [u8; 3] = [0b1110, 0b0111, 0b1111];
+///
+/// assert_eq!(roles.into_iter().feed_into(AndAll::new()), 0b0110);
+/// ```
+///
+/// It stops as soon as the result is 0.
+///
+/// ```
+/// use komadori::{prelude::*, num::AndAll};
+///
+/// let mut flags = [0b01_u8, 0b10, 0b11].into_iter();
+///
+/// assert_eq!(flags.by_ref().feed_into(AndAll::new()), 0);
+/// assert_eq!(flags.next(), Some(0b11));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AndAll<N> {
+    acc: N,
+}
+
+impl<N> AndAll<N>
+where
+    N: Default + Not<Output = N>,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self { acc: !N::default() }
+    }
+}
+
+impl<N> Default for AndAll<N>
+where
+    N: Default + Not<Output = N>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> CollectorBase for AndAll<N>
+where
+    N: Default + PartialEq,
+{
+    type Output = N;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.acc
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.acc == N::default() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<N> Collector<N> for AndAll<N>
+where
+    N: Default + PartialEq + BitAndAssign,
+{
+    #[inline]
+    fn collect(&mut self, num: N) -> ControlFlow<()> {
+        self.acc &= num;
+        self.break_hint()
+    }
+
+    fn collect_many(&mut self, nums: impl IntoIterator<Item = N>) -> ControlFlow<()> {
+        self.break_hint()?;
+
+        nums.into_iter().try_for_each(|num| {
+            self.acc &= num;
+            self.break_hint()
+        })
+    }
+}
+
+impl<'a, N> Collector<&'a N> for AndAll<N>
+where
+    N: Default + PartialEq + BitAndAssign + Copy,
+{
+    #[inline]
+    fn collect(&mut self, &num: &'a N) -> ControlFlow<()> {
+        self.collect(num)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, nums: impl IntoIterator<Item = &'a N>) -> ControlFlow<()> {
+        self.collect_many(nums.into_iter().copied())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<u8>(), ..5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<u8>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: AndAll::new,
+            should_break_pred: |iter| zero_at_index(iter).is_some(),
+            pred: |iter, output, remaining| {
+                let consumed = zero_at_index(iter.clone()).map_or(iter.len(), |index| index + 1);
+
+                if iter.clone().take(consumed).fold(!0, |acc, num| acc & num) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The index of the item making the result 0, if any.
+    fn zero_at_index(mut iter: impl Iterator<Item = u8>) -> Option<usize> {
+        let mut acc = !0;
+        iter.position(|num| {
+            acc &= num;
+            acc == 0
+        })
+    }
+}
@@ -0,0 +1,172 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that adds every collected integer, stopping on overflow.
+///
+/// Its [`Output`](CollectorBase::Output) is `Some` containing the sum,
+/// or `None` if the sum has overflowed.
+/// Once the sum overflows, it stops accumulating and no more items are consumed.
+///
+/// This collector corresponds to `iter.try_fold(0, |sum, num| sum.checked_add(num))`.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::CheckedSum};
+///
+/// let sizes: [u32; 3] = [1024, 2048, 4096];
+/// assert_eq!(sizes.into_iter().feed_into(CheckedSum::new()), Some(7168));
+///
+/// // Untrusted sizes that would overflow.
+/// let mut sizes = [u32::MAX, 1, 2].into_iter();
+/// assert_eq!(sizes.by_ref().feed_into(CheckedSum::new()), None);
+///
+/// // It stops right at the overflowing item.
+/// assert_eq!(sizes.next(), Some(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CheckedSum<N> {
+    sum: Option<N>,
+}
+
+impl<N> CheckedSum<N>
+where
+    N: Default,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sum: Some(N::default()),
+        }
+    }
+}
+
+impl<N> Default for CheckedSum<N>
+where
+    N: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> CollectorBase for CheckedSum<N> {
+    type Output = Option<N>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.sum
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.sum.is_some() {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+}
+
+macro_rules! int_impls {
+    ($($int_ty:ty)*) => {$(
+        impl CheckedSum<$int_ty> {
+            #[inline]
+            fn add_all(&mut self, nums: impl IntoIterator<Item = $int_ty>) -> ControlFlow<()> {
+                let Some(sum) = self.sum.take() else {
+                    return ControlFlow::Break(());
+                };
+
+                self.sum = nums.into_iter().try_fold(sum, <$int_ty>::checked_add);
+                self.break_hint()
+            }
+        }
+
+        impl Collector<$int_ty> for CheckedSum<$int_ty> {
+            #[inline]
+            fn collect(&mut self, num: $int_ty) -> ControlFlow<()> {
+                self.add_all([num])
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = $int_ty>,
+            ) -> ControlFlow<()> {
+                self.add_all(nums)
+            }
+        }
+
+        impl<'a> Collector<&'a $int_ty> for CheckedSum<$int_ty> {
+            #[inline]
+            fn collect(&mut self, &num: &'a $int_ty) -> ControlFlow<()> {
+                self.add_all([num])
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = &'a $int_ty>,
+            ) -> ControlFlow<()> {
+                self.add_all(nums.into_iter().copied())
+            }
+        }
+    )*};
+}
+
+int_impls!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i8>(), ..6),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i8>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: CheckedSum::new,
+            should_break_pred: |iter| overflow_at(iter).is_some(),
+            pred: |iter, output, remaining| {
+                let (expected, consumed) = match overflow_at(iter.clone()) {
+                    Some(index) => (None, index + 1),
+                    None => (Some(iter.clone().sum()), iter.len()),
+                };
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The index of the item making the sum overflow, if any.
+    fn overflow_at(mut iter: impl Iterator<Item = i8>) -> Option<usize> {
+        let mut sum = Some(0_i8);
+        iter.position(|num| {
+            sum = sum.and_then(|sum| sum.checked_add(num));
+            sum.is_none()
+        })
+    }
+}
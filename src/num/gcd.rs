@@ -0,0 +1,189 @@
+use std::ops::{ControlFlow, Rem};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that computes the greatest common divisor of every collected unsigned integer.
+/// Its [`Output`](CollectorBase::Output) is the result,
+/// which is 0 if it has not collected any numbers, or all of them are 0.
+///
+/// It stops once the result reaches 1, since it can no longer change.
+///
+/// For signed integers, collect their [`unsigned_abs()`](i32::unsigned_abs) instead.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::Gcd};
+///
+/// let frame_sizes: [u32; 3] = [1920, 1280, 800];
+///
+/// assert_eq!(frame_sizes.into_iter().feed_into(Gcd::new()), 160);
+/// ```
+///
+/// It stops as soon as the result is 1.
+///
+/// ```
+/// use komadori::{prelude::*, num::Gcd};
+///
+/// let mut nums = [4_u8, 6, 9, 12].into_iter();
+///
+/// assert_eq!(nums.by_ref().feed_into(Gcd::new()), 1);
+/// assert_eq!(nums.next(), Some(12));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Gcd<N> {
+    acc: N,
+}
+
+impl<N> Gcd<N>
+where
+    N: From<u8>,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self { acc: N::from(0) }
+    }
+}
+
+impl<N> Default for Gcd<N>
+where
+    N: From<u8>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> CollectorBase for Gcd<N>
+where
+    N: From<u8> + PartialEq,
+{
+    type Output = N;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.acc
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.acc == N::from(1) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+// Euclid's algorithm.
+pub(super) fn gcd<N>(mut a: N, mut b: N) -> N
+where
+    N: From<u8> + PartialEq + Rem<Output = N> + Copy,
+{
+    while b != N::from(0) {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+macro_rules! uint_impls {
+    ($($uint_ty:ty)*) => {$(
+        impl Collector<$uint_ty> for Gcd<$uint_ty> {
+            #[inline]
+            fn collect(&mut self, num: $uint_ty) -> ControlFlow<()> {
+                self.acc = gcd(self.acc, num);
+                self.break_hint()
+            }
+
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = $uint_ty>,
+            ) -> ControlFlow<()> {
+                self.break_hint()?;
+
+                nums.into_iter().try_for_each(|num| self.collect(num))
+            }
+        }
+
+        impl<'a> Collector<&'a $uint_ty> for Gcd<$uint_ty> {
+            #[inline]
+            fn collect(&mut self, &num: &'a $uint_ty) -> ControlFlow<()> {
+                self.collect(num)
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = &'a $uint_ty>,
+            ) -> ControlFlow<()> {
+                self.collect_many(nums.into_iter().copied())
+            }
+        }
+    )*};
+}
+
+uint_impls!(usize u8 u16 u32 u64 u128);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec((0..6_u32).prop_map(|num| num * 6), ..4),
+            coprime_at in proptest::option::of(..4_usize),
+        ) {
+            all_collect_methods_impl(nums, coprime_at)?;
+        }
+    }
+
+    fn all_collect_methods_impl(mut nums: Vec<u32>, coprime_at: Option<usize>) -> TestCaseResult {
+        // The multiples of 6 alone never reach 1, so a 5 is put in somewhere.
+        if let Some(index) = coprime_at.filter(|&index| index < nums.len()) {
+            nums[index] = 5;
+        }
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: Gcd::new,
+            should_break_pred: |iter| one_at(iter).is_some(),
+            pred: |iter, output, remaining| {
+                let consumed = one_at(iter.clone()).map_or(iter.len(), |index| index + 1);
+
+                if iter.clone().take(consumed).fold(0, naive_gcd) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The index of the item making the result 1, if any.
+    fn one_at(mut iter: impl Iterator<Item = u32>) -> Option<usize> {
+        let mut acc = 0;
+        iter.position(|num| {
+            acc = naive_gcd(acc, num);
+            acc == 1
+        })
+    }
+
+    fn naive_gcd(a: u32, b: u32) -> u32 {
+        (1..=a.max(b))
+            .rev()
+            .find(|&d| a.is_multiple_of(d) && b.is_multiple_of(d))
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,199 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that adds every collected floating point number
+/// with Kahan's compensated summation.
+/// Its [`Output`](CollectorBase::Output) is the sum.
+///
+/// The rounding error of each addition is carried over to the next one,
+/// so the error of the sum does not grow with the number of items,
+/// unlike [`f64::adding()`](crate::ops::Adding::adding).
+/// However, it still loses precision when adding numbers of very different magnitudes
+/// with opposite signs. See [`NeumaierSum`](super::NeumaierSum) for that case.
+///
+/// Besides numbers and references to them, it collects slices of numbers.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::KahanSum};
+///
+/// let tenths = || std::iter::repeat_n(0.1, 10);
+///
+/// assert_ne!(tenths().feed_into(f64::adding()), 1.0);
+/// assert_eq!(tenths().feed_into(KahanSum::new()), 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KahanSum<F> {
+    sum: F,
+    compensation: F,
+}
+
+impl<F> KahanSum<F>
+where
+    F: From<f32>,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        // The "additive identity" of floating point number is -0.0, not 0.0.
+        Self {
+            sum: F::from(-0.0),
+            compensation: F::from(0.0),
+        }
+    }
+}
+
+impl<F> Default for KahanSum<F>
+where
+    F: From<f32>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> CollectorBase for KahanSum<F> {
+    type Output = F;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.sum
+    }
+}
+
+macro_rules! float_impls {
+    ($($float_ty:ty)*) => {$(
+        impl KahanSum<$float_ty> {
+            #[inline]
+            fn add(&mut self, num: $float_ty) {
+                let y = num - self.compensation;
+                let t = self.sum + y;
+                self.compensation = (t - self.sum) - y;
+                self.sum = t;
+            }
+
+            #[inline]
+            fn add_all(&mut self, nums: impl IntoIterator<Item = $float_ty>) {
+                // Accumulating in a local lets the state stay in registers.
+                let mut local = self.clone();
+                nums.into_iter().for_each(|num| local.add(num));
+                *self = local;
+            }
+        }
+
+        impl Collector<$float_ty> for KahanSum<$float_ty> {
+            #[inline]
+            fn collect(&mut self, num: $float_ty) -> ControlFlow<()> {
+                self.add(num);
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = $float_ty>,
+            ) -> ControlFlow<()> {
+                self.add_all(nums);
+                ControlFlow::Continue(())
+            }
+        }
+
+        impl<'a> Collector<&'a $float_ty> for KahanSum<$float_ty> {
+            #[inline]
+            fn collect(&mut self, &num: &'a $float_ty) -> ControlFlow<()> {
+                self.add(num);
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = &'a $float_ty>,
+            ) -> ControlFlow<()> {
+                self.add_all(nums.into_iter().copied());
+                ControlFlow::Continue(())
+            }
+        }
+
+        impl<'a> Collector<&'a [$float_ty]> for KahanSum<$float_ty> {
+            #[inline]
+            fn collect(&mut self, nums: &'a [$float_ty]) -> ControlFlow<()> {
+                self.add_all(nums.iter().copied());
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                slices: impl IntoIterator<Item = &'a [$float_ty]>,
+            ) -> ControlFlow<()> {
+                self.add_all(slices.into_iter().flatten().copied());
+                ControlFlow::Continue(())
+            }
+        }
+    )*};
+}
+
+float_impls!(f32 f64);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i16>(), ..6),
+            chunk_size in 1..=3_usize,
+        ) {
+            all_collect_methods_impl(nums, chunk_size)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i16>, chunk_size: usize) -> TestCaseResult {
+        let nums: Vec<_> = nums.into_iter().map(f64::from).collect();
+        // Exact, since the numbers are small integers.
+        let sum: f64 = nums.iter().sum();
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: KahanSum::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if output != sum {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()?;
+
+        BasicCollectorTester {
+            iter_factory: || nums.chunks(chunk_size),
+            collector_factory: KahanSum::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if output != sum {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
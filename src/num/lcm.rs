@@ -0,0 +1,182 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+use super::gcd::gcd;
+
+/// A collector that computes the least common multiple of every collected unsigned integer,
+/// stopping on overflow.
+///
+/// Its [`Output`](CollectorBase::Output) is `Some` containing the result,
+/// or `None` if the result has overflowed.
+/// The result is 1 if it has not collected any numbers,
+/// and 0 if any of them is 0.
+///
+/// It stops once the result reaches 0 or overflows, since it can no longer change.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::Lcm};
+///
+/// // Periods of some jobs, in minutes.
+/// let periods: [u32; 3] = [4, 6, 10];
+///
+/// // When all of them run at the same time again.
+/// assert_eq!(periods.into_iter().feed_into(Lcm::new()), Some(60));
+///
+/// assert_eq!([200_u8, 3].into_iter().feed_into(Lcm::new()), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lcm<N> {
+    acc: Option<N>,
+}
+
+impl<N> Lcm<N>
+where
+    N: From<u8>,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            acc: Some(N::from(1)),
+        }
+    }
+}
+
+impl<N> Default for Lcm<N>
+where
+    N: From<u8>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> CollectorBase for Lcm<N>
+where
+    N: From<u8> + PartialEq,
+{
+    type Output = Option<N>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.acc
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        match self.acc {
+            Some(ref acc) if *acc != N::from(0) => ControlFlow::Continue(()),
+            _ => ControlFlow::Break(()),
+        }
+    }
+}
+
+macro_rules! uint_impls {
+    ($($uint_ty:ty)*) => {$(
+        impl Collector<$uint_ty> for Lcm<$uint_ty> {
+            #[inline]
+            fn collect(&mut self, num: $uint_ty) -> ControlFlow<()> {
+                self.acc = self.acc.and_then(|acc| {
+                    if acc == 0 || num == 0 {
+                        Some(0)
+                    } else {
+                        (acc / gcd(acc, num)).checked_mul(num)
+                    }
+                });
+                self.break_hint()
+            }
+
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = $uint_ty>,
+            ) -> ControlFlow<()> {
+                self.break_hint()?;
+
+                nums.into_iter().try_for_each(|num| self.collect(num))
+            }
+        }
+
+        impl<'a> Collector<&'a $uint_ty> for Lcm<$uint_ty> {
+            #[inline]
+            fn collect(&mut self, &num: &'a $uint_ty) -> ControlFlow<()> {
+                self.collect(num)
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = &'a $uint_ty>,
+            ) -> ControlFlow<()> {
+                self.collect_many(nums.into_iter().copied())
+            }
+        }
+    )*};
+}
+
+uint_impls!(usize u8 u16 u32 u64 u128);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(0..16_u8, ..5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<u8>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: Lcm::new,
+            should_break_pred: |iter| stop_at(iter).is_some(),
+            pred: |iter, output, remaining| {
+                let consumed = stop_at(iter.clone()).map_or(iter.len(), |index| index + 1);
+                let expected = iter
+                    .clone()
+                    .take(consumed)
+                    .fold(1, |acc, num| naive_lcm(acc, num.into()));
+                let expected = u8::try_from(expected).ok();
+
+                if expected != output {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // The index of the item making the result 0 or overflow, if any.
+    fn stop_at(mut iter: impl Iterator<Item = u8>) -> Option<usize> {
+        let mut acc = 1;
+        iter.position(|num| {
+            acc = naive_lcm(acc, num.into());
+            acc == 0 || acc > u8::MAX.into()
+        })
+    }
+
+    fn naive_lcm(a: u32, b: u32) -> u32 {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            (1..).map(|k| a * k).find(|m| m.is_multiple_of(b)).unwrap()
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that adds every collected integer,
+/// saturating at the numeric bounds instead of overflowing.
+/// Its [`Output`](CollectorBase::Output) is the sum.
+///
+/// Each addition saturates on its own, so for signed integers,
+/// the result may differ from clamping the exact sum,
+/// e.g., `[i8::MAX, 1, -1]` adds up to `i8::MAX - 1`.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::SaturatingSum};
+///
+/// let sizes: [u16; 3] = [40_000, 20_000, 10_000];
+///
+/// assert_eq!(sizes.into_iter().feed_into(SaturatingSum::new()), u16::MAX);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SaturatingSum<N> {
+    sum: N,
+}
+
+impl<N> SaturatingSum<N>
+where
+    N: Default,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self { sum: N::default() }
+    }
+}
+
+impl<N> Default for SaturatingSum<N>
+where
+    N: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> CollectorBase for SaturatingSum<N> {
+    type Output = N;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.sum
+    }
+}
+
+macro_rules! int_impls {
+    ($($int_ty:ty)*) => {$(
+        impl Collector<$int_ty> for SaturatingSum<$int_ty> {
+            #[inline]
+            fn collect(&mut self, num: $int_ty) -> ControlFlow<()> {
+                self.sum = self.sum.saturating_add(num);
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = $int_ty>,
+            ) -> ControlFlow<()> {
+                self.sum = nums.into_iter().fold(self.sum, <$int_ty>::saturating_add);
+                ControlFlow::Continue(())
+            }
+        }
+
+        impl<'a> Collector<&'a $int_ty> for SaturatingSum<$int_ty> {
+            #[inline]
+            fn collect(&mut self, &num: &'a $int_ty) -> ControlFlow<()> {
+                self.collect(num)
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = &'a $int_ty>,
+            ) -> ControlFlow<()> {
+                self.collect_many(nums.into_iter().copied())
+            }
+        }
+    )*};
+}
+
+int_impls!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i8>(), ..6),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i8>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: SaturatingSum::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if iter.fold(0, i8::saturating_add) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
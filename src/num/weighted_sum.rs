@@ -0,0 +1,127 @@
+use std::ops::{AddAssign, ControlFlow, Mul};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that adds the product of each `(value, weight)` pair it collects.
+/// Its [`Output`](CollectorBase::Output) is the sum of the products.
+///
+/// The value and the weight may be of different types,
+/// as long as the value can be multiplied by the weight.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::WeightedSum};
+///
+/// struct LineItem {
+///     unit_price: u32,
+///     quantity: u32,
+/// }
+///
+/// let cart = [
+///     LineItem { unit_price: 250, quantity: 2 },
+///     LineItem { unit_price: 1200, quantity: 1 },
+///     LineItem { unit_price: 75, quantity: 4 },
+/// ];
+///
+/// let total = cart
+///     .iter()
+///     .map(|item| (item.unit_price, item.quantity))
+///     .feed_into(WeightedSum::new());
+///
+/// assert_eq!(total, 2000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeightedSum<S> {
+    sum: S,
+}
+
+impl<S> WeightedSum<S> {
+    /// Creates a new instance of this collector, starting from `S::default()`.
+    #[inline]
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Self::starting_at(S::default())
+    }
+
+    /// Creates a new instance of this collector, starting from `sum`.
+    #[inline]
+    pub const fn starting_at(sum: S) -> Self {
+        Self { sum }
+    }
+}
+
+impl<S> Default for WeightedSum<S>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> CollectorBase for WeightedSum<S> {
+    type Output = S;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.sum
+    }
+}
+
+impl<S, T, W> Collector<(T, W)> for WeightedSum<S>
+where
+    T: Mul<W, Output = S>,
+    S: AddAssign,
+{
+    #[inline]
+    fn collect(&mut self, (value, weight): (T, W)) -> ControlFlow<()> {
+        self.sum += value * weight;
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            pairs in propvec((any::<i16>(), any::<i16>()), ..5),
+        ) {
+            all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    fn all_collect_methods_impl(pairs: Vec<(i16, i16)>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || {
+                pairs
+                    .iter()
+                    .map(|&(value, weight)| (i64::from(value), i64::from(weight)))
+            },
+            collector_factory: WeightedSum::<i64>::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if iter.map(|(value, weight)| value * weight).sum::<i64>() != output {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that adds every collected integer, wrapping around on overflow.
+/// Its [`Output`](CollectorBase::Output) is the sum.
+///
+/// It behaves the same regardless of whether overflow checks are enabled,
+/// unlike `<int>::adding()`, which panics on overflow when they are.
+/// It is also the same as `Wrapping::<int>::adding()`,
+/// but without wrapping the items in [`Wrapping`](std::num::Wrapping).
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::WrappingSum};
+///
+/// let bytes: [u8; 3] = [200, 100, 10];
+///
+/// // A simple checksum.
+/// assert_eq!(bytes.into_iter().feed_into(WrappingSum::new()), 54);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WrappingSum<N> {
+    sum: N,
+}
+
+impl<N> WrappingSum<N>
+where
+    N: Default,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self { sum: N::default() }
+    }
+}
+
+impl<N> Default for WrappingSum<N>
+where
+    N: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> CollectorBase for WrappingSum<N> {
+    type Output = N;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.sum
+    }
+}
+
+macro_rules! int_impls {
+    ($($int_ty:ty)*) => {$(
+        impl Collector<$int_ty> for WrappingSum<$int_ty> {
+            #[inline]
+            fn collect(&mut self, num: $int_ty) -> ControlFlow<()> {
+                self.sum = self.sum.wrapping_add(num);
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = $int_ty>,
+            ) -> ControlFlow<()> {
+                self.sum = nums.into_iter().fold(self.sum, <$int_ty>::wrapping_add);
+                ControlFlow::Continue(())
+            }
+        }
+
+        impl<'a> Collector<&'a $int_ty> for WrappingSum<$int_ty> {
+            #[inline]
+            fn collect(&mut self, &num: &'a $int_ty) -> ControlFlow<()> {
+                self.collect(num)
+            }
+
+            #[inline]
+            fn collect_many(
+                &mut self,
+                nums: impl IntoIterator<Item = &'a $int_ty>,
+            ) -> ControlFlow<()> {
+                self.collect_many(nums.into_iter().copied())
+            }
+        }
+    )*};
+}
+
+int_impls!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i8>(), ..6),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i8>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: WrappingSum::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if iter.fold(0, i8::wrapping_add) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,124 @@
+use std::ops::{BitXorAssign, ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that computes the bitwise XOR of every collected number.
+/// Its [`Output`](CollectorBase::Output) is the result,
+/// which is 0 if it has not collected any numbers.
+///
+/// This collector corresponds to `iter.fold(0, |acc, num| acc ^ num)`.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, num::XorAll};
+///
+/// // Every ID appears twice, except one.
+/// let ids: [u32; 5] = [7, 3, 9, 3, 7];
+///
+/// assert_eq!(ids.into_iter().feed_into(XorAll::new()), 9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct XorAll<N> {
+    acc: N,
+}
+
+impl<N> XorAll<N>
+where
+    N: Default,
+{
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub fn new() -> Self {
+        Self { acc: N::default() }
+    }
+}
+
+impl<N> Default for XorAll<N>
+where
+    N: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> CollectorBase for XorAll<N> {
+    type Output = N;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.acc
+    }
+}
+
+impl<N> Collector<N> for XorAll<N>
+where
+    N: BitXorAssign,
+{
+    #[inline]
+    fn collect(&mut self, num: N) -> ControlFlow<()> {
+        self.acc ^= num;
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, nums: impl IntoIterator<Item = N>) -> ControlFlow<()> {
+        nums.into_iter().for_each(|num| self.acc ^= num);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'a, N> Collector<&'a N> for XorAll<N>
+where
+    N: BitXorAssign + Copy,
+{
+    #[inline]
+    fn collect(&mut self, &num: &'a N) -> ControlFlow<()> {
+        self.collect(num)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, nums: impl IntoIterator<Item = &'a N>) -> ControlFlow<()> {
+        self.collect_many(nums.into_iter().copied())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<u8>(), ..5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<u8>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: XorAll::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                if iter.fold(0, |acc, num| acc ^ num) != output {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
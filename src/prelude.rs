@@ -3,17 +3,28 @@
 //! This module is intended to be imported with a wildcard, providing
 //! convenient access to the most frequently used traits and types.
 //!
+//! The items are versioned: this module re-exports everything in the latest version,
+//! currently [`v1`]. Import a version directly (e.g., `komadori::prelude::v1::*`)
+//! so that items added to later versions never clash with names of your own.
+//!
+//! Besides the traits, the [`collector`] module itself is re-exported,
+//! so every collector and adaptor type can be reached from a single root.
+//! [`pipeline()`] is also re-exported on its own,
+//! as the entry point for composing adapters front to back.
+//!
 //! # Example
 //!
 //! ```
 //! use komadori::prelude::*;
+//!
+//! // Types of collectors are namespaced under `collector`.
+//! let doubled: collector::Map<_, _> = pipeline::<i32>()
+//!     .map(|num| num * 2)
+//!     .sink(vec![]);
+//!
+//! assert_eq!([1, 2, 3].into_iter().feed_into(doubled), [2, 4, 6]);
 //! ```
 
-pub use crate::{
-    collector::{
-        Collector, CollectorBase, CollectorByMut, CollectorByRef, IntoCollector, IntoCollectorBase,
-    },
-    iter::IteratorExt,
-    ops::{Adding, Muling},
-    slice::Concat,
-};
+pub mod v1;
+
+pub use v1::*;
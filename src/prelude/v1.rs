@@ -0,0 +1,13 @@
+//! The first version of the prelude.
+//!
+//! See the [module-level documentation](super) for more.
+
+pub use crate::{
+    collector::{
+        self, Collector, CollectorBase, CollectorByMut, CollectorByRef, IntoCollector,
+        IntoCollectorBase, pipeline,
+    },
+    iter::IteratorExt,
+    ops::{Adding, Muling},
+    slice::Concat,
+};
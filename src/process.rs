@@ -0,0 +1,9 @@
+//! [`Collector`]s that interact with child processes.
+//!
+//! This module corresponds to [`std::process`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+mod stdin_collector;
+
+pub use stdin_collector::*;
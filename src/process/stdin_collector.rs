@@ -0,0 +1,226 @@
+use std::{
+    fmt::Debug,
+    io::{self, BufWriter, Read, Write},
+    ops::ControlFlow,
+    process::{Child, ChildStdin, Output},
+    thread::{self, JoinHandle},
+};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that writes collected bytes to the stdin of a child process.
+/// Its [`Output`](CollectorBase::Output) is [`io::Result<Output>`],
+/// holding what the child process produced, like [`Child::wait_with_output()`].
+///
+/// It accepts any item implementing [`AsRef<[u8]>`](AsRef), such as `&[u8]`,
+/// `&str`, [`String`] and [`Vec<u8>`]. Writes are buffered.
+///
+/// The stdout and stderr of the child process, if [piped](std::process::Stdio::piped),
+/// are read on background threads while writing.
+/// Otherwise, a child process streaming its output, such as `cat` or `gzip`,
+/// would block once the pipe is full, and writing to it would never return.
+///
+/// On [`finish()`](CollectorBase::finish), the stdin is flushed and closed so that
+/// the child process sees the end of its input, then the child process is waited for.
+///
+/// If writing fails (e.g., the child process has exited),
+/// this collector stops accumulating and the error is returned on finishing.
+/// In that case, the child process is still waited for before being dropped.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(unix)] {
+/// use std::process::{Command, Stdio};
+/// use komadori::{prelude::*, process::StdinCollector};
+///
+/// let child = Command::new("sort")
+///     .stdin(Stdio::piped())
+///     .stdout(Stdio::piped())
+///     .spawn()
+///     .unwrap();
+///
+/// let output = ["pear\n", "apple\n", "fig\n"]
+///     .into_iter()
+///     .feed_into(StdinCollector::new(child))
+///     .unwrap();
+///
+/// assert_eq!(output.stdout, b"apple\nfig\npear\n");
+/// # }
+/// ```
+pub struct StdinCollector {
+    child: Child,
+    stdin: BufWriter<ChildStdin>,
+    stdout_reader: Option<JoinHandle<io::Result<Vec<u8>>>>,
+    stderr_reader: Option<JoinHandle<io::Result<Vec<u8>>>>,
+    error: Option<io::Error>,
+}
+
+impl StdinCollector {
+    /// Creates a new instance of this collector writing to the stdin of the given child process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stdin of the child process is not [piped](std::process::Stdio::piped)
+    /// or has already been taken.
+    pub fn new(mut child: Child) -> Self {
+        let stdin = child
+            .stdin
+            .take()
+            .expect("stdin of the child process should be piped");
+
+        Self {
+            stdout_reader: child.stdout.take().map(spawn_reader),
+            stderr_reader: child.stderr.take().map(spawn_reader),
+            child,
+            stdin: BufWriter::new(stdin),
+            error: None,
+        }
+    }
+
+    fn write(&mut self, buf: &[u8]) -> ControlFlow<()> {
+        match self.stdin.write_all(buf) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => {
+                self.error = Some(e);
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+fn spawn_reader(mut pipe: impl Read + Send + 'static) -> JoinHandle<io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut buf = vec![];
+        pipe.read_to_end(&mut buf)?;
+        Ok(buf)
+    })
+}
+
+fn join_reader(reader: Option<JoinHandle<io::Result<Vec<u8>>>>) -> io::Result<Vec<u8>> {
+    reader.map_or(Ok(vec![]), |reader| {
+        reader
+            .join()
+            .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+    })
+}
+
+impl CollectorBase for StdinCollector {
+    type Output = io::Result<Output>;
+
+    fn finish(self) -> Self::Output {
+        let Self {
+            mut child,
+            stdin,
+            stdout_reader,
+            stderr_reader,
+            error,
+        } = self;
+
+        // Close the stdin regardless so that the child process sees EOF.
+        let result = match error {
+            Some(e) => {
+                drop(stdin);
+                Err(e)
+            }
+            None => stdin.into_inner().map(drop).map_err(|e| e.into_error()),
+        };
+
+        // Wait regardless to avoid leaving a zombie process behind.
+        // The readers keep draining the pipes, so the child process cannot block on them.
+        let status = child.wait();
+        let stdout = join_reader(stdout_reader);
+        let stderr = join_reader(stderr_reader);
+
+        result?;
+        Ok(Output {
+            status: status?,
+            stdout: stdout?,
+            stderr: stderr?,
+        })
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.error.is_some() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<T> Collector<T> for StdinCollector
+where
+    T: AsRef<[u8]>,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.write(item.as_ref())
+    }
+}
+
+impl Debug for StdinCollector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StdinCollector")
+            .field("child", &self.child)
+            .field("stdin", &self.stdin)
+            .field("stdout_reader", &self.stdout_reader)
+            .field("stderr_reader", &self.stderr_reader)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "std", unix))]
+mod proptests {
+    use std::process::{Command, Stdio};
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        // Each chunk may exceed the capacity of a pipe (64 KiB on Linux) on its own.
+        #[test]
+        fn all_collect_methods(
+            chunks in propvec(propvec(any::<u8>(), ..100_000), ..4),
+        ) {
+            all_collect_methods_impl(chunks)?;
+        }
+    }
+
+    fn all_collect_methods_impl(chunks: Vec<Vec<u8>>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chunks.iter(),
+            collector_factory: || StdinCollector::new(spawn_cat()),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let output = output.unwrap();
+
+                if !output.status.success() || iter.flatten().copied().ne(output.stdout) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn spawn_cat() -> Child {
+        Command::new("cat")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap()
+    }
+}
@@ -0,0 +1,150 @@
+//! Bridges between Python and collectors, with [`pyo3`](mod@pyo3).
+//!
+//! [`PyCollector`] exposes a collector to Python as an object
+//! with `collect(item)`, `collect_many(iterable)` and `finish()` methods,
+//! so that Python scripts can feed items into a pipeline compiled from Rust,
+//! aggregating everything in one pass.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! Exporting a pipeline to Python:
+//!
+//! ```no_run
+//! use komadori::{prelude::*, iter::Count, pyo3::PyCollector};
+//! use pyo3::prelude::*;
+//!
+//! /// Counts the truthy values.
+//! #[pyfunction]
+//! fn truthy_counter() -> PyCollector {
+//!     PyCollector::new(Count::new().filter(|obj: &Py<PyAny>| {
+//!         Python::attach(|py| obj.bind(py).is_truthy().unwrap_or(false))
+//!     }))
+//! }
+//!
+//! #[pymodule]
+//! fn etl(m: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     m.add_class::<PyCollector>()?;
+//!     m.add_function(wrap_pyfunction!(truthy_counter, m)?)
+//! }
+//! ```
+//!
+//! Which can be used in Python like:
+//!
+//! ```python
+//! counter = etl.truthy_counter()
+//! counter.collect(1)
+//! counter.collect_many([0, "a", None])
+//! print(counter.finish())  # 2
+//! ```
+
+use std::fmt::Debug;
+
+use pyo3::{IntoPyObjectExt, exceptions::PyRuntimeError, prelude::*};
+
+use crate::collector::{BoxCollector, Collector, CollectorBase};
+
+/// A collector exposed to Python, collecting Python objects.
+///
+/// It is created in Rust with [`PyCollector::new()`], then returned to Python,
+/// where it is known as `Collector` and has the following methods:
+///
+/// - `collect(item)` collects an item,
+///   and returns `False` once the collector has stopped accumulating.
+/// - `collect_many(iterable)` collects the items of an iterable,
+///   and returns `False` once the collector has stopped accumulating.
+/// - `finish()` returns the output of the collector.
+///   Afterwards, all methods raise `RuntimeError`.
+///
+/// It can only be used by the thread that created it.
+///
+/// See the [module-level documentation](self) for an example.
+#[pyclass(name = "Collector", module = "komadori", unsendable)]
+pub struct PyCollector {
+    // `None` once finished.
+    collector: Option<BoxCollector<'static, Py<PyAny>, PyResult<Py<PyAny>>>>,
+}
+
+impl PyCollector {
+    /// Wraps a collector of Python objects whose output can be converted into a Python object.
+    pub fn new<C>(collector: C) -> Self
+    where
+        C: Collector<Py<PyAny>, Output: for<'py> IntoPyObject<'py>> + 'static,
+    {
+        let collector = collector.map_output(|output| Python::attach(|py| output.into_py_any(py)));
+
+        Self {
+            collector: Some(BoxCollector::new(collector)),
+        }
+    }
+
+    fn collector_mut(
+        &mut self,
+    ) -> PyResult<&mut BoxCollector<'static, Py<PyAny>, PyResult<Py<PyAny>>>> {
+        self.collector.as_mut().ok_or_else(finished_error)
+    }
+}
+
+#[pymethods]
+impl PyCollector {
+    /// Collects an item, returning `False` once the collector has stopped accumulating.
+    ///
+    /// # Errors
+    ///
+    /// Raises `RuntimeError` if the collector has been finished.
+    pub fn collect(&mut self, item: Py<PyAny>) -> PyResult<bool> {
+        Ok(self.collector_mut()?.collect(item).is_continue())
+    }
+
+    /// Collects the items of an iterable, returning `False`
+    /// once the collector has stopped accumulating.
+    ///
+    /// # Errors
+    ///
+    /// Raises `RuntimeError` if the collector has been finished,
+    /// `TypeError` if the object is not iterable,
+    /// or the exception raised while iterating.
+    pub fn collect_many(&mut self, iterable: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let collector = self.collector_mut()?;
+        let mut error = None;
+
+        let cf = collector.collect_many(iterable.try_iter()?.map_while(|item| match item {
+            Ok(item) => Some(item.unbind()),
+            Err(err) => {
+                error = Some(err);
+                None
+            }
+        }));
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(cf.is_continue()),
+        }
+    }
+
+    /// Returns the output of the collector.
+    ///
+    /// # Errors
+    ///
+    /// Raises `RuntimeError` if the collector has been finished,
+    /// or the error raised while converting the output.
+    pub fn finish(&mut self) -> PyResult<Py<PyAny>> {
+        // `Option` itself is a collector, so its `take()` must be disambiguated.
+        Option::take(&mut self.collector)
+            .ok_or_else(finished_error)?
+            .finish()
+    }
+}
+
+impl Debug for PyCollector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PyCollector")
+            .field("finished", &self.collector.is_none())
+            .finish_non_exhaustive()
+    }
+}
+
+fn finished_error() -> PyErr {
+    PyRuntimeError::new_err("the collector has been finished")
+}
@@ -0,0 +1,167 @@
+//! Building [`Collector`]s by name from runtime configuration.
+//!
+//! A [`Registry`] maps names to [`CollectorFactory`]s.
+//! Applications register the collectors they support, whether from this crate
+//! or from third-party crates, then build them from configuration such as
+//! command-line arguments or a config file.
+//!
+//! Since collectors are chosen at runtime, they are type-erased into [`BoxCollector`]s,
+//! which all share the item type `T` and the output type `O`.
+//! Use an `enum` (or `Box<dyn Any>`) as `O` when the collectors produce different outputs.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::{
+//!     prelude::*,
+//!     cmp::Max,
+//!     collector::BoxCollector,
+//!     iter::Count,
+//!     registry::{BuildError, Config, Registry},
+//! };
+//!
+//! let mut registry = Registry::<i32, i64>::new();
+//! registry.register_fn("count", |_| {
+//!     Ok(BoxCollector::new(Count::new().map_output(|count| count as i64)))
+//! });
+//! registry.register_fn("max", |config| {
+//!     let default: i64 = config.parse("default")?.unwrap_or(0);
+//!     Ok(BoxCollector::new(
+//!         Max::new().map_output(move |max| max.map_or(default, i64::from)),
+//!     ))
+//! });
+//!
+//! let config = Config::from_iter([("default", "-1")]);
+//! let max = registry.build("max", &config).unwrap();
+//! assert_eq!([4, 2, 6, 3].into_iter().feed_into(max), 6);
+//! let max = registry.build("max", &config).unwrap();
+//! assert_eq!([].into_iter().feed_into(max), -1);
+//!
+//! assert!(matches!(
+//!     registry.build("min", &Config::new()),
+//!     Err(BuildError::UnknownCollector(_)),
+//! ));
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+
+mod config;
+mod factory;
+
+pub use config::*;
+pub use factory::*;
+
+use std::collections::HashMap;
+
+use crate::collector::BoxCollector;
+
+/// A set of [`CollectorFactory`]s, looked up by their names.
+///
+/// See the [module-level documentation](self) for more.
+pub struct Registry<T, O> {
+    factories: HashMap<String, Box<dyn CollectorFactory<T, O>>>,
+}
+
+impl<T, O> Registry<T, O> {
+    /// Creates an empty registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Registers a factory under its [`name()`](CollectorFactory::name).
+    ///
+    /// If a factory with the same name has been registered, it is replaced and returned.
+    pub fn register<F>(&mut self, factory: F) -> Option<Box<dyn CollectorFactory<T, O>>>
+    where
+        F: CollectorFactory<T, O> + 'static,
+    {
+        self.factories
+            .insert(factory.name().to_owned(), Box::new(factory))
+    }
+
+    /// Registers a function as a factory under the given name.
+    ///
+    /// If a factory with the same name has been registered, it is replaced and returned.
+    pub fn register_fn<F>(
+        &mut self,
+        name: impl Into<String>,
+        f: F,
+    ) -> Option<Box<dyn CollectorFactory<T, O>>>
+    where
+        F: Fn(&Config) -> Result<BoxCollector<'static, T, O>, BuildError> + 'static,
+    {
+        self.register(FnFactory {
+            name: name.into(),
+            f,
+        })
+    }
+
+    /// Returns `true` if a factory is registered under the given name.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Returns the names of the registered factories, in arbitrary order.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+
+    /// Builds a collector with the factory registered under the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::UnknownCollector`] if no factory is registered under the name,
+    /// or the error returned by the factory.
+    pub fn build(
+        &self,
+        name: &str,
+        config: &Config,
+    ) -> Result<BoxCollector<'static, T, O>, BuildError> {
+        self.factories
+            .get(name)
+            .ok_or_else(|| BuildError::UnknownCollector(name.to_owned()))?
+            .build(config)
+    }
+}
+
+impl<T, O> Default for Registry<T, O> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, O> std::fmt::Debug for Registry<T, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Registry")
+            .field("factories", &self.factories.keys())
+            .finish()
+    }
+}
+
+struct FnFactory<F> {
+    name: String,
+    f: F,
+}
+
+impl<T, O, F> CollectorFactory<T, O> for FnFactory<F>
+where
+    F: Fn(&Config) -> Result<BoxCollector<'static, T, O>, BuildError>,
+{
+    #[inline]
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    fn build(&self, config: &Config) -> Result<BoxCollector<'static, T, O>, BuildError> {
+        (self.f)(config)
+    }
+}
@@ -0,0 +1,90 @@
+use std::{collections::HashMap, str::FromStr};
+
+use super::BuildError;
+
+/// String key-value pairs that a [`CollectorFactory`](super::CollectorFactory)
+/// builds a collector from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config(HashMap<String, String>);
+
+impl Config {
+    /// Creates an empty configuration.
+    #[inline]
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Sets the value of a key, returning the previous value if any.
+    #[inline]
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.0.insert(key.into(), value.into())
+    }
+
+    /// Returns the value of a key, if any.
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Parses the value of a key, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::InvalidValue`] if the value cannot be parsed.
+    pub fn parse<V: FromStr>(&self, key: &str) -> Result<Option<V>, BuildError> {
+        self.get(key)
+            .map(|value| {
+                value.parse().map_err(|_| BuildError::InvalidValue {
+                    key: key.to_owned(),
+                    value: value.to_owned(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Parses the value of a key that must be present.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::MissingKey`] if the key is absent,
+    /// or [`BuildError::InvalidValue`] if the value cannot be parsed.
+    pub fn require<V: FromStr>(&self, key: &str) -> Result<V, BuildError> {
+        self.parse(key)?
+            .ok_or_else(|| BuildError::MissingKey(key.to_owned()))
+    }
+
+    /// Returns an iterator over the key-value pairs, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Config
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+impl<K, V> Extend<(K, V)> for Config
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.into())),
+        );
+    }
+}
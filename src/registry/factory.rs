@@ -0,0 +1,91 @@
+use std::fmt::{self, Display};
+
+use crate::collector::BoxCollector;
+
+use super::Config;
+
+/// Builds a collector from a [`Config`].
+///
+/// Implement it for collectors that should be buildable by name through a
+/// [`Registry`](super::Registry). For one-off factories,
+/// [`Registry::register_fn()`](super::Registry::register_fn) is more concise.
+///
+/// This trait is *dyn-compatible*.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{
+///     prelude::*,
+///     collector::BoxCollector,
+///     registry::{BuildError, CollectorFactory, Config, Registry},
+/// };
+///
+/// // Keeps the first `limit` items.
+/// struct Head;
+///
+/// impl CollectorFactory<i32, Vec<i32>> for Head {
+///     fn name(&self) -> &str {
+///         "head"
+///     }
+///
+///     fn build(&self, config: &Config) -> Result<BoxCollector<'static, i32, Vec<i32>>, BuildError> {
+///         let limit = config.require("limit")?;
+///         Ok(BoxCollector::new(vec![].into_collector().take(limit)))
+///     }
+/// }
+///
+/// let mut registry = Registry::new();
+/// registry.register(Head);
+///
+/// let head = registry
+///     .build("head", &Config::from_iter([("limit", "2")]))
+///     .unwrap();
+/// assert_eq!((1..).feed_into(head), [1, 2]);
+///
+/// assert_eq!(
+///     registry.build("head", &Config::new()).unwrap_err(),
+///     BuildError::MissingKey("limit".to_owned()),
+/// );
+/// ```
+pub trait CollectorFactory<T, O> {
+    /// The name that this factory is registered under.
+    fn name(&self) -> &str;
+
+    /// Builds a collector from the configuration.
+    fn build(&self, config: &Config) -> Result<BoxCollector<'static, T, O>, BuildError>;
+}
+
+/// An error while building a collector from a [`Config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuildError {
+    /// No factory is registered under the name.
+    UnknownCollector(String),
+    /// A required key is absent.
+    MissingKey(String),
+    /// The value of a key is invalid.
+    InvalidValue {
+        /// The key.
+        key: String,
+        /// The invalid value.
+        value: String,
+    },
+    /// Any other error, described by a message.
+    Other(String),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCollector(name) => write!(f, "unknown collector `{name}`"),
+            Self::MissingKey(key) => write!(f, "missing key `{key}`"),
+            Self::InvalidValue { key, value } => {
+                write!(f, "invalid value `{value}` for key `{key}`")
+            }
+            Self::Other(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
@@ -0,0 +1,167 @@
+//! [`Collector`]s for [`ropey::Rope`].
+//!
+//! Ropes can collect `&str` chunks and `char`s, which are appended to the end.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::prelude::*;
+//! use ropey::Rope;
+//!
+//! let rope = ["fn main() {\n", "    println!(\"Hi!\");\n", "}\n"]
+//!     .into_iter()
+//!     .feed_into(Rope::new());
+//!
+//! assert_eq!(rope.len_lines(), 4);
+//! assert_eq!(rope.line(1), "    println!(\"Hi!\");\n");
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::ops::ControlFlow;
+
+use ropey::Rope;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that appends `&str`s and `char`s to a [`Rope`].
+/// Its [`Output`] is [`Rope`].
+///
+/// This struct is created by `Rope::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug, Clone)]
+pub struct IntoCollector(Rope);
+
+/// A collector that appends `&str`s and `char`s to a [`&mut Rope`](Rope).
+/// Its [`Output`] is [`&mut Rope`](Rope).
+///
+/// This struct is created by `Rope::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+#[derive(Debug)]
+pub struct CollectorMut<'a>(&'a mut Rope);
+
+impl crate::collector::IntoCollectorBase for Rope {
+    type Output = Self;
+
+    type IntoCollector = IntoCollector;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a> crate::collector::IntoCollectorBase for &'a mut Rope {
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+#[inline]
+fn append_str(rope: &mut Rope, s: &str) -> ControlFlow<()> {
+    rope.insert(rope.len_chars(), s);
+    ControlFlow::Continue(())
+}
+
+#[inline]
+fn append_char(rope: &mut Rope, ch: char) -> ControlFlow<()> {
+    rope.insert_char(rope.len_chars(), ch);
+    ControlFlow::Continue(())
+}
+
+impl CollectorBase for IntoCollector {
+    type Output = Rope;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl Collector<&str> for IntoCollector {
+    #[inline]
+    fn collect(&mut self, s: &str) -> ControlFlow<()> {
+        append_str(&mut self.0, s)
+    }
+}
+
+impl Collector<char> for IntoCollector {
+    #[inline]
+    fn collect(&mut self, ch: char) -> ControlFlow<()> {
+        append_char(&mut self.0, ch)
+    }
+}
+
+impl<'a> CollectorBase for CollectorMut<'a> {
+    type Output = &'a mut Rope;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl Collector<&str> for CollectorMut<'_> {
+    #[inline]
+    fn collect(&mut self, s: &str) -> ControlFlow<()> {
+        append_str(self.0, s)
+    }
+}
+
+impl Collector<char> for CollectorMut<'_> {
+    #[inline]
+    fn collect(&mut self, ch: char) -> ControlFlow<()> {
+        append_char(self.0, ch)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            starting in ".{0,5}",
+            chunks in propvec(".{0,5}", ..5),
+        ) {
+            all_collect_methods_impl(starting, chunks)?;
+        }
+    }
+
+    fn all_collect_methods_impl(starting: String, chunks: Vec<String>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chunks.iter().map(String::as_str),
+            collector_factory: || Rope::from_str(&starting).into_collector(),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let expected = starting.clone() + &iter.collect::<String>();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
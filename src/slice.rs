@@ -5,5 +5,7 @@
 //! [`Collector`]: crate::collector::Collector
 
 mod concat;
+mod fill;
 
 pub use concat::*;
+pub use fill::*;
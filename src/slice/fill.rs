@@ -0,0 +1,137 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that overwrites the elements of a mutable slice with the collected items, in order.
+/// Its [`Output`](CollectorBase::Output) is the filled part of the slice.
+///
+/// This collector stops accumulating once every element has been overwritten.
+/// It never allocates, so it is usable without the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, slice::Fill};
+///
+/// let mut buf = [0; 4];
+/// let filled = (1..=3).feed_into(Fill::new(&mut buf));
+///
+/// assert_eq!(filled, [1, 2, 3]);
+/// assert_eq!(buf, [1, 2, 3, 0]);
+/// ```
+///
+/// ```
+/// use komadori::{prelude::*, slice::Fill};
+///
+/// let mut buf = [0; 2];
+/// let mut nums = 1..=3;
+///
+/// assert_eq!(nums.by_ref().feed_into(Fill::new(&mut buf)), [1, 2]);
+/// assert_eq!(nums.next(), Some(3));
+/// ```
+#[derive(Debug)]
+pub struct Fill<'a, T> {
+    slice: &'a mut [T],
+    len: usize,
+}
+
+impl<'a, T> Fill<'a, T> {
+    /// Creates a new instance of this collector overwriting the given slice.
+    #[inline]
+    pub const fn new(slice: &'a mut [T]) -> Self {
+        Self { slice, len: 0 }
+    }
+
+    /// Returns the number of elements overwritten so far.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no elements have been overwritten so far.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'a, T> CollectorBase for Fill<'a, T> {
+    type Output = &'a mut [T];
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        &mut self.slice[..self.len]
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.len == self.slice.len() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl<T> Collector<T> for Fill<'_, T> {
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        let Some(slot) = self.slice.get_mut(self.len) else {
+            return ControlFlow::Break(());
+        };
+
+        *slot = item;
+        self.len += 1;
+        self.break_hint()
+    }
+
+    fn collect_many(&mut self, items: impl IntoIterator<Item = T>) -> ControlFlow<()> {
+        // The slots go first so that no item is consumed once the slice is full.
+        for (slot, item) in self.slice[self.len..].iter_mut().zip(items) {
+            *slot = item;
+            self.len += 1;
+        }
+
+        self.break_hint()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..9),
+            cap in ..9_usize,
+        ) {
+            all_collect_methods_impl(nums, cap)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, cap: usize) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            // Leaked since each collector needs its own buffer. It is tiny anyway.
+            collector_factory: || Fill::new(vec![0; cap].leak()),
+            should_break_pred: |iter| iter.count() >= cap,
+            pred: |mut iter, output, remaining| {
+                if iter.by_ref().take(cap).ne(output.iter().copied()) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
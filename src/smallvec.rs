@@ -0,0 +1,203 @@
+//! [`Collector`]s for [`smallvec::SmallVec`].
+//!
+//! Items are stored inline until the inline capacity is exceeded,
+//! and then spill onto the heap. Hence, these collectors never stop accumulating.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::prelude::*;
+//! use smallvec::SmallVec;
+//!
+//! let vec = (1..=4).feed_into(SmallVec::<[i32; 2]>::new());
+//!
+//! assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+//! assert!(vec.spilled());
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::{fmt::Debug, ops::ControlFlow};
+
+use smallvec::{Array, SmallVec};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pushes collected items into a [`SmallVec`].
+/// Its [`Output`] is [`SmallVec`].
+///
+/// This struct is created by `SmallVec::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+pub struct IntoCollector<A: Array>(SmallVec<A>);
+
+/// A collector that pushes collected items into a [`&mut SmallVec`](SmallVec).
+/// Its [`Output`] is [`&mut SmallVec`](SmallVec).
+///
+/// This struct is created by `SmallVec::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+pub struct CollectorMut<'a, A: Array>(&'a mut SmallVec<A>);
+
+impl<A: Array> crate::collector::IntoCollectorBase for SmallVec<A> {
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<A>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, A: Array> crate::collector::IntoCollectorBase for &'a mut SmallVec<A> {
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, A>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+impl<A: Array> CollectorBase for IntoCollector<A> {
+    type Output = SmallVec<A>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        self.0.reserve(additional_min);
+    }
+}
+
+impl<A: Array> Collector<A::Item> for IntoCollector<A> {
+    #[inline]
+    fn collect(&mut self, item: A::Item) -> ControlFlow<()> {
+        self.0.push(item);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = A::Item>) -> ControlFlow<()> {
+        self.0.extend(items);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(mut self, items: impl IntoIterator<Item = A::Item>) -> Self::Output {
+        self.0.extend(items);
+        self.0
+    }
+}
+
+impl<'a, A: Array> CollectorBase for CollectorMut<'a, A> {
+    type Output = &'a mut SmallVec<A>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        self.0.reserve(additional_min);
+    }
+}
+
+impl<A: Array> Collector<A::Item> for CollectorMut<'_, A> {
+    #[inline]
+    fn collect(&mut self, item: A::Item) -> ControlFlow<()> {
+        self.0.push(item);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_many(&mut self, items: impl IntoIterator<Item = A::Item>) -> ControlFlow<()> {
+        self.0.extend(items);
+        ControlFlow::Continue(())
+    }
+
+    #[inline]
+    fn collect_then_finish(self, items: impl IntoIterator<Item = A::Item>) -> Self::Output {
+        self.0.extend(items);
+        self.0
+    }
+}
+
+// Derives would bound `A` instead of `A::Item`.
+impl<A: Array> Clone for IntoCollector<A>
+where
+    A::Item: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<A: Array> Debug for IntoCollector<A>
+where
+    A::Item: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IntoCollector").field(&self.0).finish()
+    }
+}
+
+impl<A: Array> Debug for CollectorMut<'_, A>
+where
+    A::Item: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CollectorMut").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            starting_nums in propvec(any::<i32>(), ..5),
+            nums in propvec(any::<i32>(), ..5),
+        ) {
+            all_collect_methods_impl(starting_nums, nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(starting_nums: Vec<i32>, nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || SmallVec::<[i32; 4]>::from_slice(&starting_nums).into_collector(),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let expected: Vec<_> = starting_nums.iter().copied().chain(iter).collect();
+
+                if output[..] != expected[..] {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.count() != 0 {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,29 @@
+//! [`Collector`]s for statistics over streams of items.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! [`Collector`]: crate::collector::Collector
+
+#[cfg(feature = "alloc")]
+mod histogram;
+mod lin_reg;
+#[cfg(feature = "alloc")]
+mod median;
+mod quantiles;
+#[cfg(feature = "alloc")]
+mod running_median;
+mod summary;
+#[cfg(feature = "alloc")]
+mod two_heaps;
+mod weighted_mean;
+
+#[cfg(feature = "alloc")]
+pub use histogram::*;
+pub use lin_reg::*;
+#[cfg(feature = "alloc")]
+pub use median::*;
+pub use quantiles::*;
+#[cfg(feature = "alloc")]
+pub use running_median::*;
+pub use summary::*;
+pub use weighted_mean::*;
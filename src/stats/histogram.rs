@@ -0,0 +1,339 @@
+use std::ops::ControlFlow;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that counts the items falling in each bin between consecutive edges.
+/// Its [`Output`](CollectorBase::Output) is itself, from which the counts are read.
+///
+/// Each bin includes its lower edge and excludes its upper edge,
+/// except the last bin, which includes both.
+/// Items below the first edge are counted as [`underflow()`](Histogram::underflow),
+/// and items above the last edge as [`overflow()`](Histogram::overflow).
+///
+/// Items that cannot be compared with the edges, such as NaN, are not counted at all:
+/// neither in a bin nor as [`underflow()`](Histogram::underflow) or
+/// [`overflow()`](Histogram::overflow).
+/// Count them separately if they matter, as in the last example.
+///
+/// It collects both items and references to them,
+/// so it can be [`tee`](CollectorBase::tee)d with other collectors of the same items.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, stats::Histogram};
+///
+/// let histogram = [0.5, 1.0, 1.5, 2.5, 3.0, -1.0, 7.0]
+///     .into_iter()
+///     .feed_into(Histogram::with_bins([0.0, 1.0, 2.0, 3.0]));
+///
+/// assert_eq!(histogram.counts(), [1, 2, 2]);
+/// assert_eq!(histogram.underflow(), 1);
+/// assert_eq!(histogram.overflow(), 1);
+/// ```
+///
+/// Bins of latencies in milliseconds, alongside their maximum:
+///
+/// ```
+/// use komadori::{prelude::*, cmp::Max, stats::Histogram};
+///
+/// let latencies = [12.0, 48.5, 95.0, 31.0, 250.0, 77.5];
+///
+/// let (histogram, max) = latencies
+///     .iter()
+///     .feed_into(
+///         Histogram::uniform(0.0, 100.0, 4)
+///             .tee(Max::by(|a: &&f64, b: &&f64| a.total_cmp(b))),
+///     );
+///
+/// assert_eq!(histogram.edges(), [0.0, 25.0, 50.0, 75.0, 100.0]);
+/// assert_eq!(histogram.counts(), [1, 2, 0, 2]);
+/// assert_eq!(histogram.overflow(), 1);
+/// assert_eq!(max, Some(&250.0));
+/// ```
+///
+/// NaNs are not counted, so they need a collector of their own:
+///
+/// ```
+/// use komadori::{prelude::*, iter::Count, stats::Histogram};
+///
+/// let (histogram, nans) = [0.5, f64::NAN, 1.5, f64::NAN].into_iter().feed_into(
+///     Histogram::with_bins([0.0, 1.0, 2.0])
+///         .tee(Count::new().filter(|num: &f64| num.is_nan())),
+/// );
+///
+/// assert_eq!(histogram.counts(), [1, 1]);
+/// assert_eq!((histogram.underflow(), histogram.overflow()), (0, 0));
+/// assert_eq!(nans, 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram<T> {
+    edges: Vec<T>,
+    counts: Vec<usize>,
+    underflow: usize,
+    overflow: usize,
+}
+
+impl<T> Histogram<T>
+where
+    T: PartialOrd,
+{
+    /// Creates a new instance of this collector with bins between consecutive `edges`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than 2 edges, or they are not strictly increasing.
+    #[track_caller]
+    pub fn with_bins(edges: impl Into<Vec<T>>) -> Self {
+        let edges = edges.into();
+        assert!(edges.len() >= 2, "a histogram needs at least 2 edges");
+        assert!(
+            edges.windows(2).all(|pair| pair[0] < pair[1]),
+            "the edges must be strictly increasing"
+        );
+
+        Self {
+            counts: vec![0; edges.len() - 1],
+            edges,
+            underflow: 0,
+            overflow: 0,
+        }
+    }
+
+    #[inline]
+    fn count(&mut self, item: &T) {
+        let (first, last) = (&self.edges[0], &self.edges[self.edges.len() - 1]);
+
+        if item < first {
+            self.underflow += 1;
+        } else if item > last {
+            self.overflow += 1;
+        } else if item == last
+            && let Some(count) = self.counts.last_mut()
+        {
+            *count += 1;
+        } else if item >= first {
+            // The first edge is at most the item, so the index is at least 1.
+            let upper = self.edges.partition_point(|edge| edge <= item);
+            self.counts[upper - 1] += 1;
+        }
+    }
+}
+
+impl<T> Histogram<T> {
+    /// Returns the edges of the bins.
+    #[inline]
+    pub fn edges(&self) -> &[T] {
+        &self.edges
+    }
+
+    /// Returns the number of items in each bin, in order.
+    #[inline]
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Returns the number of items below the first edge.
+    #[inline]
+    pub const fn underflow(&self) -> usize {
+        self.underflow
+    }
+
+    /// Returns the number of items above the last edge.
+    #[inline]
+    pub const fn overflow(&self) -> usize {
+        self.overflow
+    }
+}
+
+impl Histogram<f64> {
+    /// Creates a new instance of this collector with `n` bins
+    /// of equal width from `min` to `max`.
+    ///
+    /// The edges are rounded to the nearest representable values,
+    /// nudged up where needed to stay strictly increasing,
+    /// so the widths may differ slightly if the bins are very narrow relative to `min` and `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, `min` is not less than `max`,
+    /// or there are too few `f64`s between `min` and `max` to make `n` bins,
+    /// e.g., `Histogram::uniform(1e16, 1e16 + 4.0, 3)`.
+    #[track_caller]
+    pub fn uniform(min: f64, max: f64, n: usize) -> Self {
+        assert!(n != 0, "a histogram needs at least 1 bin");
+        assert!(min < max, "`min` must be less than `max`");
+
+        // The highest each edge can be while leaving room for the ones after it.
+        let mut ceilings = vec![max; n + 1];
+        for i in (0..n).rev() {
+            ceilings[i] = ceilings[i + 1].next_down();
+        }
+        assert!(
+            min <= ceilings[0],
+            "there are too few `f64`s between `min` and `max` to make {n} bins"
+        );
+
+        let width = max - min;
+        let mut edges = Vec::with_capacity(n + 1);
+        edges.push(min);
+        for i in 1..n {
+            let t = i as f64 / n as f64;
+            let edge = if width.is_finite() {
+                min + width * t
+            } else {
+                // The width overflows for, e.g., `f64::MIN` to `f64::MAX`.
+                min * (1.0 - t) + max * t
+            };
+            let prev: f64 = edges[i - 1];
+            edges.push(edge.max(prev.next_up()).min(ceilings[i]));
+        }
+        edges.push(max);
+
+        Self::with_bins(edges)
+    }
+}
+
+impl<T> CollectorBase for Histogram<T> {
+    type Output = Self;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self
+    }
+}
+
+impl<T> Collector<T> for Histogram<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.count(&item);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'a, T> Collector<&'a T> for Histogram<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn collect(&mut self, item: &'a T) -> ControlFlow<()> {
+        self.count(item);
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(-10..10, ..8),
+            min in -5..0,
+            n in 1..=4_usize,
+        ) {
+            all_collect_methods_impl(nums, min, n)?;
+        }
+
+        #[test]
+        fn uniform(
+            min in proptest::num::f64::NORMAL | proptest::num::f64::ZERO,
+            max in proptest::num::f64::NORMAL | proptest::num::f64::ZERO,
+            n in 1..=16_usize,
+        ) {
+            prop_assume!(min < max);
+            uniform_impl(min, max, n)?;
+        }
+
+        #[test]
+        fn uniform_narrow(
+            min in proptest::num::f64::NORMAL,
+            ulps in 1..=16_usize,
+            n in 1..=16_usize,
+        ) {
+            let max = (0..ulps).fold(min, |num, _| num.next_up());
+            uniform_impl(min, max, n)?;
+        }
+    }
+
+    fn uniform_impl(min: f64, max: f64, n: usize) -> TestCaseResult {
+        // Panicking is only allowed without `n + 1` `f64`s from `min` to `max`.
+        let fits = (1..=n).try_fold(min, |num, _| (num < max).then(|| num.next_up()));
+        prop_assume!(fits.is_some());
+
+        let histogram = Histogram::uniform(min, max, n);
+        let edges = histogram.edges();
+
+        prop_assert_eq!(edges.len(), n + 1);
+        prop_assert_eq!((edges[0], edges[n]), (min, max));
+        prop_assert!(edges.windows(2).all(|pair| pair[0] < pair[1]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn uniform_as_narrow_as_possible() {
+        let histogram = Histogram::uniform(1e16, 1e16 + 4.0, 2);
+
+        assert_eq!(histogram.edges(), [1e16, 1e16 + 2.0, 1e16 + 4.0]);
+    }
+
+    #[test]
+    #[should_panic = "there are too few `f64`s between `min` and `max` to make 3 bins"]
+    fn uniform_too_narrow() {
+        // Only 1e16, 1e16 + 2 and 1e16 + 4 are representable.
+        Histogram::uniform(1e16, 1e16 + 4.0, 3);
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, min: i32, n: usize) -> TestCaseResult {
+        // Bins of width 2.
+        let edges: Vec<_> = (0..=n as i32).map(|i| min + 2 * i).collect();
+        let last = edges[n];
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || Histogram::with_bins(edges.clone()),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let nums: Vec<_> = iter.collect();
+                let counts: Vec<_> = edges
+                    .windows(2)
+                    .map(|pair| {
+                        nums.iter()
+                            .filter(|&&num| {
+                                pair[0] <= num && (num < pair[1] || num == last && pair[1] == last)
+                            })
+                            .count()
+                    })
+                    .collect();
+                let underflow = nums.iter().filter(|&&num| num < min).count();
+                let overflow = nums.iter().filter(|&&num| num > last).count();
+
+                if output.counts() != counts
+                    || output.underflow() != underflow
+                    || output.overflow() != overflow
+                {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,267 @@
+use std::ops::ControlFlow;
+
+use crate::{
+    collector::{Collector, CollectorBase, SnapshotCollector},
+    merge::Merge,
+};
+
+/// A collector that fits a least-squares line through the `(x, y)` pairs it collects,
+/// and measures how strongly `x` and `y` are correlated.
+/// Its [`Output`](CollectorBase::Output) is itself, from which the statistics are read.
+///
+/// The means and co-moments are updated one pair at a time,
+/// so the pairs are not stored.
+/// Like [`Summary`](super::Summary), regressions of separate shards can be [`Merge`]d.
+///
+/// If any number is NaN, the statistics are NaN.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, stats::LinReg};
+///
+/// let reg = [(1.0, 3.0), (2.0, 5.0), (3.0, 7.0), (4.0, 9.0)]
+///     .into_iter()
+///     .feed_into(LinReg::new());
+///
+/// assert_eq!(reg.count(), 4);
+/// assert_eq!(reg.slope(), Some(2.0));
+/// assert_eq!(reg.intercept(), Some(1.0));
+/// assert_eq!(reg.correlation(), Some(1.0));
+/// ```
+///
+/// Correlating two metrics of each request, alongside other statistics:
+///
+/// ```
+/// use komadori::{prelude::*, iter::Count, stats::LinReg};
+///
+/// // (payload size in KB, latency in ms)
+/// let requests = [(1.0, 10.0), (2.0, 14.0), (4.0, 18.0), (8.0, 35.0)];
+///
+/// let (reg, count) = requests
+///     .iter()
+///     .feed_into(LinReg::new().tee(Count::new()));
+///
+/// assert_eq!(count, 4);
+/// assert!(reg.correlation().unwrap() > 0.95);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinReg {
+    count: usize,
+    mean_x: f64,
+    mean_y: f64,
+    // The sums of squared differences from the means.
+    m2_x: f64,
+    m2_y: f64,
+    // The sum of products of the differences from the means.
+    co_moment: f64,
+}
+
+impl LinReg {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            co_moment: 0.0,
+        }
+    }
+
+    /// Returns the number of pairs collected.
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the slope of the fitted line,
+    /// or `None` if fewer than 2 pairs were collected or all `x`s are equal.
+    #[inline]
+    pub fn slope(&self) -> Option<f64> {
+        (self.count > 1 && self.m2_x != 0.0).then(|| self.co_moment / self.m2_x)
+    }
+
+    /// Returns where the fitted line crosses `x = 0`,
+    /// or `None` if [`slope()`](LinReg::slope) is `None`.
+    #[inline]
+    pub fn intercept(&self) -> Option<f64> {
+        self.slope().map(|slope| self.mean_y - slope * self.mean_x)
+    }
+
+    /// Returns the population covariance, or `None` if no pairs were collected.
+    #[inline]
+    pub fn covariance(&self) -> Option<f64> {
+        (self.count != 0).then(|| self.co_moment / self.count as f64)
+    }
+
+    /// Returns the sample covariance, with Bessel's correction,
+    /// or `None` if fewer than 2 pairs were collected.
+    #[inline]
+    pub fn sample_covariance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.co_moment / (self.count - 1) as f64)
+    }
+
+    /// Returns the Pearson correlation coefficient, between -1 and 1,
+    /// or `None` if fewer than 2 pairs were collected or all `x`s or all `y`s are equal.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn correlation(&self) -> Option<f64> {
+        (self.count > 1 && self.m2_x != 0.0 && self.m2_y != 0.0)
+            .then(|| (self.co_moment / (self.m2_x * self.m2_y).sqrt()).clamp(-1.0, 1.0))
+    }
+
+    #[inline]
+    fn push(&mut self, x: f64, y: f64) {
+        self.count += 1;
+        let count = self.count as f64;
+
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / count;
+        self.mean_y += dy / count;
+
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.co_moment += dx * (y - self.mean_y);
+    }
+}
+
+impl Default for LinReg {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Combines the regressions of two shards, as if all pairs were collected by one.
+impl Merge for LinReg {
+    fn merge(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let dx = other.mean_x - self.mean_x;
+        let dy = other.mean_y - self.mean_y;
+        let weight = other.count as f64 / count as f64;
+        let scale = self.count as f64 * weight;
+
+        self.mean_x += dx * weight;
+        self.mean_y += dy * weight;
+        self.m2_x += other.m2_x + dx * dx * scale;
+        self.m2_y += other.m2_y + dy * dy * scale;
+        self.co_moment += other.co_moment + dx * dy * scale;
+        self.count = count;
+    }
+}
+
+impl CollectorBase for LinReg {
+    type Output = Self;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self
+    }
+}
+
+impl SnapshotCollector for LinReg {
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl Collector<(f64, f64)> for LinReg {
+    #[inline]
+    fn collect(&mut self, (x, y): (f64, f64)) -> ControlFlow<()> {
+        self.push(x, y);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'a> Collector<&'a (f64, f64)> for LinReg {
+    #[inline]
+    fn collect(&mut self, &(x, y): &'a (f64, f64)) -> ControlFlow<()> {
+        self.push(x, y);
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            pairs in propvec((-100..100, -100..100), ..8),
+        ) {
+            all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    fn all_collect_methods_impl(pairs: Vec<(i32, i32)>) -> TestCaseResult {
+        let pairs: Vec<_> = pairs
+            .into_iter()
+            .map(|(x, y)| (f64::from(x), f64::from(y)))
+            .collect();
+
+        BasicCollectorTester {
+            iter_factory: || pairs.iter().copied(),
+            collector_factory: LinReg::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if !is_correct(&pairs, output) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // Compares against the textbook two-pass formulas.
+    fn is_correct(pairs: &[(f64, f64)], reg: LinReg) -> bool {
+        let close = |x: Option<f64>, y: Option<f64>| match (x, y) {
+            (Some(x), Some(y)) => (x - y).abs() <= 1e-9 * y.abs().max(1.0),
+            (x, y) => x.is_none() && y.is_none(),
+        };
+
+        let n = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|&(x, _)| x).sum::<f64>() / n;
+        let mean_y = pairs.iter().map(|&(_, y)| y).sum::<f64>() / n;
+        let sxx: f64 = pairs.iter().map(|&(x, _)| (x - mean_x).powi(2)).sum();
+        let syy: f64 = pairs.iter().map(|&(_, y)| (y - mean_y).powi(2)).sum();
+        let sxy: f64 = pairs
+            .iter()
+            .map(|&(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+
+        let slope = (pairs.len() > 1 && sxx != 0.0).then(|| sxy / sxx);
+        let correlation =
+            (pairs.len() > 1 && sxx != 0.0 && syy != 0.0).then(|| sxy / (sxx * syy).sqrt());
+
+        reg.count() == pairs.len()
+            && close(reg.slope(), slope)
+            && close(reg.intercept(), slope.map(|slope| mean_y - slope * mean_x))
+            && close(reg.covariance(), (!pairs.is_empty()).then(|| sxy / n))
+            && close(reg.correlation(), correlation)
+    }
+}
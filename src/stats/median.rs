@@ -0,0 +1,231 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase};
+
+use super::two_heaps::TwoHeaps;
+
+/// A collector that computes the exact median of the items it collects.
+///
+/// Its [`Output`](CollectorBase::Output) is `None` if it has not collected any items,
+/// or `Some` containing the median otherwise.
+/// When the number of items is even, the median is the [`Midpoint`]
+/// of the two middle items if created with [`new()`](Median::new),
+/// or the lower of them if created with [`lower()`](Median::lower).
+/// For a median after each item, see [`RunningMedian`](super::RunningMedian).
+///
+/// It keeps all items in two heaps, so each item takes *O*(log *n*) time
+/// and the memory grows with the number of items.
+///
+/// Items incomparable with themselves, such as NaN, are ignored.
+/// If two other items are incomparable, the output is unspecified.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, stats::Median};
+///
+/// assert_eq!([5, 15, 1, 3, 8].into_iter().feed_into(Median::new()), Some(5));
+/// assert_eq!([4.0, 1.0, 3.0, 2.0].into_iter().feed_into(Median::new()), Some(2.5));
+/// assert_eq!([4.0, 1.0, 3.0, 2.0].into_iter().feed_into(Median::lower()), Some(2.0));
+/// ```
+///
+/// The output is `None` if no items were collected, not counting NaNs.
+///
+/// ```
+/// use komadori::{prelude::*, stats::Median};
+///
+/// assert_eq!(Median::<f64>::new().finish(), None);
+/// assert_eq!([f64::NAN].into_iter().feed_into(Median::new()), None);
+/// assert_eq!([3.0, f64::NAN, 1.0].into_iter().feed_into(Median::new()), Some(2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Median<T> {
+    heaps: TwoHeaps<T>,
+    // Combines the two middle items. `None` picks the lower one.
+    average: Option<fn(T, T) -> T>,
+}
+
+impl<T> Median<T>
+where
+    T: PartialOrd + Midpoint,
+{
+    /// Creates a new instance of this collector
+    /// taking the midpoint of the two middle items if their number is even.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            heaps: TwoHeaps::new(),
+            average: Some(T::midpoint),
+        }
+    }
+}
+
+impl<T> Median<T>
+where
+    T: PartialOrd,
+{
+    /// Creates a new instance of this collector
+    /// taking the lower of the two middle items if their number is even,
+    /// so the median is always one of the items.
+    #[inline]
+    pub fn lower() -> Self {
+        Self {
+            heaps: TwoHeaps::new(),
+            average: None,
+        }
+    }
+}
+
+impl<T> Default for Median<T>
+where
+    T: PartialOrd + Midpoint,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CollectorBase for Median<T>
+where
+    T: PartialOrd,
+{
+    type Output = Option<T>;
+
+    fn finish(self) -> Self::Output {
+        let (lower_mid, upper_mid) = self.heaps.into_mids()?;
+
+        Some(match (upper_mid, self.average) {
+            (Some(upper_mid), Some(average)) => average(lower_mid, upper_mid),
+            _ => lower_mid,
+        })
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        self.heaps.reserve(additional_min);
+    }
+}
+
+impl<T> Collector<T> for Median<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        self.heaps.push(item);
+        ControlFlow::Continue(())
+    }
+}
+
+/// A number that has a midpoint with another number of the same type.
+///
+/// It is used by [`Median`] to average the two middle items.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::stats::Midpoint;
+///
+/// assert_eq!(Midpoint::midpoint(1.0, 4.0), 2.5);
+/// assert_eq!(Midpoint::midpoint(1, 4), 2);
+/// ```
+pub trait Midpoint {
+    /// Returns the midpoint of `self` and `other`, without overflowing.
+    ///
+    /// For integers, the midpoint is rounded towards zero,
+    /// the same as the inherent `midpoint()` method of the type.
+    fn midpoint(self, other: Self) -> Self;
+}
+
+macro_rules! midpoint_impl {
+    ($($ty:ty)*) => {
+        $(
+            impl Midpoint for $ty {
+                #[inline]
+                fn midpoint(self, other: Self) -> Self {
+                    <$ty>::midpoint(self, other)
+                }
+            }
+        )*
+    };
+}
+
+midpoint_impl!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(-5..5, ..8),
+            average in any::<bool>(),
+        ) {
+            all_collect_methods_impl(nums, average)?;
+        }
+
+        #[test]
+        fn floats_with_nans(
+            nums in propvec(prop_oneof![Just(f64::NAN), -5.0..5.0], ..8),
+        ) {
+            floats_with_nans_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, average: bool) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                if average {
+                    Median::new()
+                } else {
+                    Median::lower()
+                }
+            },
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut sorted: Vec<_> = iter.collect();
+                sorted.sort_unstable();
+                let len = sorted.len();
+                let expected = match len {
+                    0 => None,
+                    _ if len % 2 == 1 || !average => Some(sorted[(len - 1) / 2]),
+                    _ => Some(sorted[len / 2 - 1].midpoint(sorted[len / 2])),
+                };
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn floats_with_nans_impl(nums: Vec<f64>) -> TestCaseResult {
+        let mut sorted: Vec<_> = nums.iter().copied().filter(|num| !num.is_nan()).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+        let len = sorted.len();
+        let expected = match len {
+            0 => None,
+            _ if len % 2 == 1 => Some(sorted[len / 2]),
+            _ => Some(sorted[len / 2 - 1].midpoint(sorted[len / 2])),
+        };
+
+        prop_assert_eq!(nums.into_iter().feed_into(Median::new()), expected);
+
+        Ok(())
+    }
+}
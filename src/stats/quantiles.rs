@@ -0,0 +1,274 @@
+use std::{array, ops::ControlFlow};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that estimates quantiles of the items it collects in constant memory,
+/// using the P² algorithm.
+///
+/// The target quantiles are given at construction, as fractions between 0 and 1,
+/// e.g., `0.95` for the 95th percentile.
+/// Its [`Output`](CollectorBase::Output) is `None` if it has not collected any items,
+/// or `Some` containing the estimate for each target quantile, in order.
+///
+/// Each target quantile takes a fixed number of markers, which are adjusted after each item
+/// without storing it, so the memory does not grow with the number of items.
+/// The estimates are exact for up to 5 items, interpolating between the two closest items,
+/// and approximate afterwards.
+/// For the exact median, see `Median` instead.
+///
+/// If any item is NaN, the output is unspecified.
+///
+/// # Panics
+///
+/// [`new()`](Quantiles::new) panics if any target quantile is not between 0 and 1, inclusive.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, stats::Quantiles};
+///
+/// // Latencies in milliseconds, in some shuffled order.
+/// let latencies = (0..1000).map(|i| f64::from((i * 7919) % 1000));
+///
+/// let [p50, p95, p99] = latencies
+///     .feed_into(Quantiles::new([0.5, 0.95, 0.99]))
+///     .unwrap();
+///
+/// assert!((p50 - 500.0).abs() < 20.0);
+/// assert!((p95 - 950.0).abs() < 20.0);
+/// assert!((p99 - 990.0).abs() < 20.0);
+/// ```
+///
+/// With few items, the quantiles are exact.
+///
+/// ```
+/// use komadori::{prelude::*, stats::Quantiles};
+///
+/// let quartiles = [4.0, 1.0, 3.0, 2.0, 5.0]
+///     .into_iter()
+///     .feed_into(Quantiles::new([0.25, 0.5, 0.75]));
+///
+/// assert_eq!(quartiles, Some([2.0, 3.0, 4.0]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Quantiles<const N: usize> {
+    count: usize,
+    // The first items, from which the markers start.
+    first: [f64; MARKERS],
+    estimators: [Estimator; N],
+}
+
+const MARKERS: usize = 5;
+
+// The markers of the P² algorithm for one quantile.
+// The middle marker estimates the quantile,
+// and the others the minimum, the maximum and the quantiles halfway to them.
+#[derive(Debug, Clone)]
+struct Estimator {
+    p: f64,
+    heights: [f64; MARKERS],
+    // Kept as `f64`s to avoid conversions. They are integers.
+    positions: [f64; MARKERS],
+    desired_positions: [f64; MARKERS],
+}
+
+impl<const N: usize> Quantiles<N> {
+    /// Creates a new instance of this collector estimating the given quantiles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any target quantile is not between 0 and 1, inclusive.
+    #[inline]
+    #[track_caller]
+    pub fn new(quantiles: [f64; N]) -> Self {
+        assert!(
+            quantiles.iter().all(|p| (0.0..=1.0).contains(p)),
+            "quantiles must be between 0 and 1"
+        );
+
+        Self {
+            count: 0,
+            first: [0.0; MARKERS],
+            estimators: quantiles.map(|p| Estimator {
+                p,
+                heights: [0.0; MARKERS],
+                positions: [0.0, 1.0, 2.0, 3.0, 4.0],
+                desired_positions: [0.0, 2.0 * p, 4.0 * p, 2.0 + 2.0 * p, 4.0],
+            }),
+        }
+    }
+
+    /// Returns the current estimates, or `None` if no items have been collected.
+    pub fn get(&self) -> Option<[f64; N]> {
+        match self.count {
+            0 => None,
+            count @ ..=MARKERS => {
+                let mut sorted = self.first;
+                let sorted = &mut sorted[..count];
+                sorted.sort_unstable_by(f64::total_cmp);
+
+                Some(array::from_fn(|i| {
+                    interpolate(sorted, self.estimators[i].p)
+                }))
+            }
+            _ => Some(array::from_fn(|i| self.estimators[i].heights[2])),
+        }
+    }
+}
+
+impl<const N: usize> CollectorBase for Quantiles<N> {
+    type Output = Option<[f64; N]>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.get()
+    }
+}
+
+impl<const N: usize> Collector<f64> for Quantiles<N> {
+    fn collect(&mut self, item: f64) -> ControlFlow<()> {
+        if self.count < MARKERS {
+            self.first[self.count] = item;
+            self.count += 1;
+
+            if self.count == MARKERS {
+                let mut sorted = self.first;
+                sorted.sort_unstable_by(f64::total_cmp);
+
+                for estimator in &mut self.estimators {
+                    estimator.heights = sorted;
+                }
+            }
+        } else {
+            self.count += 1;
+
+            for estimator in &mut self.estimators {
+                estimator.add(item);
+            }
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl Estimator {
+    fn add(&mut self, item: f64) {
+        let q = &mut self.heights;
+        let n = &mut self.positions;
+
+        // The cell the item falls in, extending the extremes if needed.
+        let cell = if item < q[0] {
+            q[0] = item;
+            0
+        } else if item >= q[MARKERS - 1] {
+            q[MARKERS - 1] = item;
+            MARKERS - 2
+        } else {
+            q[1..]
+                .iter()
+                .position(|&height| item < height)
+                .unwrap_or(MARKERS - 2)
+        };
+
+        for position in &mut n[cell + 1..] {
+            *position += 1.0;
+        }
+
+        let p = self.p;
+        let increments = [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0];
+        for (desired, increment) in self.desired_positions.iter_mut().zip(increments) {
+            *desired += increment;
+        }
+
+        for i in 1..MARKERS - 1 {
+            let d = self.desired_positions[i] - n[i];
+
+            if (d >= 1.0 && n[i + 1] - n[i] > 1.0) || (d <= -1.0 && n[i - 1] - n[i] < -1.0) {
+                let d = d.signum();
+                let parabolic = q[i]
+                    + d / (n[i + 1] - n[i - 1])
+                        * ((n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                            + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]));
+
+                q[i] = if q[i - 1] < parabolic && parabolic < q[i + 1] {
+                    parabolic
+                } else {
+                    // Falls back to the linear formula, towards the neighbor in direction `d`.
+                    let j = if d > 0.0 { i + 1 } else { i - 1 };
+                    q[i] + d * (q[j] - q[i]) / (n[j] - n[i])
+                };
+                n[i] += d;
+            }
+        }
+    }
+}
+
+// The quantile of sorted, non-empty items,
+// interpolating linearly between the two closest items.
+fn interpolate(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank as usize;
+    let fraction = rank - lower as f64;
+
+    match sorted.get(lower + 1) {
+        Some(&upper) if fraction > 0.0 => sorted[lower] + fraction * (upper - sorted[lower]),
+        _ => sorted[lower],
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(-100..100, ..20),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        let quantiles = [0.0, 0.25, 0.5, 0.9, 1.0];
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().map(|&num| f64::from(num)),
+            collector_factory: || Quantiles::new(quantiles),
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let mut sorted: Vec<_> = iter.collect();
+                sorted.sort_unstable_by(f64::total_cmp);
+
+                let is_correct = match output {
+                    None => sorted.is_empty(),
+                    // Exact.
+                    Some(estimates) if sorted.len() <= MARKERS => quantiles
+                        .iter()
+                        .zip(estimates)
+                        .all(|(&p, estimate)| estimate == interpolate(&sorted, p)),
+                    // Approximate, but never outside the range of the items.
+                    Some(estimates) => {
+                        let range = sorted[0]..=sorted[sorted.len() - 1];
+                        estimates.iter().all(|estimate| range.contains(estimate))
+                    }
+                };
+
+                if !is_correct {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
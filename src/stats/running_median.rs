@@ -0,0 +1,208 @@
+use std::ops::ControlFlow;
+
+use crate::collector::{Collector, CollectorBase, IntoCollector};
+
+use super::{Midpoint, two_heaps::TwoHeaps};
+
+/// A collector that feeds the median of the items collected so far
+/// into another collector, after each item.
+/// Its [`Output`](CollectorBase::Output) is that of the underlying collector.
+///
+/// When the number of items is even, the lower of the two middle items is fed
+/// if created with [`new()`](RunningMedian::new),
+/// so the median is always one of the items and no arithmetic is needed,
+/// or their [`Midpoint`] if created with [`averaging()`](RunningMedian::averaging).
+/// For the median of all items only, see [`Median`](super::Median).
+///
+/// It keeps all items in two heaps, so each item takes *O*(log *n*) time
+/// and one clone.
+///
+/// Items incomparable with themselves, such as NaN, are ignored,
+/// and no median is fed for them.
+/// If two other items are incomparable, the medians are unspecified.
+///
+/// This collector stops accumulating once the underlying collector has.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, stats::RunningMedian};
+///
+/// let medians = [5, 15, 1, 3, 8]
+///     .into_iter()
+///     .feed_into(RunningMedian::new(vec![]));
+///
+/// assert_eq!(medians, [5, 5, 5, 3, 5]);
+///
+/// let medians = [5, 15, 1, 3, 8]
+///     .into_iter()
+///     .feed_into(RunningMedian::averaging(vec![]));
+///
+/// assert_eq!(medians, [5, 10, 5, 4, 5]);
+/// ```
+///
+/// Only the latest median is often needed:
+///
+/// ```
+/// use komadori::{prelude::*, iter::Last, stats::RunningMedian};
+///
+/// let median = [40, 10, 30, 20]
+///     .into_iter()
+///     .feed_into(RunningMedian::new(Last::new()));
+///
+/// assert_eq!(median, Some(20));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RunningMedian<C, T> {
+    collector: C,
+    heaps: TwoHeaps<T>,
+    // Combines the two middle items. `None` picks the lower one.
+    average: Option<fn(T, T) -> T>,
+}
+
+impl<C, T> RunningMedian<C, T>
+where
+    C: Collector<T>,
+    T: PartialOrd + Clone,
+{
+    /// Creates a new instance of this collector feeding medians into a collector,
+    /// taking the lower of the two middle items if their number is even.
+    #[inline]
+    pub fn new(collector: impl IntoCollector<T, IntoCollector = C>) -> Self {
+        Self {
+            collector: collector.into_collector(),
+            heaps: TwoHeaps::new(),
+            average: None,
+        }
+    }
+
+    /// Creates a new instance of this collector feeding medians into a collector,
+    /// taking the midpoint of the two middle items if their number is even.
+    #[inline]
+    pub fn averaging(collector: impl IntoCollector<T, IntoCollector = C>) -> Self
+    where
+        T: Midpoint,
+    {
+        Self {
+            average: Some(T::midpoint),
+            ..Self::new(collector)
+        }
+    }
+}
+
+impl<C, T> CollectorBase for RunningMedian<C, T>
+where
+    C: CollectorBase,
+{
+    type Output = C::Output;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.collector.reserve(additional_min, additional_max);
+        self.heaps.reserve(additional_min);
+    }
+}
+
+impl<C, T> Collector<T> for RunningMedian<C, T>
+where
+    C: Collector<T>,
+    T: PartialOrd + Clone,
+{
+    fn collect(&mut self, item: T) -> ControlFlow<()> {
+        if !self.heaps.push(item) {
+            return self.collector.break_hint();
+        }
+
+        let median = match (self.heaps.mids(), self.average) {
+            (Some((lower_mid, Some(upper_mid))), Some(average)) => {
+                average(lower_mid.clone(), upper_mid.clone())
+            }
+            (Some((lower_mid, _)), _) => lower_mid.clone(),
+            // An item was just pushed.
+            (None, _) => unreachable!(),
+        };
+
+        self.collector.collect(median)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(-5..5, ..8),
+            take_count in ..=8_usize,
+            average in any::<bool>(),
+        ) {
+            all_collect_methods_impl(nums, take_count, average)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        take_count: usize,
+        average: bool,
+    ) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                let collector = vec![].into_collector().take(take_count);
+                if average {
+                    RunningMedian::averaging(collector)
+                } else {
+                    RunningMedian::new(collector)
+                }
+            },
+            should_break_pred: |iter| iter.count() >= take_count,
+            pred: |mut iter, output, remaining| {
+                let mut seen = vec![];
+                let expected: Vec<_> = iter
+                    .by_ref()
+                    .take(take_count)
+                    .map(|num| {
+                        seen.push(num);
+                        seen.sort_unstable();
+                        let len = seen.len();
+                        if average && len % 2 == 0 {
+                            seen[len / 2 - 1].midpoint(seen[len / 2])
+                        } else {
+                            seen[(len - 1) / 2]
+                        }
+                    })
+                    .collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,331 @@
+use std::ops::ControlFlow;
+
+use crate::{
+    collector::{Collector, CollectorBase, SnapshotCollector},
+    merge::Merge,
+};
+
+/// A collector that computes the count, minimum, maximum, sum, mean and variance
+/// of the numbers it collects, in one pass.
+/// Its [`Output`](CollectorBase::Output) is itself, from which the statistics are read.
+///
+/// The mean and variance are updated with Welford's algorithm,
+/// which stays accurate even when the numbers are large compared to their spread.
+/// Besides numbers and references to them, it collects slices of numbers,
+/// which are summarized in a faster batch before being merged.
+/// Summaries of separate shards can also be [`Merge`]d.
+///
+/// If any number is NaN, the sum, mean and variance are NaN,
+/// while the minimum and maximum ignore it.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, stats::Summary};
+///
+/// let summary = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+///     .into_iter()
+///     .feed_into(Summary::new());
+///
+/// assert_eq!(summary.count(), 8);
+/// assert_eq!(summary.min(), Some(2.0));
+/// assert_eq!(summary.max(), Some(9.0));
+/// assert_eq!(summary.sum(), 40.0);
+/// assert_eq!(summary.mean(), Some(5.0));
+/// assert_eq!(summary.variance(), Some(4.0));
+/// assert_eq!(summary.std_dev(), Some(2.0));
+/// ```
+///
+/// Summarizing batches of readings:
+///
+/// ```
+/// use komadori::{prelude::*, stats::Summary};
+///
+/// let batches: [&[f64]; 2] = [&[1.0, 2.0], &[3.0, 4.0, 5.0]];
+///
+/// let summary = batches.into_iter().feed_into(Summary::new());
+///
+/// assert_eq!(summary.count(), 5);
+/// assert_eq!(summary.mean(), Some(3.0));
+/// assert_eq!(summary.sample_variance(), Some(2.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    count: usize,
+    sum: f64,
+    mean: f64,
+    // The sum of squared differences from the mean.
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Summary {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Returns the number of numbers collected.
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the smallest number, or `None` if no numbers were collected.
+    #[inline]
+    pub const fn min(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    /// Returns the largest number, or `None` if no numbers were collected.
+    #[inline]
+    pub const fn max(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+
+    /// Returns the sum of the numbers, which is 0 if no numbers were collected.
+    #[inline]
+    pub const fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Returns the arithmetic mean, or `None` if no numbers were collected.
+    #[inline]
+    pub const fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+
+    /// Returns the population variance, or `None` if no numbers were collected.
+    #[inline]
+    pub fn variance(&self) -> Option<f64> {
+        (self.count != 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance, with Bessel's correction,
+    /// or `None` if fewer than 2 numbers were collected.
+    #[inline]
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Returns the population standard deviation, or `None` if no numbers were collected.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    #[inline]
+    fn push(&mut self, num: f64) {
+        self.count += 1;
+        self.sum += num;
+
+        let delta = num - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (num - self.mean);
+
+        self.min = self.min.min(num);
+        self.max = self.max.max(num);
+    }
+
+    // Two passes over the slice, which are easier to vectorize than Welford's updates.
+    fn of_slice(nums: &[f64]) -> Self {
+        if nums.is_empty() {
+            return Self::new();
+        }
+
+        let sum: f64 = nums.iter().sum();
+        let mean = sum / nums.len() as f64;
+        let (m2, min, max) = nums.iter().fold(
+            (0.0, f64::INFINITY, f64::NEG_INFINITY),
+            |(m2, min, max), &num| (m2 + (num - mean) * (num - mean), min.min(num), max.max(num)),
+        );
+
+        Self {
+            count: nums.len(),
+            sum,
+            mean,
+            m2,
+            min,
+            max,
+        }
+    }
+}
+
+impl Default for Summary {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Combines the summaries of two shards, with Chan et al.'s formula for the variance.
+impl Merge for Summary {
+    fn merge(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+impl CollectorBase for Summary {
+    type Output = Self;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self
+    }
+}
+
+impl SnapshotCollector for Summary {
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl Collector<f64> for Summary {
+    #[inline]
+    fn collect(&mut self, num: f64) -> ControlFlow<()> {
+        self.push(num);
+        ControlFlow::Continue(())
+    }
+
+    fn collect_many(&mut self, nums: impl IntoIterator<Item = f64>) -> ControlFlow<()> {
+        // Accumulating in a local lets the state stay in registers.
+        let mut batch = Self::new();
+        nums.into_iter().for_each(|num| batch.push(num));
+        self.merge(batch);
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'a> Collector<&'a f64> for Summary {
+    #[inline]
+    fn collect(&mut self, &num: &'a f64) -> ControlFlow<()> {
+        self.collect(num)
+    }
+
+    #[inline]
+    fn collect_many(&mut self, nums: impl IntoIterator<Item = &'a f64>) -> ControlFlow<()> {
+        self.collect_many(nums.into_iter().copied())
+    }
+}
+
+impl<'a> Collector<&'a [f64]> for Summary {
+    #[inline]
+    fn collect(&mut self, nums: &'a [f64]) -> ControlFlow<()> {
+        self.merge(Self::of_slice(nums));
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(-1000..1000, ..8),
+            chunk_size in 1..=3_usize,
+        ) {
+            all_collect_methods_impl(nums, chunk_size)?;
+        }
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>, chunk_size: usize) -> TestCaseResult {
+        let nums: Vec<_> = nums.into_iter().map(f64::from).collect();
+
+        let count = nums.len();
+        let sum: f64 = nums.iter().sum();
+        let mean = sum / count as f64;
+        let variance = nums.iter().map(|num| (num - mean).powi(2)).sum::<f64>() / count as f64;
+        let is_correct = |summary: Summary| {
+            let close = |x: f64, y: f64| (x - y).abs() <= 1e-9 * y.abs().max(1.0);
+
+            summary.count() == count
+                && summary.sum() == sum
+                && summary.min() == nums.iter().copied().reduce(f64::min)
+                && summary.max() == nums.iter().copied().reduce(f64::max)
+                && summary.mean().is_some_and(|m| close(m, mean)) == (count != 0)
+                && summary.variance().is_some_and(|v| close(v, variance)) == (count != 0)
+        };
+
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: Summary::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if !is_correct(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()?;
+
+        BasicCollectorTester {
+            iter_factory: || nums.chunks(chunk_size),
+            collector_factory: Summary::new,
+            should_break_pred: |_| false,
+            pred: |_, output, remaining| {
+                if !is_correct(output) {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,122 @@
+use std::cmp::{Ordering, Reverse};
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BinaryHeap;
+#[cfg(feature = "std")]
+use std::collections::BinaryHeap;
+
+/// The lower and upper halves of the items, shared by [`Median`](super::Median)
+/// and [`RunningMedian`](super::RunningMedian).
+#[derive(Debug, Clone)]
+pub(super) struct TwoHeaps<T> {
+    // The lower half. It has as many items as `upper`, or one more.
+    lower: BinaryHeap<Ordered<T>>,
+    upper: BinaryHeap<Reverse<Ordered<T>>>,
+}
+
+impl<T> TwoHeaps<T> {
+    #[inline]
+    pub(super) const fn new() -> Self {
+        Self {
+            lower: BinaryHeap::new(),
+            upper: BinaryHeap::new(),
+        }
+    }
+
+    #[inline]
+    pub(super) fn reserve(&mut self, additional: usize) {
+        let half = additional / 2;
+        self.lower.reserve(additional - half);
+        self.upper.reserve(half);
+    }
+}
+
+impl<T> TwoHeaps<T>
+where
+    T: PartialOrd,
+{
+    /// Adds an item, returning `false` without adding it
+    /// if it is incomparable with itself, such as NaN.
+    pub(super) fn push(&mut self, item: T) -> bool {
+        // Without such items, floats are totally ordered.
+        if item.partial_cmp(&item).is_none() {
+            return false;
+        }
+
+        let item = Ordered(item);
+
+        if self.lower.peek().is_none_or(|max| item <= *max) {
+            self.lower.push(item);
+
+            if self.lower.len() > self.upper.len() + 1
+                && let Some(max) = self.lower.pop()
+            {
+                self.upper.push(Reverse(max));
+            }
+        } else {
+            self.upper.push(Reverse(item));
+
+            if self.upper.len() > self.lower.len()
+                && let Some(Reverse(min)) = self.upper.pop()
+            {
+                self.lower.push(min);
+            }
+        }
+
+        true
+    }
+
+    /// Returns the middle item if the number of items is odd,
+    /// or the two middle items if it is even.
+    #[inline]
+    pub(super) fn mids(&self) -> Option<(&T, Option<&T>)> {
+        let Ordered(lower_mid) = self.lower.peek()?;
+        let upper_mid = (self.lower.len() == self.upper.len())
+            .then(|| self.upper.peek())
+            .flatten()
+            .map(|Reverse(Ordered(upper_mid))| upper_mid);
+
+        Some((lower_mid, upper_mid))
+    }
+
+    /// Same as [`mids()`](TwoHeaps::mids), but by value.
+    #[inline]
+    pub(super) fn into_mids(mut self) -> Option<(T, Option<T>)> {
+        let Ordered(lower_mid) = self.lower.pop()?;
+        let upper_mid = (self.lower.len() < self.upper.len())
+            .then(|| self.upper.pop())
+            .flatten()
+            .map(|Reverse(Ordered(upper_mid))| upper_mid);
+
+        Some((lower_mid, upper_mid))
+    }
+}
+
+// Orders a `PartialOrd` type.
+// Items incomparable with themselves are never pushed, so for floats this is a total order.
+// For other types, incomparable items are treated as equal.
+#[derive(Debug, Clone)]
+struct Ordered<T>(T);
+
+impl<T: PartialOrd> PartialEq for Ordered<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Ordered<T> {}
+
+impl<T: PartialOrd> PartialOrd for Ordered<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Ordered<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
@@ -0,0 +1,170 @@
+use std::ops::ControlFlow;
+
+use crate::{
+    collector::{Collector, CollectorBase, SnapshotCollector},
+    merge::Merge,
+};
+
+/// A collector that computes the weighted arithmetic mean of the `(value, weight)` pairs
+/// it collects.
+///
+/// Its [`Output`](CollectorBase::Output) is `None` if the weights add up to 0,
+/// including when it has not collected any pairs,
+/// or `Some` containing the weighted mean.
+/// Weighted means of separate shards can be [`Merge`]d.
+///
+/// If any value or weight is NaN, the mean is NaN.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, stats::WeightedMean};
+///
+/// struct Grade {
+///     score: f64,
+///     credits: u8,
+/// }
+///
+/// let transcript = [
+///     Grade { score: 3.0, credits: 4 },
+///     Grade { score: 4.0, credits: 3 },
+///     Grade { score: 2.0, credits: 1 },
+/// ];
+///
+/// let gpa = transcript
+///     .iter()
+///     .map(|grade| (grade.score, f64::from(grade.credits)))
+///     .feed_into(WeightedMean::new());
+///
+/// assert_eq!(gpa, Some(3.25));
+/// ```
+///
+/// The output is `None` if the weights add up to 0.
+///
+/// ```
+/// use komadori::{prelude::*, stats::WeightedMean};
+///
+/// assert_eq!([(5.0, 0.0)].into_iter().feed_into(WeightedMean::new()), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedMean {
+    weighted_sum: f64,
+    total_weight: f64,
+}
+
+impl WeightedMean {
+    /// Creates a new instance of this collector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            weighted_sum: 0.0,
+            total_weight: 0.0,
+        }
+    }
+
+    /// Returns the current weighted mean, or `None` if the weights add up to 0.
+    #[inline]
+    pub fn get(&self) -> Option<f64> {
+        (self.total_weight != 0.0).then(|| self.weighted_sum / self.total_weight)
+    }
+
+    /// Returns the sum of the weights.
+    #[inline]
+    pub const fn total_weight(&self) -> f64 {
+        self.total_weight
+    }
+}
+
+impl Default for WeightedMean {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Merge for WeightedMean {
+    #[inline]
+    fn merge(&mut self, other: Self) {
+        self.weighted_sum += other.weighted_sum;
+        self.total_weight += other.total_weight;
+    }
+}
+
+impl CollectorBase for WeightedMean {
+    type Output = Option<f64>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.get()
+    }
+}
+
+impl SnapshotCollector for WeightedMean {
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.get()
+    }
+}
+
+impl Collector<(f64, f64)> for WeightedMean {
+    #[inline]
+    fn collect(&mut self, (value, weight): (f64, f64)) -> ControlFlow<()> {
+        self.weighted_sum += value * weight;
+        self.total_weight += weight;
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'a> Collector<&'a (f64, f64)> for WeightedMean {
+    #[inline]
+    fn collect(&mut self, &pair: &'a (f64, f64)) -> ControlFlow<()> {
+        self.collect(pair)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            pairs in propvec((-100..100, 0..5), ..6),
+        ) {
+            all_collect_methods_impl(pairs)?;
+        }
+    }
+
+    fn all_collect_methods_impl(pairs: Vec<(i32, i32)>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || {
+                pairs
+                    .iter()
+                    .map(|&(value, weight)| (f64::from(value), f64::from(weight)))
+            },
+            collector_factory: WeightedMean::new,
+            should_break_pred: |_| false,
+            pred: |iter, output, remaining| {
+                let (weighted_sum, total_weight) =
+                    iter.fold((0.0, 0.0), |(sum, total), (v, w)| (sum + v * w, total + w));
+                // Exact, since the values and weights are small integers.
+                let expected = (total_weight != 0.0).then(|| weighted_sum / total_weight);
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if remaining.next().is_some() {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
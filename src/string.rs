@@ -19,6 +19,12 @@ use crate::{
     slice::{Concat, ConcatItem, ConcatItemSealed, ConcatSealed},
 };
 
+mod lines;
+mod utf8_decode;
+
+pub use lines::*;
+pub use utf8_decode::*;
+
 /// A collector that pushes `char`s into a [`String`].
 /// Its [`Output`] is [`String`].
 ///
@@ -27,6 +33,11 @@ use crate::{
 /// [`Collector`]: crate::collector::Collector
 /// [`Output`]: CollectorBase::Output
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct IntoCollector(String);
 
 /// A collector that pushes `char`s into a [`&mut String`](String).
@@ -68,6 +79,12 @@ impl CollectorBase for IntoCollector {
     fn finish(self) -> Self::Output {
         self.0
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        // Every `char` takes at least one byte.
+        self.0.reserve(additional_min);
+    }
 }
 
 impl Collector<char> for IntoCollector {
@@ -140,6 +157,12 @@ impl<'a> CollectorBase for CollectorMut<'a> {
     fn finish(self) -> Self::Output {
         self.0
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        // Every `char` takes at least one byte.
+        self.0.reserve(additional_min);
+    }
 }
 
 impl<'a> Collector<char> for CollectorMut<'a> {
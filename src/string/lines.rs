@@ -0,0 +1,180 @@
+use std::ops::ControlFlow;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::collector::{Collector, CollectorBase, IntoCollector};
+
+/// A collector that splits string chunks into lines and feeds each line into another collector.
+/// Its [`Output`](CollectorBase::Output) is that of the underlying collector.
+///
+/// It accepts `&str` and [`String`]. A line may span multiple chunks,
+/// so text read from a stream can be fed as is,
+/// and only the line in progress is buffered.
+///
+/// Lines are split the same way as [`str::lines()`]: they end with either `\n` or `\r\n`,
+/// which are not included in the lines fed to the underlying collector.
+/// The last line is fed on finishing, unless it is empty.
+///
+/// This collector stops accumulating once the underlying collector has.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, string::Lines};
+///
+/// let chunks = ["GET / HTTP/1.1\r\nHo", "st: example.com\r", "\n\r\nbody"];
+///
+/// let lines = chunks.into_iter().feed_into(Lines::new(vec![]));
+///
+/// assert_eq!(lines, ["GET / HTTP/1.1", "Host: example.com", "", "body"]);
+/// ```
+///
+/// ```
+/// use komadori::{prelude::*, string::Lines};
+///
+/// let mut chunks = ["a\nb", "\nc\n", "d"].into_iter();
+///
+/// let lines = chunks.by_ref().feed_into(Lines::new(vec![].into_collector().take(2)));
+///
+/// assert_eq!(lines, ["a", "b"]);
+/// assert_eq!(chunks.next(), Some("d"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lines<C> {
+    collector: C,
+    // The line in progress.
+    buf: String,
+}
+
+impl<C> Lines<C>
+where
+    C: Collector<String>,
+{
+    /// Creates a new instance of this collector feeding lines into a collector.
+    #[inline]
+    pub fn new(collector: impl IntoCollector<String, IntoCollector = C>) -> Self {
+        Self {
+            collector: collector.into_collector(),
+            buf: String::new(),
+        }
+    }
+
+    fn collect_str(&mut self, mut chunk: &str) -> ControlFlow<()> {
+        while let Some((line, rest)) = chunk.split_once('\n') {
+            self.buf.push_str(line);
+            // The `\r` may have come from the previous chunk.
+            if self.buf.ends_with('\r') {
+                self.buf.pop();
+            }
+
+            self.collector.collect(std::mem::take(&mut self.buf))?;
+            chunk = rest;
+        }
+
+        self.buf.push_str(chunk);
+        ControlFlow::Continue(())
+    }
+}
+
+impl<C> CollectorBase for Lines<C>
+where
+    C: Collector<String>,
+{
+    type Output = C::Output;
+
+    fn finish(mut self) -> Self::Output {
+        if !self.buf.is_empty() {
+            let _ = self.collector.collect(self.buf);
+        }
+
+        self.collector.finish()
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        self.collector.break_hint()
+    }
+}
+
+impl<C> Collector<&str> for Lines<C>
+where
+    C: Collector<String>,
+{
+    #[inline]
+    fn collect(&mut self, chunk: &str) -> ControlFlow<()> {
+        self.collect_str(chunk)
+    }
+}
+
+impl<C> Collector<String> for Lines<C>
+where
+    C: Collector<String>,
+{
+    #[inline]
+    fn collect(&mut self, chunk: String) -> ControlFlow<()> {
+        self.collect_str(&chunk)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            chunks in propvec("[ab\r\n]{0,4}", ..6),
+            take_count in ..=5_usize,
+        ) {
+            all_collect_methods_impl(chunks, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(chunks: Vec<String>, take_count: usize) -> TestCaseResult {
+        // The number of chunks until the underlying collector stops, if it does.
+        let stop_at = || {
+            if take_count == 0 {
+                return Some(0);
+            }
+
+            let mut newlines = 0;
+            chunks
+                .iter()
+                .position(|chunk| {
+                    newlines += chunk.matches('\n').count();
+                    newlines >= take_count
+                })
+                .map(|i| i + 1)
+        };
+
+        BasicCollectorTester {
+            iter_factory: || chunks.iter().map(String::as_str),
+            collector_factory: || Lines::new(vec![].into_collector().take(take_count)),
+            should_break_pred: |_| stop_at().is_some(),
+            pred: |iter, output, remaining| {
+                let text = chunks.concat();
+                let expected: Vec<_> = text.lines().take(take_count).collect();
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(stop_at().unwrap_or(chunks.len())).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
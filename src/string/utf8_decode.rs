@@ -0,0 +1,391 @@
+use std::{
+    fmt::{self, Display},
+    ops::ControlFlow,
+};
+
+use crate::collector::{Collector, CollectorBase, IntoCollector};
+
+/// A collector that decodes UTF-8 bytes into `char`s and feeds them into another collector.
+/// Its [`Output`](CollectorBase::Output) is `Result<C::Output, Utf8DecodeError>`.
+///
+/// It accepts `u8`, `&u8` and `&[u8]`. A `char` may span multiple items,
+/// so byte chunks read from a stream can be fed as is.
+///
+/// By default, decoding is strict: on an invalid sequence (including an incomplete one
+/// on finishing), this collector stops accumulating and the error is returned on finishing.
+/// With [`lossy()`](Utf8Decode::lossy), each invalid sequence is replaced with
+/// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER) instead,
+/// the same way as [`String::from_utf8_lossy()`], and the output is never an error.
+///
+/// This collector stops accumulating once the underlying collector has.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, string::Utf8Decode};
+///
+/// // "héllo" split in the middle of "é".
+/// let chunks: [&[u8]; 2] = [b"h\xC3", b"\xA9llo"];
+/// let s = chunks.into_iter().feed_into(Utf8Decode::new(String::new()));
+///
+/// assert_eq!(s.unwrap(), "héllo");
+/// ```
+///
+/// ```
+/// use komadori::{prelude::*, string::Utf8Decode};
+///
+/// let bytes = b"ok\xFFthen\xE2\x82";
+///
+/// let err = bytes.iter().feed_into(Utf8Decode::new(String::new())).unwrap_err();
+/// assert_eq!(err.valid_up_to(), 2);
+/// assert_eq!(err.error_len(), Some(1));
+///
+/// let s = bytes.iter().feed_into(Utf8Decode::new(String::new()).lossy());
+/// assert_eq!(s.unwrap(), "ok\u{FFFD}then\u{FFFD}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Utf8Decode<C> {
+    collector: C,
+    // The bytes of the incomplete `char` so far.
+    pending: [u8; 4],
+    pending_len: u8,
+    // The number of bytes of the incomplete `char`. Unspecified if there are no pending bytes.
+    width: u8,
+    // The number of bytes collected so far.
+    consumed: usize,
+    lossy: bool,
+    error: Option<Utf8DecodeError>,
+}
+
+/// The error returned by [`Utf8Decode`] when the bytes are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Utf8DecodeError {
+    valid_up_to: usize,
+    error_len: Option<u8>,
+}
+
+impl<C> Utf8Decode<C>
+where
+    C: Collector<char>,
+{
+    /// Creates a new instance of this collector feeding decoded `char`s into a collector.
+    #[inline]
+    pub fn new(collector: impl IntoCollector<char, IntoCollector = C>) -> Self {
+        Self {
+            collector: collector.into_collector(),
+            pending: [0; 4],
+            pending_len: 0,
+            width: 0,
+            consumed: 0,
+            lossy: false,
+            error: None,
+        }
+    }
+
+    /// Replaces invalid sequences with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER) instead of failing.
+    #[inline]
+    pub fn lossy(mut self) -> Self {
+        self.lossy = true;
+        self
+    }
+
+    fn fail(&mut self, error_len: Option<u8>) -> ControlFlow<()> {
+        let valid_up_to = self.consumed - usize::from(self.pending_len);
+        self.pending_len = 0;
+
+        if self.lossy {
+            self.collector.collect(char::REPLACEMENT_CHARACTER)
+        } else {
+            self.error = Some(Utf8DecodeError {
+                valid_up_to,
+                error_len,
+            });
+            ControlFlow::Break(())
+        }
+    }
+
+    fn decode(&mut self, byte: u8) -> ControlFlow<()> {
+        if self.pending_len > 0 {
+            // The valid range of the second byte depends on the first one,
+            // to reject overlong encodings, surrogates and code points beyond U+10FFFF.
+            let valid = match (self.pending[0], self.pending_len) {
+                (0xE0, 1) => (0xA0..=0xBF).contains(&byte),
+                (0xED, 1) => (0x80..=0x9F).contains(&byte),
+                (0xF0, 1) => (0x90..=0xBF).contains(&byte),
+                (0xF4, 1) => (0x80..=0x8F).contains(&byte),
+                _ => (0x80..=0xBF).contains(&byte),
+            };
+
+            if !valid {
+                // The pending bytes form an invalid sequence on their own,
+                // and this byte is decoded afresh.
+                let error_len = self.pending_len;
+                self.fail(Some(error_len))?;
+                return self.decode_fresh(byte);
+            }
+
+            self.pending[usize::from(self.pending_len)] = byte;
+            self.pending_len += 1;
+            self.consumed += 1;
+
+            if self.pending_len < self.width {
+                return ControlFlow::Continue(());
+            }
+
+            self.pending_len = 0;
+            let c = std::str::from_utf8(&self.pending[..usize::from(self.width)])
+                .ok()
+                .and_then(|s| s.chars().next())
+                .expect("the sequence should have been validated");
+            self.collector.collect(c)
+        } else {
+            self.decode_fresh(byte)
+        }
+    }
+
+    fn decode_fresh(&mut self, byte: u8) -> ControlFlow<()> {
+        self.consumed += 1;
+
+        let width = match byte {
+            0x00..=0x7F => return self.collector.collect(char::from(byte)),
+            0xC2..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF4 => 4,
+            _ => {
+                self.pending_len = 1;
+                return self.fail(Some(1));
+            }
+        };
+
+        self.pending[0] = byte;
+        self.pending_len = 1;
+        self.width = width;
+        ControlFlow::Continue(())
+    }
+}
+
+impl<C> CollectorBase for Utf8Decode<C>
+where
+    C: Collector<char>,
+{
+    type Output = Result<C::Output, Utf8DecodeError>;
+
+    fn finish(mut self) -> Self::Output {
+        if self.error.is_none() && self.pending_len > 0 {
+            // An incomplete sequence at the end.
+            let _ = self.fail(None);
+        }
+
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.collector.finish()),
+        }
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.error.is_some() {
+            ControlFlow::Break(())
+        } else {
+            self.collector.break_hint()
+        }
+    }
+}
+
+impl<C> Collector<u8> for Utf8Decode<C>
+where
+    C: Collector<char>,
+{
+    #[inline]
+    fn collect(&mut self, byte: u8) -> ControlFlow<()> {
+        self.decode(byte)
+    }
+}
+
+impl<C> Collector<&u8> for Utf8Decode<C>
+where
+    C: Collector<char>,
+{
+    #[inline]
+    fn collect(&mut self, &byte: &u8) -> ControlFlow<()> {
+        self.decode(byte)
+    }
+}
+
+impl<C> Collector<&[u8]> for Utf8Decode<C>
+where
+    C: Collector<char>,
+{
+    fn collect(&mut self, mut bytes: &[u8]) -> ControlFlow<()> {
+        // Finishes the pending `char` first so that the rest can be decoded in bulk.
+        while self.pending_len > 0 {
+            let Some((&byte, rest)) = bytes.split_first() else {
+                return ControlFlow::Continue(());
+            };
+
+            self.decode(byte)?;
+            bytes = rest;
+        }
+
+        let valid = bytes.utf8_chunks().next().map_or("", |chunk| chunk.valid());
+
+        self.consumed += valid.len();
+        self.collector.collect_many(valid.chars())?;
+
+        bytes[valid.len()..]
+            .iter()
+            .try_for_each(|&byte| self.decode(byte))
+    }
+}
+
+impl Utf8DecodeError {
+    /// Returns the number of bytes that were successfully decoded
+    /// before the invalid sequence.
+    ///
+    /// This counts all bytes collected, not only those in the item that caused the error.
+    #[inline]
+    pub const fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the length of the invalid sequence,
+    /// or [`None`] if the bytes ended in the middle of a `char`.
+    ///
+    /// This is the same as [`Utf8Error::error_len()`](std::str::Utf8Error::error_len).
+    #[inline]
+    pub const fn error_len(&self) -> Option<usize> {
+        match self.error_len {
+            Some(len) => Some(len as usize),
+            None => None,
+        }
+    }
+}
+
+impl Display for Utf8DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error_len {
+            Some(len) => write!(
+                f,
+                "invalid utf-8 sequence of {len} bytes from index {}",
+                self.valid_up_to,
+            ),
+            None => write!(
+                f,
+                "incomplete utf-8 byte sequence from index {}",
+                self.valid_up_to,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Utf8DecodeError {}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    // Mostly valid UTF-8, with some stray bytes.
+    fn chunks() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        let byte_or_char = prop_oneof![
+            3 => any::<char>().prop_map(|c| c.to_string().into_bytes()),
+            1 => any::<u8>().prop_map(|byte| vec![byte]),
+        ];
+
+        propvec(byte_or_char, ..8).prop_flat_map(|parts| {
+            let bytes = parts.concat();
+            let len = bytes.len();
+            // Splits at arbitrary points, possibly in the middle of a `char`.
+            propvec(0..=len, ..4).prop_map(move |mut cuts| {
+                cuts.sort_unstable();
+                let mut chunks = vec![];
+                let mut start = 0;
+                for cut in cuts.into_iter().chain([len]) {
+                    chunks.push(bytes[start..cut].to_vec());
+                    start = cut;
+                }
+                chunks
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            chunks in chunks(),
+            lossy in any::<bool>(),
+            take_count in ..=6_usize,
+        ) {
+            all_collect_methods_impl(chunks, lossy, take_count)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        chunks: Vec<Vec<u8>>,
+        lossy: bool,
+        take_count: usize,
+    ) -> TestCaseResult {
+        let bytes = chunks.concat();
+        let expected_chars: Vec<_> = String::from_utf8_lossy(&bytes).chars().collect();
+        // The number of `char`s decoded before the first error.
+        let valid_count = match std::str::from_utf8(&bytes) {
+            Ok(s) => s.chars().count(),
+            Err(e) => std::str::from_utf8(&bytes[..e.valid_up_to()])
+                .unwrap()
+                .chars()
+                .count(),
+        };
+
+        BasicCollectorTester {
+            iter_factory: || chunks.iter().map(Vec::as_slice),
+            collector_factory: || {
+                let decode = Utf8Decode::new(vec![].into_collector().take(take_count));
+                if lossy { decode.lossy() } else { decode }
+            },
+            should_break_pred: |_| {
+                if lossy {
+                    // An incomplete sequence at the end is only replaced on finishing.
+                    let incomplete_end = bytes.utf8_chunks().last().is_some_and(|chunk| {
+                        std::str::from_utf8(chunk.invalid()).is_err_and(|e| e.error_len().is_none())
+                    });
+                    expected_chars.len() - usize::from(incomplete_end) >= take_count
+                } else {
+                    take_count <= valid_count
+                        || std::str::from_utf8(&bytes).is_err_and(|e| e.error_len().is_some())
+                }
+            },
+            pred: |_, output, _| {
+                let correct = match (output, std::str::from_utf8(&bytes)) {
+                    (Ok(chars), _) if lossy => {
+                        chars.iter().eq(expected_chars.iter().take(take_count))
+                    }
+                    (Ok(chars), Ok(s)) => chars.iter().copied().eq(s.chars().take(take_count)),
+                    (Ok(chars), Err(_)) => {
+                        // Stopped by the underlying collector before the error.
+                        take_count <= valid_count && chars.len() == take_count
+                    }
+                    (Err(e), Err(expected)) if !lossy => {
+                        e.valid_up_to() == expected.valid_up_to()
+                            && e.error_len() == expected.error_len()
+                            && take_count > valid_count
+                    }
+                    _ => false,
+                };
+
+                if correct {
+                    Ok(())
+                } else {
+                    Err(PredError::IncorrectOutput)
+                }
+            },
+        }
+        .test_collector()
+    }
+}
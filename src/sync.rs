@@ -1,3 +1,5 @@
 //! This module corresponds to [`std::sync`].
 
+pub mod atomic;
+#[cfg(feature = "std")]
 pub mod mpsc;
@@ -0,0 +1,270 @@
+//! [`Collector`]s for atomic integers.
+//!
+//! A shared reference to an atomic integer (e.g., [`&AtomicUsize`](AtomicUsize))
+//! can be turned into a collector that adds every collected value to it.
+//! [`Counting`] adds 1 for every item collected instead, regardless of its type.
+//! This allows counters shared across threads to sit directly in a chain.
+//!
+//! All operations use [`Ordering::Relaxed`] and wrap around on overflow,
+//! similar to [`fetch_add()`](AtomicUsize::fetch_add).
+//!
+//! This module corresponds to [`std::sync::atomic`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::{fmt::Debug, ops::ControlFlow, sync::atomic::Ordering};
+
+#[cfg(target_has_atomic = "8")]
+use std::sync::atomic::{AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use std::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use std::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use std::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::atomic::{AtomicIsize, AtomicUsize};
+
+use crate::collector::{CollectorBase, IntoCollectorBase};
+
+/// A collector that adds every collected value to an atomic integer.
+/// Its [`Output`](CollectorBase::Output) is the reference to the atomic integer.
+///
+/// This struct is created by `(&AtomicXxx).into_collector()`
+/// and `AtomicXxx::collector()`.
+///
+/// # Examples
+///
+/// ```
+/// use std::{sync::atomic::{AtomicUsize, Ordering}, thread};
+/// use komadori::prelude::*;
+///
+/// let total = AtomicUsize::new(0);
+///
+/// thread::scope(|s| {
+///     s.spawn(|| (1..=10).feed_into(&total));
+///     s.spawn(|| [5, 6].into_iter().feed_into(total.collector()));
+/// });
+///
+/// assert_eq!(total.load(Ordering::Relaxed), 66);
+/// ```
+///
+/// [`Collector`]: crate::collector::Collector
+pub struct Collector<'a, A>(&'a A);
+
+/// A collector that adds 1 to an atomic integer for every item collected.
+/// Its [`Output`](CollectorBase::Output) is the reference to the atomic integer.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use komadori::{prelude::*, sync::atomic::Counting};
+///
+/// let processed = AtomicU64::new(0);
+///
+/// let words = "the noble and the singer"
+///     .split(' ')
+///     .feed_into(vec![].into_collector().tee(Counting::new(&processed)))
+///     .0;
+///
+/// assert_eq!(words.len(), 5);
+/// assert_eq!(processed.load(Ordering::Relaxed), 5);
+/// ```
+pub struct Counting<'a, A>(&'a A);
+
+impl<'a, A> Counting<'a, A> {
+    /// Creates a new instance of this collector counting into the given atomic integer.
+    #[inline]
+    pub const fn new(atomic: &'a A) -> Self {
+        Self(atomic)
+    }
+}
+
+macro_rules! atomic_impl {
+    ($atomic:ident, $int:ty, $width:literal) => {
+        #[cfg(target_has_atomic = $width)]
+        impl<'a> IntoCollectorBase for &'a $atomic {
+            type Output = Self;
+
+            type IntoCollector = Collector<'a, $atomic>;
+
+            #[inline]
+            fn into_collector(self) -> Self::IntoCollector {
+                Collector(self)
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl<'a> CollectorBase for Collector<'a, $atomic> {
+            type Output = &'a $atomic;
+
+            #[inline]
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl crate::collector::Collector<$int> for Collector<'_, $atomic> {
+            #[inline]
+            fn collect(&mut self, item: $int) -> ControlFlow<()> {
+                self.0.fetch_add(item, Ordering::Relaxed);
+                ControlFlow::Continue(())
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl crate::collector::Collector<&$int> for Collector<'_, $atomic> {
+            #[inline]
+            fn collect(&mut self, &item: &$int) -> ControlFlow<()> {
+                self.0.fetch_add(item, Ordering::Relaxed);
+                ControlFlow::Continue(())
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl crate::collector::Collector<&mut $int> for Collector<'_, $atomic> {
+            #[inline]
+            fn collect(&mut self, &mut item: &mut $int) -> ControlFlow<()> {
+                self.0.fetch_add(item, Ordering::Relaxed);
+                ControlFlow::Continue(())
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl<'a> CollectorBase for Counting<'a, $atomic> {
+            type Output = &'a $atomic;
+
+            #[inline]
+            fn finish(self) -> Self::Output {
+                self.0
+            }
+        }
+
+        #[cfg(target_has_atomic = $width)]
+        impl<T> crate::collector::Collector<T> for Counting<'_, $atomic> {
+            #[inline]
+            fn collect(&mut self, _item: T) -> ControlFlow<()> {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                ControlFlow::Continue(())
+            }
+        }
+    };
+}
+
+atomic_impl!(AtomicU8, u8, "8");
+atomic_impl!(AtomicU16, u16, "16");
+atomic_impl!(AtomicU32, u32, "32");
+atomic_impl!(AtomicU64, u64, "64");
+atomic_impl!(AtomicUsize, usize, "ptr");
+atomic_impl!(AtomicI8, i8, "8");
+atomic_impl!(AtomicI16, i16, "16");
+atomic_impl!(AtomicI32, i32, "32");
+atomic_impl!(AtomicI64, i64, "64");
+atomic_impl!(AtomicIsize, isize, "ptr");
+
+impl<A> Clone for Collector<'_, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for Collector<'_, A> {}
+
+impl<A> Clone for Counting<'_, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for Counting<'_, A> {}
+
+impl<A: Debug> Debug for Collector<'_, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Collector").field(&self.0).finish()
+    }
+}
+
+impl<A: Debug> Debug for Counting<'_, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Counting").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use super::Counting;
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+        ) {
+            all_collect_methods_impl(nums)?;
+        }
+
+        #[test]
+        fn all_collect_methods_counting(
+            nums in propvec(any::<i32>(), ..=5),
+        ) {
+            all_collect_methods_counting_impl(nums)?;
+        }
+    }
+
+    // A fresh atomic is needed for each collector, and the tester requires
+    // the collector to not borrow from anything local.
+    fn new_atomic() -> &'static AtomicI32 {
+        Box::leak(Box::new(AtomicI32::new(0)))
+    }
+
+    fn all_collect_methods_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                new_atomic()
+                    .into_collector()
+                    .map_output(|atomic| atomic.load(Ordering::Relaxed))
+            },
+            should_break_pred: |_| false,
+            pred: |mut iter, output, remaining| {
+                if output != iter.by_ref().fold(0, i32::wrapping_add) {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    fn all_collect_methods_counting_impl(nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                Counting::new(new_atomic()).map_output(|atomic| atomic.load(Ordering::Relaxed))
+            },
+            should_break_pred: |_| false,
+            pred: |mut iter, output, remaining| {
+                if output != iter.by_ref().count() as i32 {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -1,3 +0,0 @@
-mod collector_tester;
-
-pub use collector_tester::*;
@@ -0,0 +1,105 @@
+//! A [`proptest`]-based harness for checking [`Collector`] implementations.
+//!
+//! It checks that [`collect()`], [`collect_many()`] and [`collect_then_finish()`]
+//! agree with each other and with [`break_hint()`]:
+//! they must produce the same output, consume the same items from the iterator,
+//! and stop accumulating at the same point.
+//! This is the same harness this crate uses to test its own collectors.
+//!
+//! Most collectors only need [`BasicCollectorTester`].
+//! Implement [`CollectorTester`] directly for anything more involved,
+//! such as collectors or outputs borrowing from the tester.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::ops::ControlFlow;
+//! use komadori::{
+//!     prelude::*,
+//!     testkit::{
+//!         BasicCollectorTester, CollectorTesterExt, PredError,
+//!         proptest::{prelude::*, test_runner::TestCaseResult},
+//!     },
+//! };
+//!
+//! /// Sums numbers, and stops once the sum exceeds 100.
+//! struct SumTill100(u32);
+//!
+//! impl CollectorBase for SumTill100 {
+//!     type Output = u32;
+//!
+//!     fn finish(self) -> Self::Output {
+//!         self.0
+//!     }
+//!
+//!     fn break_hint(&self) -> ControlFlow<()> {
+//!         if self.0 > 100 {
+//!             ControlFlow::Break(())
+//!         } else {
+//!             ControlFlow::Continue(())
+//!         }
+//!     }
+//! }
+//!
+//! impl Collector<u32> for SumTill100 {
+//!     fn collect(&mut self, num: u32) -> ControlFlow<()> {
+//!         self.0 += num;
+//!         self.break_hint()
+//!     }
+//! }
+//!
+//! // The expected sum, and the number of items consumed if it stops.
+//! fn expected(nums: impl Iterator<Item = u32>) -> (u32, Option<usize>) {
+//!     let mut sum = 0;
+//!     for (idx, num) in nums.enumerate() {
+//!         sum += num;
+//!         if sum > 100 {
+//!             return (sum, Some(idx + 1));
+//!         }
+//!     }
+//!
+//!     (sum, None)
+//! }
+//!
+//! fn check(nums: Vec<u32>) -> TestCaseResult {
+//!     BasicCollectorTester {
+//!         iter_factory: || nums.iter().copied(),
+//!         collector_factory: || SumTill100(0),
+//!         should_break_pred: |iter| expected(iter).1.is_some(),
+//!         pred: |iter, output, remaining| {
+//!             let (sum, consumed) = expected(iter.clone());
+//!
+//!             if output != sum {
+//!                 Err(PredError::IncorrectOutput)
+//!             } else if iter.skip(consumed.unwrap_or(nums.len())).ne(remaining) {
+//!                 Err(PredError::IncorrectIterConsumption)
+//!             } else {
+//!                 Ok(())
+//!             }
+//!         },
+//!     }
+//!     .test_collector()
+//! }
+//!
+//! // Typically placed in a `#[test]` function inside `proptest! { ... }`.
+//! proptest!(|(nums in prop::collection::vec(0..50_u32, ..10))| {
+//!     check(nums)?;
+//! });
+//! ```
+//!
+//! [`proptest`]: https://docs.rs/proptest
+//! [`Collector`]: crate::collector::Collector
+//! [`collect()`]: crate::collector::Collector::collect
+//! [`collect_many()`]: crate::collector::Collector::collect_many
+//! [`collect_then_finish()`]: crate::collector::Collector::collect_then_finish
+//! [`break_hint()`]: crate::collector::CollectorBase::break_hint
+
+mod collector_tester;
+
+pub use collector_tester::*;
+
+/// The version of [`proptest`](https://docs.rs/proptest) this module is built on.
+///
+/// Use it to make sure [`TestCaseResult`](proptest::test_runner::TestCaseResult)
+/// and other types line up with this module.
+pub use proptest;
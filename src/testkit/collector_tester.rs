@@ -6,6 +6,8 @@ use crate::collector::{Collector, CollectorBase};
 
 /// Test helper that returns parts needed for collector proptest.
 ///
+/// Use [`CollectorTesterExt::test_collector()`] to run the checks.
+///
 /// # Notes
 ///
 /// The [`Output`] should be reset for every call. May not needed
@@ -13,13 +15,23 @@ use crate::collector::{Collector, CollectorBase};
 ///
 /// [`Output`]: CollectorTester::Output
 pub trait CollectorTester {
+    /// The item type of the collector.
     type Item<'a>
     where
         Self: 'a;
+    /// The output type of the collector.
     type Output<'a>
     where
         Self: 'a;
 
+    /// Returns a fresh set of parts for one round of checks.
+    ///
+    /// It is called once for each of [`collect()`], [`collect_many()`]
+    /// and [`collect_then_finish()`], and every call should return equivalent parts.
+    ///
+    /// [`collect()`]: Collector::collect
+    /// [`collect_many()`]: Collector::collect_many
+    /// [`collect_then_finish()`]: Collector::collect_then_finish
     #[allow(clippy::type_complexity)] // Can't satisfy it so I suppress it.
     fn collector_test_parts<'a>(
         &'a mut self,
@@ -50,6 +62,9 @@ where
     /// - Output of the collector.
     /// - Remaining of the iterator after the operation.
     pub pred: P,
+    /// Items fed after the collector has stopped, if it has,
+    /// which it must reject. [`None`] skips this check,
+    /// which is appropriate for collectors that are not fused.
     pub iter_for_fuse_test: Option<IF>,
 }
 
@@ -58,7 +73,7 @@ where
 pub enum PredError {
     /// Incorrect [`Output`] produced by the collector
     ///
-    /// [`Output`]: crate::collector::Collector::Output
+    /// [`Output`]: crate::collector::CollectorBase::Output
     IncorrectOutput,
     /// The [`Iterator`] is not consumed as expected.
     IncorrectIterConsumption,
@@ -85,8 +100,18 @@ impl From<OfMethod> for TestCaseError {
     }
 }
 
-/// Used because we don't want the user to override any methods here.
+/// Extends [`CollectorTester`] with the method running the checks.
+///
+/// It is a separate trait so that the checks cannot be overridden.
+/// It is automatically implemented for all [`CollectorTester`] types.
 pub trait CollectorTesterExt: CollectorTester {
+    /// Checks that [`collect()`], [`collect_many()`] and [`collect_then_finish()`]
+    /// behave consistently with each other and with the parts
+    /// returned by [`collector_test_parts()`](CollectorTester::collector_test_parts).
+    ///
+    /// [`collect()`]: Collector::collect
+    /// [`collect_many()`]: Collector::collect_many
+    /// [`collect_then_finish()`]: Collector::collect_then_finish
     fn test_collector(&mut self) -> TestCaseResult {
         test_collector_part(self)
     }
@@ -107,9 +132,15 @@ where
     SbPred: FnMut(I) -> bool,
     Pred: FnMut(I, C::Output, &mut dyn Iterator<Item = I::Item>) -> Result<(), PredError>,
 {
+    /// Creates the iterator to feed the collector.
     pub iter_factory: ItFac,
+    /// Creates the collector to be tested.
     pub collector_factory: ClFac,
+    /// Determines, from a fresh iterator, whether the collector
+    /// should have stopped accumulating after being fed.
     pub should_break_pred: SbPred,
+    /// Checks the output and the remaining items of the iterator,
+    /// given a fresh iterator to compute the expectation from.
     pub pred: Pred,
 }
 
@@ -160,6 +191,8 @@ where
     }
 }
 
+/// Returns [`None`] as [`CollectorTestParts::iter_for_fuse_test`],
+/// skipping the check.
 pub fn none_iter_for_fuse_test<T>() -> Option<impl Iterator<Item = T>> {
     None::<std::iter::Empty<T>>
 }
@@ -0,0 +1,9 @@
+//! [`Collector`]s that analyze text.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! [`Collector`]: crate::collector::Collector
+
+mod bracket_balance;
+
+pub use bracket_balance::*;
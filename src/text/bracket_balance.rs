@@ -0,0 +1,280 @@
+use std::ops::ControlFlow;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that checks whether delimiter pairs (e.g., brackets) are balanced,
+/// tracking their nesting depth.
+/// Its [`Output`](CollectorBase::Output) is [`Balance`].
+///
+/// It accepts `char`, `&char`, `u8` and `&u8`. Bytes are compared as Latin-1 characters,
+/// which works for ASCII delimiters in UTF-8 text.
+/// Characters that are not delimiters are ignored.
+///
+/// A delimiter pair may have the same opening and closing character (e.g., quotes).
+/// Such a character closes the pair if it is the innermost one still open,
+/// and opens a new pair otherwise.
+///
+/// The stream becomes imbalanced as soon as a closing delimiter does not match
+/// the innermost opening one. From then on, items are ignored,
+/// and if [`stop_on_imbalance()`](Self::stop_on_imbalance) is set,
+/// this collector stops accumulating.
+/// It is also imbalanced if some delimiters are still open on finishing.
+///
+/// # Examples
+///
+/// ```
+/// use komadori::{prelude::*, text::{Balance, BracketBalance}};
+///
+/// let balance = "fn main() { let v = [(1, 2)]; }"
+///     .chars()
+///     .feed_into(BracketBalance::new());
+///
+/// assert_eq!(balance, Balance { max_depth: 3, balanced: true });
+///
+/// let balance = "([)]".chars().feed_into(BracketBalance::new());
+///
+/// assert_eq!(balance, Balance { max_depth: 2, balanced: false });
+/// ```
+///
+/// Stopping early on bytes:
+///
+/// ```
+/// use komadori::{prelude::*, text::BracketBalance};
+///
+/// let mut bytes = b"(a]b)c".iter();
+/// let balance = bytes
+///     .by_ref()
+///     .feed_into(BracketBalance::new().stop_on_imbalance());
+///
+/// assert!(!balance.balanced);
+/// assert_eq!(bytes.as_slice(), b"b)c");
+/// ```
+///
+/// Custom delimiters:
+///
+/// ```
+/// use komadori::{prelude::*, text::BracketBalance};
+///
+/// let balance = "'a' + ('b')'"
+///     .chars()
+///     .feed_into(BracketBalance::with_pairs([('(', ')'), ('\'', '\'')]));
+///
+/// // The last quote is left open.
+/// assert_eq!(balance.max_depth, 2);
+/// assert!(!balance.balanced);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BracketBalance {
+    pairs: Vec<(char, char)>,
+    // The closing delimiters expected, innermost last.
+    stack: Vec<char>,
+    max_depth: usize,
+    imbalanced: bool,
+    stop_on_imbalance: bool,
+}
+
+/// The [`Output`](CollectorBase::Output) of [`BracketBalance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Balance {
+    /// The maximum nesting depth reached (before the stream became imbalanced, if it did).
+    pub max_depth: usize,
+    /// Whether every opening delimiter was closed by the matching closing one, in order.
+    pub balanced: bool,
+}
+
+impl BracketBalance {
+    /// Creates a new instance of this collector checking `()`, `[]` and `{}`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_pairs([('(', ')'), ('[', ']'), ('{', '}')])
+    }
+
+    /// Creates a new instance of this collector checking the given
+    /// pairs of opening and closing delimiters.
+    #[inline]
+    pub fn with_pairs(pairs: impl IntoIterator<Item = (char, char)>) -> Self {
+        Self {
+            pairs: pairs.into_iter().collect(),
+            stack: vec![],
+            max_depth: 0,
+            imbalanced: false,
+            stop_on_imbalance: false,
+        }
+    }
+
+    /// Makes this collector stop accumulating as soon as the stream becomes imbalanced.
+    #[inline]
+    pub fn stop_on_imbalance(mut self) -> Self {
+        self.stop_on_imbalance = true;
+        self
+    }
+
+    /// Returns the current nesting depth.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    fn collect_char(&mut self, ch: char) -> ControlFlow<()> {
+        if self.imbalanced {
+            return self.break_hint();
+        }
+
+        if self.stack.last() == Some(&ch) {
+            self.stack.pop();
+        } else if let Some(&(_, close)) = self.pairs.iter().find(|&&(open, _)| open == ch) {
+            self.stack.push(close);
+            self.max_depth = self.max_depth.max(self.stack.len());
+        } else if self.pairs.iter().any(|&(_, close)| close == ch) {
+            self.imbalanced = true;
+            return self.break_hint();
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl CollectorBase for BracketBalance {
+    type Output = Balance;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        Balance {
+            max_depth: self.max_depth,
+            balanced: !self.imbalanced && self.stack.is_empty(),
+        }
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        if self.imbalanced && self.stop_on_imbalance {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl Collector<char> for BracketBalance {
+    #[inline]
+    fn collect(&mut self, ch: char) -> ControlFlow<()> {
+        self.collect_char(ch)
+    }
+}
+
+impl Collector<&char> for BracketBalance {
+    #[inline]
+    fn collect(&mut self, &ch: &char) -> ControlFlow<()> {
+        self.collect_char(ch)
+    }
+}
+
+impl Collector<u8> for BracketBalance {
+    #[inline]
+    fn collect(&mut self, byte: u8) -> ControlFlow<()> {
+        self.collect_char(byte.into())
+    }
+}
+
+impl Collector<&u8> for BracketBalance {
+    #[inline]
+    fn collect(&mut self, &byte: &u8) -> ControlFlow<()> {
+        self.collect_char(byte.into())
+    }
+}
+
+impl Default for BracketBalance {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            chars in propvec(prop::sample::select(&['(', ')', '[', ']', 'x'][..]), ..=9),
+            stop_on_imbalance in any::<bool>(),
+        ) {
+            all_collect_methods_impl(chars, stop_on_imbalance)?;
+        }
+    }
+
+    fn all_collect_methods_impl(chars: Vec<char>, stop_on_imbalance: bool) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || chars.iter().copied(),
+            collector_factory: || {
+                let collector = BracketBalance::new();
+                if stop_on_imbalance {
+                    collector.stop_on_imbalance()
+                } else {
+                    collector
+                }
+            },
+            should_break_pred: |iter| stop_on_imbalance && iter_output(iter).1 > 0,
+            pred: |iter, output, remaining| {
+                let (expected, consumed) = iter_output(iter.clone());
+                let consumed = if stop_on_imbalance && consumed > 0 {
+                    consumed
+                } else {
+                    chars.len()
+                };
+
+                if output != expected {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(consumed).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+
+    // Also returns the number of items up to and including the first unmatched closing one,
+    // or 0 if there is none.
+    fn iter_output(iter: impl IntoIterator<Item = char>) -> (Balance, usize) {
+        let mut stack = vec![];
+        let mut max_depth = 0;
+
+        for (idx, ch) in iter.into_iter().enumerate() {
+            match ch {
+                '(' => stack.push(')'),
+                '[' => stack.push(']'),
+                ')' | ']' => {
+                    let matched = stack.pop() == Some(ch);
+                    if !matched {
+                        let balance = Balance {
+                            max_depth,
+                            balanced: false,
+                        };
+                        return (balance, idx + 1);
+                    }
+                }
+                _ => {}
+            }
+
+            max_depth = max_depth.max(stack.len());
+        }
+
+        let balance = Balance {
+            max_depth,
+            balanced: stack.is_empty(),
+        };
+        (balance, 0)
+    }
+}
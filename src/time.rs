@@ -0,0 +1,147 @@
+//! Clocks for time-based collectors.
+//!
+//! Time-based collectors read the current time from a [`Clock`] instead of
+//! calling [`Instant::now()`] directly. By default, they use [`SystemClock`],
+//! which is zero-sized and forwards to [`Instant::now()`], so it costs nothing.
+//! In tests, a [`MockClock`] can be passed instead, so that time-dependent pipelines
+//! can be tested deterministically.
+//!
+//! This module corresponds to [`std::time`].
+
+use std::{
+    cell::Cell,
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// A source of the current time.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use komadori::time::{Clock, MockClock};
+///
+/// fn elapsed_since(clock: &impl Clock, start: std::time::Instant) -> Duration {
+///     clock.now() - start
+/// }
+///
+/// let clock = MockClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(3));
+///
+/// assert_eq!(elapsed_since(&clock, start), Duration::from_secs(3));
+/// ```
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    #[inline]
+    fn now(&self) -> Instant {
+        C::now(self)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Rc<C> {
+    #[inline]
+    fn now(&self) -> Instant {
+        C::now(self)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    #[inline]
+    fn now(&self) -> Instant {
+        C::now(self)
+    }
+}
+
+/// The system's monotonic clock, reading the time with [`Instant::now()`].
+///
+/// This is the default clock of time-based collectors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only advances when told to, for deterministic tests.
+///
+/// Since it advances through a shared reference, it can be passed to a collector
+/// by reference (or in an [`Rc`]) while the test keeps advancing it.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use komadori::time::{Clock, MockClock};
+///
+/// let clock = MockClock::new();
+/// let start = clock.now();
+///
+/// // The time does not pass by itself.
+/// assert_eq!(clock.now(), start);
+///
+/// clock.advance(Duration::from_millis(1500));
+/// assert_eq!(clock.now() - start, Duration::from_millis(1500));
+/// assert_eq!(clock.elapsed(), Duration::from_millis(1500));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    start: Instant,
+    elapsed: Cell<Duration>,
+}
+
+impl MockClock {
+    /// Creates a new clock, starting at the current time of the system.
+    #[inline]
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Creates a new clock, starting at the given time.
+    #[inline]
+    pub const fn starting_at(start: Instant) -> Self {
+        Self {
+            start,
+            elapsed: Cell::new(Duration::ZERO),
+        }
+    }
+
+    /// Advances the clock by the given duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time overflows.
+    #[inline]
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed.set(self.elapsed.get() + duration);
+    }
+
+    /// Returns the duration the clock has advanced since it started.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed.get()
+    }
+}
+
+impl Default for MockClock {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+}
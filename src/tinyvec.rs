@@ -0,0 +1,26 @@
+//! [`Collector`]s for [`tinyvec::ArrayVec`] and [`tinyvec::TinyVec`].
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::prelude::*;
+//! use tinyvec::ArrayVec;
+//!
+//! let mut nums = 1..;
+//! let vec = nums
+//!     .by_ref()
+//!     .feed_into(ArrayVec::<[i32; 3]>::new());
+//!
+//! assert_eq!(vec.as_slice(), [1, 2, 3]);
+//! // Nothing more is consumed once the vector is full.
+//! assert_eq!(nums.next(), Some(4));
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+
+pub mod array_vec;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod tiny_vec;
@@ -0,0 +1,198 @@
+//! [`Collector`]s for [`tinyvec::ArrayVec`].
+//!
+//! [`Collector`]: crate::collector::Collector
+
+use std::{fmt::Debug, ops::ControlFlow};
+
+use tinyvec::{Array, ArrayVec};
+
+use crate::collector::{Collector, CollectorBase};
+
+/// A collector that pushes collected items into an [`ArrayVec`].
+/// Its [`Output`] is [`ArrayVec`].
+///
+/// It stops accumulating once the vector is full.
+///
+/// This struct is created by `ArrayVec::into_collector()`.
+///
+/// [`Output`]: CollectorBase::Output
+pub struct IntoCollector<A: Array>(ArrayVec<A>);
+
+/// A collector that pushes collected items into a [`&mut ArrayVec`](ArrayVec).
+/// Its [`Output`] is [`&mut ArrayVec`](ArrayVec).
+///
+/// It stops accumulating once the vector is full.
+///
+/// This struct is created by `ArrayVec::collector_mut()`.
+///
+/// [`Output`]: CollectorBase::Output
+pub struct CollectorMut<'a, A: Array>(&'a mut ArrayVec<A>);
+
+impl<A: Array> crate::collector::IntoCollectorBase for ArrayVec<A> {
+    type Output = Self;
+
+    type IntoCollector = IntoCollector<A>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        IntoCollector(self)
+    }
+}
+
+impl<'a, A: Array> crate::collector::IntoCollectorBase for &'a mut ArrayVec<A> {
+    type Output = Self;
+
+    type IntoCollector = CollectorMut<'a, A>;
+
+    #[inline]
+    fn into_collector(self) -> Self::IntoCollector {
+        CollectorMut(self)
+    }
+}
+
+#[inline]
+fn push<A: Array>(vec: &mut ArrayVec<A>, item: A::Item) -> ControlFlow<()> {
+    // The item is dropped if it doesn't fit, which only happens
+    // if the caller ignores the previous `Break`.
+    if vec.try_push(item).is_some() || vec.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+#[inline]
+fn break_hint<A: Array>(vec: &ArrayVec<A>) -> ControlFlow<()> {
+    if vec.is_full() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<A: Array> CollectorBase for IntoCollector<A> {
+    type Output = ArrayVec<A>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(&self.0)
+    }
+}
+
+impl<A: Array> Collector<A::Item> for IntoCollector<A> {
+    #[inline]
+    fn collect(&mut self, item: A::Item) -> ControlFlow<()> {
+        push(&mut self.0, item)
+    }
+}
+
+impl<'a, A: Array> CollectorBase for CollectorMut<'a, A> {
+    type Output = &'a mut ArrayVec<A>;
+
+    #[inline]
+    fn finish(self) -> Self::Output {
+        self.0
+    }
+
+    #[inline]
+    fn break_hint(&self) -> ControlFlow<()> {
+        break_hint(self.0)
+    }
+}
+
+impl<A: Array> Collector<A::Item> for CollectorMut<'_, A> {
+    #[inline]
+    fn collect(&mut self, item: A::Item) -> ControlFlow<()> {
+        push(self.0, item)
+    }
+}
+
+// Derives would bound `A` instead of `A::Item`.
+impl<A: Array> Clone for IntoCollector<A>
+where
+    A: Clone,
+    A::Item: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<A: Array> Debug for IntoCollector<A>
+where
+    A::Item: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IntoCollector").field(&self.0).finish()
+    }
+}
+
+impl<A: Array> Debug for CollectorMut<'_, A>
+where
+    A::Item: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CollectorMut").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    use super::*;
+
+    const CAP: usize = 4;
+
+    proptest! {
+        #[test]
+        fn all_collect_methods(
+            starting_nums in propvec(any::<i32>(), ..=CAP),
+            nums in propvec(any::<i32>(), ..=6),
+        ) {
+            all_collect_methods_impl(starting_nums, nums)?;
+        }
+    }
+
+    fn all_collect_methods_impl(starting_nums: Vec<i32>, nums: Vec<i32>) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                starting_nums
+                    .iter()
+                    .copied()
+                    .collect::<ArrayVec<[i32; CAP]>>()
+                    .into_collector()
+            },
+            should_break_pred: |iter| starting_nums.len() + iter.count() >= CAP,
+            pred: |mut iter, output, remaining| {
+                let free = CAP - starting_nums.len();
+                let expected: Vec<_> = starting_nums
+                    .iter()
+                    .copied()
+                    .chain(iter.by_ref().take(free))
+                    .collect();
+
+                if output[..] != expected[..] {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
@@ -0,0 +1,221 @@
+//! [`Collector`]s for tuples.
+//!
+//! A tuple of up to 12 collectors (or types implementing [`IntoCollectorBase`])
+//! is also a collector. Every item is fed to each element that has not stopped
+//! accumulating, in order. Each element but the last receives a clone of the item,
+//! and the last one takes the ownership of it.
+//! The collector only stops when **all** of its elements have stopped.
+//!
+//! Unlike chaining [`tee()`], the [`Output`] is a flat tuple of the elements' outputs.
+//!
+//! # Examples
+//!
+//! ```
+//! use komadori::{prelude::*, cmp::Max, iter::{Count, Last}};
+//!
+//! let (count, max, last, nums) = [4, 2, 6, 3]
+//!     .into_iter()
+//!     .feed_into((Count::new(), Max::new(), Last::new(), vec![]));
+//!
+//! assert_eq!(count, 4);
+//! assert_eq!(max, Some(6));
+//! assert_eq!(last, Some(3));
+//! assert_eq!(nums, [4, 2, 6, 3]);
+//! ```
+//!
+//! [`Collector`]: crate::collector::Collector
+//! [`tee()`]: CollectorBase::tee
+//! [`Output`]: CollectorBase::Output
+
+use std::ops::ControlFlow;
+
+use crate::collector::{CollectorBase, IntoCollectorBase};
+
+/// A collector that feeds every item to each collector in a tuple.
+/// Its [`Output`](CollectorBase::Output) is a tuple of outputs of the underlying collectors.
+///
+/// This struct is created by `(C1, C2, ...).into_collector()`.
+/// See the [module-level documentation](self) for more.
+///
+/// [`Collector`]: crate::collector::Collector
+#[derive(Debug, Clone)]
+pub struct Collector<C> {
+    collectors: C,
+    // One bit per element, set when that element has stopped accumulating.
+    stopped: u16,
+}
+
+impl<C> Collector<C> {
+    #[inline]
+    fn has_stopped(&self, idx: usize) -> bool {
+        self.stopped & (1 << idx) != 0
+    }
+
+    #[inline]
+    fn mark_stopped(&mut self, idx: usize, cf: ControlFlow<()>) {
+        if cf.is_break() {
+            self.stopped |= 1 << idx;
+        }
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($idx:tt $cs:ident),*; $last_idx:tt $c_last:ident) => {
+        impl<$($cs,)* $c_last> IntoCollectorBase for ($($cs,)* $c_last,)
+        where
+            $($cs: IntoCollectorBase,)*
+            $c_last: IntoCollectorBase,
+        {
+            type Output = ($($cs::Output,)* $c_last::Output,);
+
+            type IntoCollector = Collector<($($cs::IntoCollector,)* $c_last::IntoCollector,)>;
+
+            #[inline]
+            fn into_collector(self) -> Self::IntoCollector {
+                let collectors = ($(self.$idx.into_collector(),)* self.$last_idx.into_collector(),);
+                let mut stopped = 0;
+                $(
+                    if collectors.$idx.break_hint().is_break() {
+                        stopped |= 1 << $idx;
+                    }
+                )*
+                if collectors.$last_idx.break_hint().is_break() {
+                    stopped |= 1 << $last_idx;
+                }
+
+                Collector {
+                    collectors,
+                    stopped,
+                }
+            }
+        }
+
+        impl<$($cs,)* $c_last> CollectorBase for Collector<($($cs,)* $c_last,)>
+        where
+            $($cs: CollectorBase,)*
+            $c_last: CollectorBase,
+        {
+            type Output = ($($cs::Output,)* $c_last::Output,);
+
+            #[inline]
+            fn finish(self) -> Self::Output {
+                ($(self.collectors.$idx.finish(),)* self.collectors.$last_idx.finish(),)
+            }
+
+            #[inline]
+            fn break_hint(&self) -> ControlFlow<()> {
+                if self.stopped == (1 << ($last_idx + 1)) - 1 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            }
+
+            #[inline]
+            fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+                $(
+                    if !self.has_stopped($idx) {
+                        self.collectors.$idx.reserve(additional_min, additional_max);
+                    }
+                )*
+                if !self.has_stopped($last_idx) {
+                    self.collectors.$last_idx.reserve(additional_min, additional_max);
+                }
+            }
+        }
+
+        impl<T, $($cs,)* $c_last> crate::collector::Collector<T>
+            for Collector<($($cs,)* $c_last,)>
+        where
+            T: Clone,
+            $($cs: crate::collector::Collector<T>,)*
+            $c_last: crate::collector::Collector<T>,
+        {
+            fn collect(&mut self, item: T) -> ControlFlow<()> {
+                $(
+                    if !self.has_stopped($idx) {
+                        let cf = self.collectors.$idx.collect(item.clone());
+                        self.mark_stopped($idx, cf);
+                    }
+                )*
+                if !self.has_stopped($last_idx) {
+                    let cf = self.collectors.$last_idx.collect(item);
+                    self.mark_stopped($last_idx, cf);
+                }
+
+                self.break_hint()
+            }
+        }
+    };
+}
+
+tuple_impl!(0 C0; 1 C1);
+tuple_impl!(0 C0, 1 C1; 2 C2);
+tuple_impl!(0 C0, 1 C1, 2 C2; 3 C3);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3; 4 C4);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4; 5 C5);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5; 6 C6);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6; 7 C7);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7; 8 C8);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7, 8 C8; 9 C9);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7, 8 C8, 9 C9; 10 C10);
+tuple_impl!(0 C0, 1 C1, 2 C2, 3 C3, 4 C4, 5 C5, 6 C6, 7 C7, 8 C8, 9 C9, 10 C10; 11 C11);
+
+#[cfg(all(test, feature = "std"))]
+mod proptests {
+    use proptest::collection::vec as propvec;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestCaseResult;
+
+    use crate::prelude::*;
+    use crate::testkit::{BasicCollectorTester, CollectorTesterExt, PredError};
+
+    proptest! {
+        /// Precondition:
+        /// - [`crate::collector::Collector::take()`]
+        /// - [`crate::vec::IntoCollector`]
+        #[test]
+        fn all_collect_methods(
+            nums in propvec(any::<i32>(), ..=5),
+            counts in (..=5_usize, ..=5_usize, ..=5_usize),
+        ) {
+            all_collect_methods_impl(nums, counts)?;
+        }
+    }
+
+    fn all_collect_methods_impl(
+        nums: Vec<i32>,
+        (count0, count1, count2): (usize, usize, usize),
+    ) -> TestCaseResult {
+        BasicCollectorTester {
+            iter_factory: || nums.iter().copied(),
+            collector_factory: || {
+                (
+                    vec![].into_collector().take(count0),
+                    vec![].into_collector().take(count1),
+                    vec![].into_collector().take(count2),
+                )
+                    .into_collector()
+            },
+            should_break_pred: |iter| iter.count() >= count0.max(count1).max(count2),
+            pred: |iter, output, remaining| {
+                let max_count = count0.max(count1).max(count2);
+
+                if output
+                    != (
+                        iter.clone().take(count0).collect(),
+                        iter.clone().take(count1).collect(),
+                        iter.clone().take(count2).collect(),
+                    )
+                {
+                    Err(PredError::IncorrectOutput)
+                } else if iter.skip(max_count).ne(remaining) {
+                    Err(PredError::IncorrectIterConsumption)
+                } else {
+                    Ok(())
+                }
+            },
+        }
+        .test_collector()
+    }
+}
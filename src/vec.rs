@@ -3,7 +3,7 @@
 //! This module corresponds to [`mod@std::vec`].
 
 use crate::{
-    collector::{Collector, CollectorBase},
+    collector::{Collector, CollectorBase, SnapshotCollector},
     slice::{Concat, ConcatItem, ConcatItemSealed, ConcatSealed},
 };
 
@@ -19,6 +19,11 @@ use alloc::vec::Vec;
 ///
 /// [`Output`]: CollectorBase::Output
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct IntoCollector<T>(Vec<T>);
 
 /// A collector that pushes collected items into a [`&mut Vec`](Vec).
@@ -59,6 +64,21 @@ impl<T> CollectorBase for IntoCollector<T> {
     fn finish(self) -> Self::Output {
         self.0
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        self.0.reserve(additional_min);
+    }
+}
+
+impl<T> SnapshotCollector for IntoCollector<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn peek_output(&self) -> Self::Output {
+        self.0.clone()
+    }
 }
 
 impl<T> Collector<T> for IntoCollector<T> {
@@ -134,6 +154,11 @@ impl<'a, T> CollectorBase for CollectorMut<'a, T> {
     fn finish(self) -> Self::Output {
         self.0
     }
+
+    #[inline]
+    fn reserve(&mut self, additional_min: usize, _additional_max: Option<usize>) {
+        self.0.reserve(additional_min);
+    }
 }
 
 impl<'a, T> Collector<T> for CollectorMut<'a, T> {
@@ -251,7 +276,7 @@ mod proptests {
     use proptest::test_runner::TestCaseResult;
 
     use crate::prelude::*;
-    use crate::test_utils::{
+    use crate::testkit::{
         BasicCollectorTester, CollectorTestParts, CollectorTester, CollectorTesterExt, PredError,
         none_iter_for_fuse_test,
     };
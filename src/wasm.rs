@@ -0,0 +1,210 @@
+//! Bridges between JavaScript and collectors, for WebAssembly with [`wasm-bindgen`](mod@wasm_bindgen).
+//!
+//! - [`JsIter`] turns a JS iterable (e.g., an array, a `Set` or a generator)
+//!   into a Rust [`Iterator`], so that it can be fed into collectors.
+//! - [`JsCollector`] exposes a collector to JS as an object
+//!   with `collect(item)`, `collectMany(iterable)` and `finish()` methods,
+//!   so that JS code can feed items into a pipeline compiled to WebAssembly.
+//!
+//! JS values can only be used on `wasm32` targets.
+//! On other targets, the items here compile, but panic when used.
+//!
+//! This module has no counterpart in the standard library.
+//!
+//! # Examples
+//!
+//! Exporting a pipeline to JS:
+//!
+//! ```no_run
+//! use komadori::{prelude::*, iter::Count, wasm::JsCollector};
+//! use wasm_bindgen::prelude::*;
+//!
+//! /// Counts the truthy values.
+//! #[wasm_bindgen(js_name = truthyCounter)]
+//! pub fn truthy_counter() -> JsCollector {
+//!     JsCollector::new(Count::new().filter(JsValue::is_truthy))
+//! }
+//! ```
+//!
+//! Which can be used in JS like:
+//!
+//! ```js
+//! const counter = truthyCounter();
+//! counter.collect(1);
+//! counter.collectMany([0, "a", null]);
+//! console.log(counter.finish()); // 2
+//! ```
+
+use std::fmt::Debug;
+
+use js_sys::{IntoIter, try_iter};
+use wasm_bindgen::prelude::*;
+
+use crate::collector::{BoxCollector, Collector, CollectorBase};
+
+/// An iterator over the values of a JS iterable.
+///
+/// If the JS iterator throws, this iterator ends,
+/// and the thrown value can be retrieved with [`take_error()`](JsIter::take_error).
+///
+/// # Examples
+///
+/// ```no_run
+/// use komadori::{prelude::*, wasm::JsIter};
+/// use wasm_bindgen::prelude::*;
+///
+/// #[wasm_bindgen]
+/// pub fn sum(iterable: &JsValue) -> Result<f64, JsValue> {
+///     let mut iter = JsIter::new(iterable)?;
+///     let sum = iter
+///         .by_ref()
+///         .filter_map(|value| value.as_f64())
+///         .feed_into(f64::adding());
+///
+///     match iter.take_error() {
+///         Some(error) => Err(error),
+///         None => Ok(sum),
+///     }
+/// }
+/// ```
+pub struct JsIter {
+    // `None` once the JS iterator has thrown.
+    iter: Option<IntoIter>,
+    error: Option<JsValue>,
+}
+
+impl JsIter {
+    /// Creates an iterator over the values of a JS iterable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the value is not iterable,
+    /// or the thrown value if getting its iterator throws.
+    pub fn new(iterable: &JsValue) -> Result<Self, JsValue> {
+        let iter =
+            try_iter(iterable)?.ok_or_else(|| js_sys::TypeError::new("value is not iterable"))?;
+
+        Ok(Self {
+            iter: Some(iter),
+            error: None,
+        })
+    }
+
+    /// Takes the value thrown by the JS iterator, if any.
+    #[inline]
+    pub fn take_error(&mut self) -> Option<JsValue> {
+        self.error.take()
+    }
+}
+
+impl Iterator for JsIter {
+    type Item = JsValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.as_mut()?.next()? {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.iter = None;
+                self.error = Some(error);
+                None
+            }
+        }
+    }
+}
+
+impl Debug for JsIter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsIter")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A collector exposed to JS, collecting JS values.
+///
+/// It is created in Rust with [`JsCollector::new()`], then returned to JS,
+/// where it has the following methods:
+///
+/// - `collect(item)` collects an item,
+///   and returns `false` once the collector has stopped accumulating.
+/// - `collectMany(iterable)` collects the values of an iterable,
+///   and returns `false` once the collector has stopped accumulating.
+/// - `finish()` returns the output of the collector.
+///   Afterwards, all methods throw.
+///
+/// See the [module-level documentation](self) for an example.
+#[wasm_bindgen]
+pub struct JsCollector {
+    // `None` once finished.
+    collector: Option<BoxCollector<'static, JsValue, JsValue>>,
+}
+
+impl JsCollector {
+    /// Wraps a collector of JS values whose output can be converted into a JS value.
+    pub fn new<C>(collector: C) -> Self
+    where
+        C: Collector<JsValue, Output: Into<JsValue>> + 'static,
+    {
+        Self {
+            collector: Some(BoxCollector::new(collector.map_output(Into::into))),
+        }
+    }
+
+    fn collector_mut(&mut self) -> Result<&mut BoxCollector<'static, JsValue, JsValue>, JsError> {
+        self.collector.as_mut().ok_or_else(finished_error)
+    }
+}
+
+#[wasm_bindgen]
+impl JsCollector {
+    /// Collects an item, returning `false` once the collector has stopped accumulating.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collector has been finished.
+    pub fn collect(&mut self, item: JsValue) -> Result<bool, JsError> {
+        Ok(self.collector_mut()?.collect(item).is_continue())
+    }
+
+    /// Collects the values of an iterable, returning `false`
+    /// once the collector has stopped accumulating.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collector has been finished, or the value is not iterable,
+    /// or the value thrown by the iterable.
+    #[wasm_bindgen(js_name = collectMany)]
+    pub fn collect_many(&mut self, iterable: &JsValue) -> Result<bool, JsValue> {
+        let mut iter = JsIter::new(iterable)?;
+        let cf = self.collector_mut()?.collect_many(iter.by_ref());
+
+        match iter.take_error() {
+            Some(error) => Err(error),
+            None => Ok(cf.is_continue()),
+        }
+    }
+
+    /// Returns the output of the collector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collector has been finished.
+    pub fn finish(&mut self) -> Result<JsValue, JsError> {
+        // `Option` itself is a collector, so its `take()` must be disambiguated.
+        Option::take(&mut self.collector)
+            .map(CollectorBase::finish)
+            .ok_or_else(finished_error)
+    }
+}
+
+impl Debug for JsCollector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsCollector")
+            .field("finished", &self.collector.is_none())
+            .finish_non_exhaustive()
+    }
+}
+
+fn finished_error() -> JsError {
+    JsError::new("the collector has been finished")
+}
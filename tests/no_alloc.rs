@@ -0,0 +1,67 @@
+//! Pins down that a pipeline of allocation-free collectors never allocates.
+//!
+//! It builds with every feature combination, including `--no-default-features`
+//! where the crate does not link `alloc` at all.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use komadori::{
+    cmp::{Max, Min},
+    prelude::*,
+    slice::Fill,
+};
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.with(|allocs| allocs.set(allocs.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+// Only counts the allocations of the current thread,
+// so that the test harness does not interfere.
+fn allocs_during<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCS.with(Cell::get);
+    let result = f();
+    (result, ALLOCS.with(Cell::get) - before)
+}
+
+#[test]
+fn static_pipeline() {
+    let mut buf = [0; 4];
+
+    let ((((filled, sum), min), max), allocs) = allocs_during(|| {
+        [3, -1, 4, 1, -5, 9]
+            .into_iter()
+            .filter(|&num| num != 0)
+            .feed_into(
+                Fill::new(&mut buf)
+                    .tee(i32::adding())
+                    .tee(Min::new())
+                    .tee(Max::new())
+                    .take_while(|&num| num < 100),
+            )
+    });
+
+    assert_eq!(
+        (filled, sum, min, max),
+        ([3, -1, 4, 1].as_mut_slice(), 11, Some(-5), Some(9))
+    );
+    assert_eq!(allocs, 0);
+}
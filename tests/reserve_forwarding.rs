@@ -0,0 +1,78 @@
+//! Pins down that collectors fed by reference or through a trait object
+//! still receive the size hint of the iterator.
+
+use std::{any::Any, ops::ControlFlow};
+
+use komadori::{
+    collector::{Collector, CollectorBase, DynCollector},
+    prelude::*,
+};
+
+// Records the hints it receives.
+#[derive(Debug, Default)]
+struct Reserving {
+    hints: Vec<(usize, Option<usize>)>,
+}
+
+impl CollectorBase for Reserving {
+    type Output = Vec<(usize, Option<usize>)>;
+
+    fn finish(self) -> Self::Output {
+        self.hints
+    }
+
+    fn reserve(&mut self, additional_min: usize, additional_max: Option<usize>) {
+        self.hints.push((additional_min, additional_max));
+    }
+}
+
+impl Collector<i32> for Reserving {
+    fn collect(&mut self, _item: i32) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+#[test]
+fn by_mut_ref() {
+    let mut collector = Reserving::default();
+    (0..5).feed_into(&mut collector);
+
+    assert_eq!(collector.finish(), [(5, Some(5))]);
+}
+
+#[test]
+fn by_dyn_collector() {
+    let mut collector = Reserving::default();
+    (0..5).feed_into(&mut collector as &mut dyn Collector<i32>);
+    (0..3).feed_into(&mut collector as &mut (dyn Collector<i32> + Send + Sync));
+
+    assert_eq!(collector.finish(), [(5, Some(5)), (3, Some(3))]);
+}
+
+#[test]
+fn break_hint_by_dyn_collector() {
+    let mut collector = vec![].into_collector().take(0);
+    let collector: &mut dyn Collector<i32> = &mut collector;
+
+    assert!(CollectorBase::break_hint(&collector).is_break());
+}
+
+#[test]
+fn by_dyn_collector_base() {
+    let mut collector = Reserving::default();
+    let mut by_ref: &mut dyn CollectorBase = &mut collector;
+    CollectorBase::reserve(&mut by_ref, 2, None);
+
+    assert_eq!(collector.finish(), [(2, None)]);
+}
+
+#[test]
+fn by_box_dyn_collector() {
+    let collector: Box<dyn DynCollector<i32>> = Box::new(Reserving::default());
+    let hints: Box<dyn Any> = (0..5).feed_into(collector);
+
+    assert_eq!(
+        *hints.downcast::<Vec<(usize, Option<usize>)>>().unwrap(),
+        [(5, Some(5))],
+    );
+}
@@ -0,0 +1,89 @@
+//! Pins down that a pipeline checkpointed with serde resumes where it left off,
+//! producing the same output as if it had never stopped.
+
+use std::collections::{BTreeSet, HashMap};
+
+use komadori::{
+    cmp::{Max, MinMax},
+    collector::{Take, Tee},
+    iter::Count,
+    prelude::*,
+    vec,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+// Saves the state as if to disk, and restores it.
+fn checkpoint<C>(collector: &C) -> C
+where
+    C: Serialize + DeserializeOwned,
+{
+    let json = serde_json::to_string(collector).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn resume_from_checkpoint() {
+    let nums = [4, 8, 15, 16, 23, 42, 7, 4];
+    let (before, after) = nums.split_at(3);
+
+    let pipeline = || {
+        Count::new()
+            .tee(i32::adding())
+            .tee(Max::new().tee(MinMax::new()))
+            .tee(vec![].into_collector().take(5))
+            .tee(BTreeSet::new().into_collector().skip(2))
+    };
+
+    let mut collector = pipeline();
+    let _ = collector.collect_many(before.iter().copied());
+
+    let mut resumed = checkpoint(&collector);
+    drop(collector);
+    let _ = resumed.collect_many(after.iter().copied());
+
+    assert_eq!(resumed.finish(), nums.into_iter().feed_into(pipeline()));
+}
+
+#[test]
+fn stopped_collectors_stay_stopped() {
+    let mut collector = vec![].into_collector().take(2).tee(Count::new());
+    let _ = collector.collect_many([1, 2, 3]);
+
+    let mut resumed = checkpoint(&collector);
+    let _ = resumed.collect_many([4, 5]);
+
+    assert_eq!(resumed.finish(), (vec![1, 2], 5));
+}
+
+#[test]
+fn hash_map() {
+    let mut collector = HashMap::new().into_collector();
+    let _ = collector.collect_many([("a".to_owned(), 1), ("b".to_owned(), 2)]);
+
+    assert_eq!(
+        checkpoint(&collector).finish(),
+        HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)])
+    );
+}
+
+#[test]
+fn resume_from_checkpoint_every() {
+    let nums = [4, 8, 15, 16, 23, 42, 7, 4];
+    let pipeline = || vec![].into_collector().take(6).tee(i32::adding());
+
+    let (_, saved) = nums.into_iter().feed_into(
+        pipeline().checkpoint_every(
+            3,
+            vec![]
+                .into_collector()
+                .map(|collector| serde_json::to_string(&collector).unwrap()),
+        ),
+    );
+
+    // Pretend that the process crashed after the 7th item.
+    let mut resumed: Tee<Take<vec::IntoCollector<i32>>, komadori::num::Adding<i32>> =
+        serde_json::from_str(&saved[1]).unwrap();
+    let _ = resumed.collect_many(nums[6..].iter().copied());
+
+    assert_eq!(resumed.finish(), nums.into_iter().feed_into(pipeline()));
+}